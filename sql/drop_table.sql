@@ -1,2 +1,8 @@
-DROP TABLE IF EXISTS circles,
-members,
\ No newline at end of file
+DROP TABLE IF EXISTS outbox,
+join_requests,
+invitations,
+waitlist_entries,
+memberships,
+members,
+circles,
+_sqlx_migrations;
@@ -1,6 +1,12 @@
-use main::app::run;
+use main::{app::run, migrate};
 
 #[tokio::main]
 async fn main() {
-    run().await.expect("Failed to run the app");
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("migrate") => migrate::run(args.get(1).map(String::as_str))
+            .await
+            .expect("Failed to run migrations"),
+        _ => run().await.expect("Failed to run the app"),
+    }
 }
@@ -1,3 +1,5 @@
 pub mod circle;
+pub mod invitation;
+pub mod join_request;
 pub mod member;
 pub mod value_object;
@@ -1,49 +1,104 @@
+use std::collections::BTreeMap;
+
 use super::{
     member::Member,
-    value_object::{circle_id::CircleId, grade::Grade},
+    value_object::{
+        capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+        circle_status::CircleStatus, grade::Grade, member_id::MemberId, permission::Permission,
+        role::Role,
+    },
 };
-use anyhow::Error;
+use crate::{error::DomainError, event::DomainEvent};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Circle {
     pub id: CircleId,
-    pub name: String,
-    pub capacity: i16,
+    pub name: CircleName,
+    pub capacity: Capacity,
     pub owner: Member,
     pub members: Vec<Member>,
+    /// Members waiting for a seat, first in line first.
+    pub waitlist: Vec<Member>,
+    /// Roles given to members; anyone not listed here is a plain `Member`.
+    pub roles: BTreeMap<MemberId, Role>,
+    /// Member nominated to take over when the owner graduates at the next rollover.
+    pub successor_id: Option<MemberId>,
+    pub status: CircleStatus,
+    /// Incremented on every persisted update; used for optimistic concurrency control.
+    pub version: u32,
+    /// Events recorded since the circle was loaded, handed to the publisher once persisted.
+    pub events: Vec<DomainEvent>,
 }
 
-impl Circle {
-    const MIN_CAPACITY: i16 = 1;
-    // const MIN_RUNNABLE_MEMBERS: usize = 3;
+// events describe how a circle got to its state, not the state itself
+impl PartialEq for Circle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.name == other.name
+            && self.capacity == other.capacity
+            && self.owner == other.owner
+            && self.members == other.members
+            && self.waitlist == other.waitlist
+            && self.roles == other.roles
+            && self.successor_id == other.successor_id
+            && self.status == other.status
+            && self.version == other.version
+    }
+}
 
-    pub fn create(name: String, owner: Member, capacity: i16) -> Result<Self, Error> {
-        if owner.grade != Grade::Third {
-            return Err(Error::msg("Owner must be 3rd grade"));
-        }
+impl Eq for Circle {}
 
-        if capacity < Self::MIN_CAPACITY {
-            return Err(Error::msg(format!(
-                "Capacity must be at least {}",
-                Self::MIN_CAPACITY
-            )));
+impl Circle {
+    /// Whoever starts running a circle, at creation or through a transfer, is a 3rd grader.
+    const OWNER_GRADE: Grade = Grade::Third;
+    /// A successor is nominated a year ahead, so a 2nd grader is eligible too.
+    const SUCCESSOR_GRADES: [Grade; 2] = [Grade::Second, Grade::Third];
+    const MIN_RUNNABLE_MEMBERS: usize = 3;
+
+    pub fn create(
+        id: CircleId,
+        name: CircleName,
+        owner: Member,
+        capacity: Capacity,
+    ) -> Result<Self, DomainError> {
+        if owner.grade != Self::OWNER_GRADE {
+            return Err(DomainError::InvalidOwnerGrade);
         }
 
+        let event = DomainEvent::CircleCreated {
+            circle_id: id.clone(),
+            name: name.clone(),
+            owner_id: owner.id.clone(),
+        };
         Ok(Circle {
-            id: CircleId::gen(),
+            id,
             name,
             owner,
             capacity,
             members: vec![],
-        })
+            waitlist: vec![],
+            roles: BTreeMap::new(),
+            successor_id: None,
+            status: CircleStatus::Recruiting,
+            version: 1,
+            events: vec![],
+        }
+        .record(event))
     }
 
+    // one argument per stored field
+    #[allow(clippy::too_many_arguments)]
     pub fn reconstruct(
         id: CircleId,
-        name: String,
+        name: CircleName,
         owner: Member,
-        capacity: i16,
+        capacity: Capacity,
         members: Vec<Member>,
+        waitlist: Vec<Member>,
+        roles: BTreeMap<MemberId, Role>,
+        successor_id: Option<MemberId>,
+        status: CircleStatus,
+        version: u32,
     ) -> Self {
         Circle {
             id,
@@ -51,44 +106,94 @@ impl Circle {
             owner,
             capacity,
             members,
+            waitlist,
+            roles,
+            successor_id,
+            status,
+            version,
+            events: vec![],
         }
     }
 
-    pub fn update(self, name: Option<String>, capacity: Option<i16>) -> Self {
-        let updated_name = name.unwrap_or(self.name);
-        let updated_capacity = capacity.unwrap_or(self.capacity);
-
-        Circle {
-            name: updated_name,
-            capacity: updated_capacity,
-            ..self
+    pub fn update(
+        self,
+        name: Option<CircleName>,
+        capacity: Option<Capacity>,
+    ) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+        if let Some(capacity) = capacity {
+            if !capacity.can_hold(self.member_count()) {
+                return Err(DomainError::CapacityBelowMemberCount(self.member_count()));
+            }
+        }
+        let mut circle = self;
+        if let Some(name) = name.filter(|name| name != &circle.name) {
+            circle.name = name;
+            let event = DomainEvent::CircleRenamed {
+                circle_id: circle.id.clone(),
+                name: circle.name.clone(),
+            };
+            circle = circle.record(event);
         }
+        if let Some(capacity) = capacity.filter(|capacity| capacity != &circle.capacity) {
+            circle.capacity = capacity;
+            let event = DomainEvent::CapacityChanged {
+                circle_id: circle.id.clone(),
+                capacity,
+            };
+            circle = circle.record(event);
+        }
+        Ok(circle.promote_waitlisted().refresh_status())
     }
 
-    pub fn add_member(self, member: Member) -> Result<Self, Error> {
-        if self.is_full() {
-            return Err(Error::msg("Circle member is full"));
+    /// Adds the member, or puts them at the end of the waitlist when the circle is full.
+    ///
+    /// Approved join requests and accepted invitations come through here too, so the grade
+    /// rule and the waitlist apply against the circle as it is when the member joins, not as
+    /// it was when they were asked in.
+    pub fn add_member(self, member: Member) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+
+        // checked first, so that a repeated join reports the duplicate rather than a full circle
+        if self.member(&member.id).is_some() {
+            return Err(DomainError::AlreadyMember);
+        }
+        if self.waitlist_position(&member.id).is_some() {
+            return Err(DomainError::AlreadyWaitlisted);
         }
 
         if member.grade == Grade::Fourth {
-            return Err(Error::msg("4th grade can't join circle"));
+            return Err(DomainError::FourthGradeCannotJoin);
         }
 
-        let new_members: Vec<Member> = self
-            .members
-            .into_iter()
-            .chain(std::iter::once(member))
-            .collect();
+        if self.is_full() {
+            let event = DomainEvent::MemberWaitlisted {
+                circle_id: self.id.clone(),
+                member_id: member.id.clone(),
+            };
+            let mut circle = self;
+            circle.waitlist.push(member);
+            return Ok(circle.record(event));
+        }
 
-        Ok(Circle {
-            members: new_members,
-            ..self
-        })
+        Ok(self.seat(member).refresh_status())
     }
 
-    pub fn remove_member(self, member: &Member) -> Result<Self, Error> {
+    /// Takes the member out of the circle, or off its waitlist. A freed seat goes to the next
+    /// member in line.
+    pub fn remove_member(self, member: &Member) -> Result<Self, DomainError> {
         if self.owner.id == member.id {
-            return Err(Error::msg("Owner can't be removed"));
+            return Err(DomainError::OwnerCannotBeRemoved);
+        }
+
+        if let Some(position) = self.waitlist_position(&member.id) {
+            let event = DomainEvent::MemberLeftWaitlist {
+                circle_id: self.id.clone(),
+                member_id: member.id.clone(),
+            };
+            let mut circle = self;
+            circle.waitlist.remove(position - 1);
+            return Ok(circle.record(event));
         }
 
         let new_members: Vec<Member> = self
@@ -99,32 +204,293 @@ impl Circle {
             .collect();
 
         if new_members.len() == self.members.len() {
-            return Err(Error::msg("Member not found in circle"));
+            return Err(DomainError::NotMember);
         }
 
+        let successor_id = self.successor_id.filter(|id| id != &member.id);
+        let event = DomainEvent::MemberLeft {
+            circle_id: self.id.clone(),
+            member_id: member.id.clone(),
+        };
         Ok(Circle {
-            id: self.id,
-            name: self.name,
-            owner: self.owner,
-            capacity: self.capacity,
             members: new_members,
+            successor_id,
+            ..self
+        }
+        .record(event)
+        .prune_roles()
+        .promote_waitlisted()
+        .refresh_status())
+    }
+
+    /// Hands the circle over to `member_id` right away; the previous owner stays on as a member.
+    /// The new owner has to meet the same grade rule as the owner of a new circle.
+    pub fn transfer_ownership(self, member_id: &MemberId) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+        let new_owner = self
+            .members
+            .iter()
+            .find(|m| &m.id == member_id)
+            .ok_or(DomainError::NotMember)?
+            .clone();
+        if new_owner.grade != Self::OWNER_GRADE {
+            return Err(DomainError::InvalidOwnerGrade);
+        }
+        let event = DomainEvent::OwnershipTransferred {
+            circle_id: self.id.clone(),
+            previous_owner_id: self.owner.id.clone(),
+            new_owner_id: new_owner.id.clone(),
+        };
+        let members = self
+            .members
+            .into_iter()
+            .filter(|m| m.id != new_owner.id)
+            .chain(std::iter::once(self.owner))
+            .collect();
+
+        Ok(Circle {
+            owner: new_owner,
+            members,
+            successor_id: None,
+            ..self
+        }
+        .record(event)
+        .prune_roles())
+    }
+
+    /// Gives a member a role. Ownership only changes hands through `transfer_ownership`.
+    pub fn assign_role(self, member_id: &MemberId, role: Role) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+        if role == Role::Owner || &self.owner.id == member_id {
+            return Err(DomainError::OwnerRoleNotAssignable);
+        }
+        if !self.members.iter().any(|m| &m.id == member_id) {
+            return Err(DomainError::NotMember);
+        }
+        if self.role(member_id) == Some(role) {
+            return Ok(self);
+        }
+
+        let event = DomainEvent::RoleAssigned {
+            circle_id: self.id.clone(),
+            member_id: member_id.clone(),
+            role,
+        };
+        let mut circle = self;
+        if role == Role::Member {
+            circle.roles.remove(member_id);
+        } else {
+            circle.roles.insert(member_id.clone(), role);
+        }
+        Ok(circle.record(event))
+    }
+
+    /// Fails with `Forbidden` unless `member_id` belongs to the circle with a role that allows
+    /// `permission`.
+    pub fn authorize(
+        &self,
+        member_id: &MemberId,
+        permission: Permission,
+    ) -> Result<(), DomainError> {
+        match self.role(member_id) {
+            Some(role) if role.can(permission) => Ok(()),
+            _ => Err(DomainError::Forbidden(permission)),
+        }
+    }
+
+    /// Nominates `member_id` to take over when the owner graduates at the next rollover.
+    pub fn nominate_successor(self, member_id: &MemberId) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+        let member = self
+            .members
+            .iter()
+            .find(|m| &m.id == member_id)
+            .ok_or(DomainError::NotMember)?;
+        if !Self::SUCCESSOR_GRADES.contains(&member.grade) {
+            return Err(DomainError::InvalidSuccessorGrade);
+        }
+        Ok(Circle {
+            successor_id: Some(member_id.clone()),
+            ..self
         })
     }
 
     pub fn graduate(self) -> Self {
-        let new_members: Vec<Member> = self
+        let (graduates, new_members): (Vec<Member>, Vec<Member>) = self
             .members
             .into_iter()
+            .partition(|m| m.grade == Grade::Fourth);
+
+        let successor_id = self
+            .successor_id
+            .filter(|id| new_members.iter().any(|m| &m.id == id));
+        // graduates waiting for a seat would never get one
+        let waitlist = self
+            .waitlist
+            .into_iter()
             .filter(|m| m.grade != Grade::Fourth)
             .collect();
-
-        Circle {
-            id: self.id,
-            name: self.name,
-            owner: self.owner,
-            capacity: self.capacity,
+        let circle = Circle {
             members: new_members,
+            waitlist,
+            successor_id,
+            ..self
+        }
+        .prune_roles();
+        if graduates.is_empty() {
+            return circle;
         }
+        let event = DomainEvent::MembersGraduated {
+            circle_id: circle.id.clone(),
+            member_ids: graduates.into_iter().map(|m| m.id).collect(),
+        };
+        circle.record(event).promote_waitlisted().refresh_status()
+    }
+
+    /// Closes the academic year for this circle: 4th-grade members graduate, and a graduating
+    /// owner hands the circle over to the nominated successor. Without one there is nobody to take
+    /// over, so the circle goes dormant until the owner transfers it. Grades belong to the members
+    /// themselves, so moving everyone up a grade happens outside the circle.
+    pub fn rollover(self) -> Self {
+        let graduated = self.graduate();
+        match graduated.owner.grade {
+            Grade::Fourth if graduated.successor_id.is_some() => graduated
+                .hand_over_to_successor()
+                .promote_waitlisted()
+                .refresh_status(),
+            Grade::Fourth if graduated.status != CircleStatus::Disbanded => {
+                graduated.change_status(CircleStatus::Dormant)
+            }
+            _ => graduated,
+        }
+    }
+
+    /// Closes the circle. It keeps its members, but takes no new ones and can't be changed.
+    pub fn disband(self) -> Result<Self, DomainError> {
+        self.ensure_not_disbanded()?;
+        Ok(self.change_status(CircleStatus::Disbanded))
+    }
+
+    /// Brings a dormant or disbanded circle back: active if it has the members to run,
+    /// recruiting otherwise.
+    pub fn reactivate(self) -> Result<Self, DomainError> {
+        if !matches!(self.status, CircleStatus::Dormant | CircleStatus::Disbanded) {
+            return Err(DomainError::NotReactivatable(self.status));
+        }
+        let status = if self.is_runnable() {
+            CircleStatus::Active
+        } else {
+            CircleStatus::Recruiting
+        };
+        Ok(self
+            .change_status(status)
+            .promote_waitlisted()
+            .refresh_status())
+    }
+
+    // a recruiting circle starts running once it has enough members, and an active one goes
+    // dormant when it drops below that; a dormant circle only comes back through `reactivate`
+    fn refresh_status(self) -> Self {
+        let status = match self.status {
+            CircleStatus::Recruiting if self.is_runnable() => CircleStatus::Active,
+            CircleStatus::Active if !self.is_runnable() => CircleStatus::Dormant,
+            status => status,
+        };
+        self.change_status(status)
+    }
+
+    fn change_status(self, status: CircleStatus) -> Self {
+        if status == self.status {
+            return self;
+        }
+        let event = DomainEvent::StatusChanged {
+            circle_id: self.id.clone(),
+            status,
+        };
+        Circle { status, ..self }.record(event)
+    }
+
+    fn seat(self, member: Member) -> Self {
+        let event = DomainEvent::MemberJoined {
+            circle_id: self.id.clone(),
+            member_id: member.id.clone(),
+        };
+        let mut circle = self;
+        circle.members.push(member);
+        circle.record(event)
+    }
+
+    // fills free seats in waitlist order; a disbanded circle takes nobody in, and members who
+    // can no longer join stay in line until they graduate
+    fn promote_waitlisted(self) -> Self {
+        let mut circle = self;
+        while circle.status != CircleStatus::Disbanded && !circle.is_full() {
+            let Some(index) = circle
+                .waitlist
+                .iter()
+                .position(|m| m.grade != Grade::Fourth)
+            else {
+                break;
+            };
+            let member = circle.waitlist.remove(index);
+            circle = circle.seat(member);
+        }
+        circle
+    }
+
+    fn ensure_not_disbanded(&self) -> Result<(), DomainError> {
+        if self.status == CircleStatus::Disbanded {
+            return Err(DomainError::CircleDisbanded);
+        }
+        Ok(())
+    }
+
+    // the previous owner leaves together with the other graduates
+    fn hand_over_to_successor(self) -> Self {
+        let Some(successor) = self
+            .successor_id
+            .as_ref()
+            .and_then(|id| self.members.iter().find(|m| &m.id == id))
+            .cloned()
+        else {
+            return self;
+        };
+        let events = [
+            DomainEvent::MembersGraduated {
+                circle_id: self.id.clone(),
+                member_ids: vec![self.owner.id.clone()],
+            },
+            DomainEvent::OwnershipTransferred {
+                circle_id: self.id.clone(),
+                previous_owner_id: self.owner.id.clone(),
+                new_owner_id: successor.id.clone(),
+            },
+        ];
+        let mut circle = Circle {
+            members: self
+                .members
+                .into_iter()
+                .filter(|m| m.id != successor.id)
+                .collect(),
+            owner: successor,
+            successor_id: None,
+            ..self
+        };
+        circle.events.extend(events);
+        circle.prune_roles()
+    }
+
+    // roles belong to members; whoever left or took over the circle loses theirs
+    fn prune_roles(mut self) -> Self {
+        let members = &self.members;
+        self.roles
+            .retain(|member_id, _| members.iter().any(|m| &m.id == member_id));
+        self
+    }
+
+    fn record(mut self, event: DomainEvent) -> Self {
+        self.events.push(event);
+        self
     }
 
     fn circle_members(&self) -> Vec<&Member> {
@@ -133,13 +499,13 @@ impl Circle {
             .collect()
     }
 
-    fn is_full(&self) -> bool {
-        self.circle_members().len() >= self.capacity as usize
+    pub fn is_full(&self) -> bool {
+        !self.capacity.can_hold(self.member_count() + 1)
     }
 
-    // fn _is_runnable(&self) -> bool {
-    //     self.circle_members().len() >= Self::MIN_RUNNABLE_MEMBERS
-    // }
+    fn is_runnable(&self) -> bool {
+        self.member_count() >= Self::MIN_RUNNABLE_MEMBERS
+    }
 
     fn _is_drinkable_alcohol(member: &Member) -> bool {
         member.is_adult()
@@ -151,83 +517,284 @@ impl Circle {
     }
 
     pub fn name(&self) -> &str {
-        &self.name
+        self.name.as_str()
+    }
+
+    pub fn member_count(&self) -> usize {
+        self.circle_members().len()
+    }
+
+    pub fn member(&self, member_id: &MemberId) -> Option<&Member> {
+        self.circle_members()
+            .into_iter()
+            .find(|member| &member.id == member_id)
+    }
+
+    /// The member's role, or `None` if they don't belong to the circle.
+    pub fn role(&self, member_id: &MemberId) -> Option<Role> {
+        if &self.owner.id == member_id {
+            return Some(Role::Owner);
+        }
+        self.members
+            .iter()
+            .any(|m| &m.id == member_id)
+            .then(|| self.roles.get(member_id).copied().unwrap_or(Role::Member))
+    }
+
+    /// Where the member stands on the waitlist, counting from 1.
+    pub fn waitlist_position(&self, member_id: &MemberId) -> Option<usize> {
+        self.waitlist
+            .iter()
+            .position(|member| &member.id == member_id)
+            .map(|index| index + 1)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aggregate::value_object::{major::Major, member_id::MemberId};
+    use crate::aggregate::value_object::{age::Age, major::Major};
 
     fn create_owner() -> Member {
         Member::reconstruct(
             MemberId::gen(),
             "owner".to_string(),
-            21,
+            Age::try_from(21).unwrap(),
             Grade::Third,
             Major::ComputerScience,
         )
     }
 
     fn create_member(grade: Grade) -> Member {
-        Member::new("member".to_string(), 20, grade, Major::ComputerScience)
+        Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(20).unwrap(),
+            grade,
+            Major::ComputerScience,
+        )
     }
 
     #[test]
     fn test_create_circle() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 10).unwrap();
-        assert_eq!(circle.name, "test circle");
-        assert_eq!(circle.capacity, 10);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(circle.name.as_str(), "test circle");
+        assert_eq!(i16::from(circle.capacity), 10);
         assert_eq!(circle.members.len(), 0);
     }
 
     #[test]
     fn test_create_circle_with_invalid_owner() {
         let owner = create_member(Grade::First);
-        let error = Circle::create("test circle".to_string(), owner, 10).unwrap_err();
+        let error = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(error, DomainError::InvalidOwnerGrade);
         assert_eq!(error.to_string(), "Owner must be 3rd grade");
     }
 
     #[test]
     fn test_create_circle_with_invalid_capacity() {
+        let error = Capacity::try_from(0).unwrap_err();
+        assert_eq!(error, DomainError::InvalidCapacity(0));
+        assert_eq!(
+            error.to_string(),
+            "Capacity must be between 1 and 100, got 0"
+        );
+    }
+
+    #[test]
+    fn test_update_capacity_below_member_count() {
         let owner = create_owner();
-        let error = Circle::create("test circle".to_string(), owner, 0).unwrap_err();
-        assert_eq!(error.to_string(), "Capacity must be at least 1");
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap()
+        .add_member(create_member(Grade::First))
+        .unwrap();
+        let error = circle
+            .clone()
+            .update(None, Some(Capacity::try_from(1).unwrap()))
+            .unwrap_err();
+        assert_eq!(error, DomainError::CapacityBelowMemberCount(2));
+        assert_eq!(
+            error.to_string(),
+            "Capacity can't be lower than the current 2 members"
+        );
+        let circle = circle
+            .update(None, Some(Capacity::try_from(2).unwrap()))
+            .unwrap();
+        assert!(circle.is_full());
     }
 
     #[test]
     fn test_add_member() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 10).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
         let member = create_member(Grade::First);
         let circle = circle.add_member(member).unwrap();
         assert_eq!(circle.members.len(), 1);
     }
 
+    #[test]
+    fn test_add_member_twice() {
+        let owner = create_owner();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner.clone(),
+            Capacity::try_from(2).unwrap(),
+        )
+        .unwrap();
+        let member = create_member(Grade::First);
+        let circle = circle.add_member(member.clone()).unwrap();
+        // the circle is full now, but the duplicate is what gets reported
+        assert_eq!(
+            circle.clone().add_member(member).unwrap_err(),
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            circle.add_member(owner).unwrap_err(),
+            DomainError::AlreadyMember
+        );
+    }
+
     #[test]
     fn test_add_member_to_full_circle() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 1).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(1).unwrap(),
+        )
+        .unwrap();
         let member = create_member(Grade::First);
+        let circle = circle.add_member(member.clone()).unwrap();
+        assert!(circle.members.is_empty());
+        assert_eq!(circle.waitlist, vec![member.clone()]);
+        assert_eq!(circle.waitlist_position(&member.id), Some(1));
+        assert_eq!(
+            circle.events.last(),
+            Some(&DomainEvent::MemberWaitlisted {
+                circle_id: circle.id.clone(),
+                member_id: member.id.clone(),
+            })
+        );
+
         let error = circle.add_member(member).unwrap_err();
-        assert_eq!(error.to_string(), "Circle member is full");
+        assert_eq!(error, DomainError::AlreadyWaitlisted);
+        assert_eq!(error.to_string(), "Member is already on the waitlist");
+    }
+
+    #[test]
+    fn test_freed_seat_goes_to_waitlist() -> anyhow::Result<()> {
+        let member1 = create_member(Grade::First);
+        let member2 = create_member(Grade::Second);
+        let member3 = create_member(Grade::Third);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            create_owner(),
+            Capacity::try_from(2)?,
+        )?
+        .add_member(member1.clone())?
+        .add_member(member2.clone())?
+        .add_member(member3.clone())?;
+        assert_eq!(circle.waitlist_position(&member2.id), Some(1));
+        assert_eq!(circle.waitlist_position(&member3.id), Some(2));
+
+        // leaving the waitlist moves everyone behind up
+        let circle = circle.remove_member(&member2)?;
+        assert_eq!(circle.waitlist_position(&member3.id), Some(1));
+
+        let circle = circle
+            .add_member(member2.clone())?
+            .remove_member(&member1)?;
+        assert_eq!(circle.members, vec![member3.clone()]);
+        assert_eq!(circle.waitlist, vec![member2.clone()]);
+        assert_eq!(
+            circle.events.last(),
+            Some(&DomainEvent::MemberJoined {
+                circle_id: circle.id.clone(),
+                member_id: member3.id.clone(),
+            })
+        );
+
+        let circle = circle.update(None, Some(Capacity::try_from(5)?))?;
+        assert_eq!(circle.members, vec![member3, member2]);
+        assert!(circle.waitlist.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_graduation_promotes_waitlist() {
+        let graduate = create_member(Grade::Fourth);
+        let waiting_graduate = create_member(Grade::Fourth);
+        let waiting = create_member(Grade::First);
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            create_owner(),
+            Capacity::try_from(2).unwrap(),
+            vec![graduate],
+            vec![waiting_graduate, waiting.clone()],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
+        );
+        let graduated = circle.graduate();
+        assert_eq!(graduated.members, vec![waiting]);
+        assert!(graduated.waitlist.is_empty());
     }
 
     #[test]
     fn test_add_4th_grade_member() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 10).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
         let member = create_member(Grade::Fourth);
         let error = circle.add_member(member).unwrap_err();
+        assert_eq!(error, DomainError::FourthGradeCannotJoin);
         assert_eq!(error.to_string(), "4th grade can't join circle");
     }
 
     #[test]
     fn test_remove_member() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 10).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
         let member = create_member(Grade::First);
         let circle = circle.add_member(member.clone()).unwrap();
         let circle = circle.remove_member(&member).unwrap();
@@ -237,20 +804,68 @@ mod tests {
     #[test]
     fn test_remove_owner() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner.clone(), 10).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner.clone(),
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
         let error = circle.remove_member(&owner).unwrap_err();
+        assert_eq!(error, DomainError::OwnerCannotBeRemoved);
         assert_eq!(error.to_string(), "Owner can't be removed");
     }
 
     #[test]
     fn test_remove_non_existent_member() {
         let owner = create_owner();
-        let circle = Circle::create("test circle".to_string(), owner, 10).unwrap();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
         let member = create_member(Grade::First);
         let error = circle.remove_member(&member).unwrap_err();
+        assert_eq!(error, DomainError::NotMember);
         assert_eq!(error.to_string(), "Member not found in circle");
     }
 
+    #[test]
+    fn test_member_count() {
+        let owner = create_owner();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner,
+            Capacity::try_from(2).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(circle.member_count(), 1);
+        assert!(!circle.is_full());
+        let circle = circle.add_member(create_member(Grade::First)).unwrap();
+        assert_eq!(circle.member_count(), 2);
+        assert!(circle.is_full());
+    }
+
+    #[test]
+    fn test_member() {
+        let owner = create_owner();
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner.clone(),
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap();
+        let member = create_member(Grade::First);
+        let circle = circle.add_member(member.clone()).unwrap();
+        assert_eq!(circle.member(&owner.id), Some(&owner));
+        assert_eq!(circle.member(&member.id), Some(&member));
+        assert_eq!(circle.member(&MemberId::gen()), None);
+    }
+
     #[test]
     fn test_graduate() {
         let owner = create_owner();
@@ -258,12 +873,402 @@ mod tests {
         let member2 = create_member(Grade::Fourth);
         let circle = Circle::reconstruct(
             CircleId::gen(),
-            "test circle".to_string(),
+            CircleName::try_from("test circle").unwrap(),
             owner,
-            10,
+            Capacity::try_from(10).unwrap(),
             vec![member1, member2],
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
         );
         let graduated_circle = circle.graduate();
         assert_eq!(graduated_circle.members.len(), 1);
     }
+
+    #[test]
+    fn test_rollover() {
+        let owner = create_owner();
+        let member1 = create_member(Grade::First);
+        let member2 = create_member(Grade::Third);
+        let member3 = create_member(Grade::Fourth);
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            owner.clone(),
+            Capacity::try_from(10).unwrap(),
+            vec![member1.clone(), member2.clone(), member3.clone()],
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
+        );
+        let rolled_over = circle.rollover();
+        assert_eq!(rolled_over.owner, owner);
+        assert_eq!(rolled_over.member(&member1.id), Some(&member1));
+        assert_eq!(rolled_over.member(&member2.id), Some(&member2));
+        assert!(rolled_over.member(&member3.id).is_none());
+    }
+
+    #[test]
+    fn test_transfer_ownership() -> anyhow::Result<()> {
+        let owner = create_owner();
+        let member = create_member(Grade::Third);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?;
+
+        let transferred = circle.clone().transfer_ownership(&member.id)?;
+        assert_eq!(transferred.owner, member);
+        assert_eq!(transferred.members, vec![owner.clone()]);
+
+        assert_eq!(
+            circle.clone().transfer_ownership(&MemberId::gen()),
+            Err(DomainError::NotMember)
+        );
+        // the owner is not a candidate for taking over from themselves
+        assert_eq!(
+            circle.transfer_ownership(&owner.id),
+            Err(DomainError::NotMember)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_transfer_ownership_invalid_grade() -> anyhow::Result<()> {
+        let freshman = create_member(Grade::First);
+        let sophomore = create_member(Grade::Second);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            create_owner(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(freshman.clone())?
+        .add_member(sophomore.clone())?;
+        assert_eq!(
+            circle.clone().transfer_ownership(&freshman.id),
+            Err(DomainError::InvalidOwnerGrade)
+        );
+        assert_eq!(
+            circle.clone().nominate_successor(&freshman.id),
+            Err(DomainError::InvalidSuccessorGrade)
+        );
+        // a 2nd grader can be nominated, since they are a 3rd grader by the time they take over
+        assert_eq!(
+            circle.clone().transfer_ownership(&sophomore.id),
+            Err(DomainError::InvalidOwnerGrade)
+        );
+        assert!(circle.nominate_successor(&sophomore.id).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rollover_hands_over_to_successor() -> anyhow::Result<()> {
+        let owner = Member {
+            grade: Grade::Fourth,
+            ..create_owner()
+        };
+        let successor = create_member(Grade::Second);
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+            vec![successor.clone()],
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
+        )
+        .nominate_successor(&successor.id)?;
+        assert_eq!(circle.successor_id, Some(successor.id.clone()));
+
+        let rolled_over = circle.rollover();
+        assert_eq!(rolled_over.owner, successor);
+        assert!(rolled_over.members.is_empty());
+        assert!(rolled_over.member(&owner.id).is_none());
+        assert_eq!(rolled_over.successor_id, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rollover_without_successor() -> anyhow::Result<()> {
+        let owner = Member {
+            grade: Grade::Fourth,
+            ..create_owner()
+        };
+        let members = vec![create_member(Grade::First), create_member(Grade::Third)];
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+            members.clone(),
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Active,
+            1,
+        );
+
+        let rolled_over = circle.rollover();
+        assert_eq!(rolled_over.status, CircleStatus::Dormant);
+        assert_eq!(rolled_over.owner.id, owner.id);
+        assert_eq!(
+            rolled_over.events.last(),
+            Some(&DomainEvent::StatusChanged {
+                circle_id: rolled_over.id.clone(),
+                status: CircleStatus::Dormant,
+            })
+        );
+
+        // the owner can still hand the circle over, and it can run again after that
+        let circle = rolled_over
+            .transfer_ownership(&members[1].id)
+            .and_then(Circle::reactivate)?;
+        assert_eq!(circle.owner.id, members[1].id);
+        assert_eq!(circle.status, CircleStatus::Active);
+
+        // a disbanded circle stays disbanded
+        let disbanded = Circle {
+            owner,
+            ..circle.disband()?
+        };
+        assert_eq!(disbanded.rollover().status, CircleStatus::Disbanded);
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_follows_member_count() -> anyhow::Result<()> {
+        let member1 = create_member(Grade::First);
+        let member2 = create_member(Grade::Second);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            create_owner(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member1.clone())?;
+        assert_eq!(circle.status, CircleStatus::Recruiting);
+
+        let circle = circle.add_member(member2.clone())?;
+        assert_eq!(circle.status, CircleStatus::Active);
+        assert_eq!(
+            circle.events.last(),
+            Some(&DomainEvent::StatusChanged {
+                circle_id: circle.id.clone(),
+                status: CircleStatus::Active,
+            })
+        );
+
+        let circle = circle.remove_member(&member1)?;
+        assert_eq!(circle.status, CircleStatus::Dormant);
+        // a dormant circle stays dormant until it is reactivated
+        let circle = circle.add_member(member1)?;
+        assert_eq!(circle.status, CircleStatus::Dormant);
+        assert_eq!(circle.reactivate()?.status, CircleStatus::Active);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graduation_makes_circle_dormant() {
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            create_owner(),
+            Capacity::try_from(10).unwrap(),
+            vec![create_member(Grade::First), create_member(Grade::Fourth)],
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Active,
+            1,
+        );
+        assert_eq!(circle.rollover().status, CircleStatus::Dormant);
+    }
+
+    #[test]
+    fn test_disband() -> anyhow::Result<()> {
+        let member = create_member(Grade::Second);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            create_owner(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?
+        .disband()?;
+        assert_eq!(circle.status, CircleStatus::Disbanded);
+
+        for result in [
+            circle.clone().disband(),
+            circle.clone().add_member(create_member(Grade::First)),
+            circle
+                .clone()
+                .update(Some(CircleName::try_from("renamed")?), None),
+            circle.clone().transfer_ownership(&member.id),
+            circle.clone().nominate_successor(&member.id),
+        ] {
+            assert_eq!(result, Err(DomainError::CircleDisbanded));
+        }
+        // leaving is still possible, and doesn't bring the circle back
+        let circle = circle.remove_member(&member)?;
+        assert_eq!(circle.status, CircleStatus::Disbanded);
+
+        let circle = circle.reactivate()?;
+        assert_eq!(circle.status, CircleStatus::Recruiting);
+        assert_eq!(
+            circle.reactivate(),
+            Err(DomainError::NotReactivatable(CircleStatus::Recruiting))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_assign_role() -> anyhow::Result<()> {
+        let owner = create_owner();
+        let member = create_member(Grade::Third);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?;
+        assert_eq!(circle.role(&owner.id), Some(Role::Owner));
+        assert_eq!(circle.role(&member.id), Some(Role::Member));
+        assert_eq!(circle.role(&MemberId::gen()), None);
+        assert_eq!(
+            circle.authorize(&member.id, Permission::Rename),
+            Err(DomainError::Forbidden(Permission::Rename))
+        );
+
+        let circle = circle.assign_role(&member.id, Role::ViceOwner)?;
+        assert_eq!(circle.role(&member.id), Some(Role::ViceOwner));
+        assert_eq!(circle.authorize(&member.id, Permission::Rename), Ok(()));
+        assert_eq!(
+            circle.events.last(),
+            Some(&DomainEvent::RoleAssigned {
+                circle_id: circle.id.clone(),
+                member_id: member.id.clone(),
+                role: Role::ViceOwner,
+            })
+        );
+
+        for (member_id, role) in [(&member.id, Role::Owner), (&owner.id, Role::Treasurer)] {
+            assert_eq!(
+                circle.clone().assign_role(member_id, role),
+                Err(DomainError::OwnerRoleNotAssignable)
+            );
+        }
+        assert_eq!(
+            circle
+                .clone()
+                .assign_role(&MemberId::gen(), Role::Treasurer),
+            Err(DomainError::NotMember)
+        );
+
+        // taking over the circle or leaving it drops the role
+        let transferred = circle.clone().transfer_ownership(&member.id)?;
+        assert_eq!(transferred.role(&member.id), Some(Role::Owner));
+        assert_eq!(transferred.role(&owner.id), Some(Role::Member));
+        assert!(transferred.roles.is_empty());
+        assert!(circle.remove_member(&member)?.roles.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_events() -> anyhow::Result<()> {
+        let owner = create_owner();
+        let member = create_member(Grade::First);
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?
+        .update(
+            Some(CircleName::try_from("renamed")?),
+            Some(Capacity::try_from(10)?),
+        )?
+        .add_member(member.clone())?
+        .remove_member(&member)?;
+        let circle_id = circle.id.clone();
+
+        assert_eq!(
+            circle.events,
+            vec![
+                DomainEvent::CircleCreated {
+                    circle_id: circle_id.clone(),
+                    name: CircleName::try_from("test circle")?,
+                    owner_id: owner.id.clone(),
+                },
+                DomainEvent::CircleRenamed {
+                    circle_id: circle_id.clone(),
+                    name: CircleName::try_from("renamed")?,
+                },
+                DomainEvent::MemberJoined {
+                    circle_id: circle_id.clone(),
+                    member_id: member.id.clone(),
+                },
+                DomainEvent::MemberLeft {
+                    circle_id: circle_id.clone(),
+                    member_id: member.id.clone(),
+                },
+            ]
+        );
+
+        // a reconstructed circle starts with a clean slate, and events don't affect equality
+        let reconstructed = Circle::reconstruct(
+            circle.id.clone(),
+            circle.name.clone(),
+            circle.owner.clone(),
+            circle.capacity,
+            circle.members.clone(),
+            circle.waitlist.clone(),
+            BTreeMap::new(),
+            None,
+            circle.status,
+            circle.version,
+        );
+        assert!(reconstructed.events.is_empty());
+        assert_eq!(reconstructed, circle);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graduate_events() {
+        let member1 = create_member(Grade::First);
+        let member2 = create_member(Grade::Fourth);
+        let circle = Circle::reconstruct(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            create_owner(),
+            Capacity::try_from(10).unwrap(),
+            vec![member1, member2.clone()],
+            vec![],
+            BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
+        );
+        let graduated = circle.graduate();
+        assert_eq!(
+            graduated.events,
+            vec![DomainEvent::MembersGraduated {
+                circle_id: graduated.id.clone(),
+                member_ids: vec![member2.id],
+            }]
+        );
+        // nobody left to graduate, so nothing new is recorded
+        assert_eq!(graduated.graduate().events.len(), 1);
+    }
 }
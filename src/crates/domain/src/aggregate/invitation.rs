@@ -0,0 +1,200 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::{
+    circle::Circle,
+    value_object::{
+        circle_id::CircleId, circle_status::CircleStatus, invitation_id::InvitationId,
+        invitation_status::InvitationStatus, member_id::MemberId,
+    },
+};
+use crate::error::DomainError;
+
+/// An owner inviting a specific member into the circle. It can be accepted once, until it expires
+/// or is revoked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Invitation {
+    pub id: InvitationId,
+    pub circle_id: CircleId,
+    pub member_id: MemberId,
+    pub expires_at: SystemTime,
+    pub status: InvitationStatus,
+}
+
+impl Invitation {
+    /// Whether the member fits into the circle is only checked when the invitation is accepted.
+    pub fn issue(
+        id: InvitationId,
+        circle: &Circle,
+        member_id: MemberId,
+        now: SystemTime,
+        valid_for: Duration,
+    ) -> Result<Self, DomainError> {
+        if circle.status == CircleStatus::Disbanded {
+            return Err(DomainError::CircleDisbanded);
+        }
+        if circle.member(&member_id).is_some() {
+            return Err(DomainError::AlreadyMember);
+        }
+        // whole seconds, which is all the storage keeps
+        let expires_at = (now + valid_for)
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| UNIX_EPOCH + Duration::from_secs(elapsed.as_secs()))
+            .unwrap_or(UNIX_EPOCH);
+        Ok(Invitation {
+            id,
+            circle_id: circle.id.clone(),
+            member_id,
+            expires_at,
+            status: InvitationStatus::Pending,
+        })
+    }
+
+    pub fn reconstruct(
+        id: InvitationId,
+        circle_id: CircleId,
+        member_id: MemberId,
+        expires_at: SystemTime,
+        status: InvitationStatus,
+    ) -> Self {
+        Invitation {
+            id,
+            circle_id,
+            member_id,
+            expires_at,
+            status,
+        }
+    }
+
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Uses up the invitation. The member is added on its own, through `Circle::add_member`.
+    pub fn accept(self, now: SystemTime) -> Result<Self, DomainError> {
+        self.ensure_pending()?;
+        if self.is_expired(now) {
+            return Err(DomainError::InvitationExpired);
+        }
+        Ok(Invitation {
+            status: InvitationStatus::Accepted,
+            ..self
+        })
+    }
+
+    pub fn revoke(self) -> Result<Self, DomainError> {
+        self.ensure_pending()?;
+        Ok(Invitation {
+            status: InvitationStatus::Revoked,
+            ..self
+        })
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.status != InvitationStatus::Pending {
+            return Err(DomainError::InvitationNotPending(self.status));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::{
+        member::Member,
+        value_object::{
+            age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+        },
+    };
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    fn create_circle() -> Circle {
+        Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21).unwrap(),
+                Grade::Third,
+                Major::ComputerScience,
+            ),
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_issue() {
+        let circle = create_circle();
+        let member_id = MemberId::gen();
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_500);
+        let invitation =
+            Invitation::issue(InvitationId::gen(), &circle, member_id.clone(), now, DAY).unwrap();
+        assert_eq!(invitation.circle_id, circle.id);
+        assert_eq!(invitation.member_id, member_id);
+        assert_eq!(invitation.status, InvitationStatus::Pending);
+        assert_eq!(
+            invitation.expires_at,
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000) + DAY
+        );
+
+        assert_eq!(
+            Invitation::issue(
+                InvitationId::gen(),
+                &circle,
+                circle.owner.id.clone(),
+                now,
+                DAY
+            ),
+            Err(DomainError::AlreadyMember)
+        );
+        assert_eq!(
+            Invitation::issue(
+                InvitationId::gen(),
+                &circle.disband().unwrap(),
+                member_id,
+                now,
+                DAY
+            ),
+            Err(DomainError::CircleDisbanded)
+        );
+    }
+
+    #[test]
+    fn test_accept_and_revoke() {
+        let now = SystemTime::now();
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &create_circle(),
+            MemberId::gen(),
+            now,
+            DAY,
+        )
+        .unwrap();
+
+        let accepted = invitation.clone().accept(now).unwrap();
+        assert_eq!(accepted.status, InvitationStatus::Accepted);
+        // single use
+        assert_eq!(
+            accepted.accept(now),
+            Err(DomainError::InvitationNotPending(
+                InvitationStatus::Accepted
+            ))
+        );
+        assert_eq!(
+            invitation.clone().accept(now + DAY),
+            Err(DomainError::InvitationExpired)
+        );
+
+        let revoked = invitation.revoke().unwrap();
+        assert_eq!(revoked.status, InvitationStatus::Revoked);
+        let error = revoked.accept(now).unwrap_err();
+        assert_eq!(
+            error,
+            DomainError::InvitationNotPending(InvitationStatus::Revoked)
+        );
+        assert_eq!(error.to_string(), "Invitation is already Revoked");
+    }
+}
@@ -0,0 +1,173 @@
+use super::{
+    circle::Circle,
+    value_object::{
+        circle_id::CircleId, circle_status::CircleStatus, join_request_id::JoinRequestId,
+        join_request_status::JoinRequestStatus, member_id::MemberId,
+    },
+};
+use crate::error::DomainError;
+
+/// A member asking to join a circle. It stays pending until the circle decides on it or the
+/// member withdraws it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JoinRequest {
+    pub id: JoinRequestId,
+    pub circle_id: CircleId,
+    pub member_id: MemberId,
+    pub status: JoinRequestStatus,
+}
+
+impl JoinRequest {
+    /// Whether the member fits into the circle is only checked when the request is approved.
+    pub fn submit(
+        id: JoinRequestId,
+        circle: &Circle,
+        member_id: MemberId,
+    ) -> Result<Self, DomainError> {
+        if circle.status == CircleStatus::Disbanded {
+            return Err(DomainError::CircleDisbanded);
+        }
+        if circle.member(&member_id).is_some() {
+            return Err(DomainError::AlreadyMember);
+        }
+        if circle.waitlist_position(&member_id).is_some() {
+            return Err(DomainError::AlreadyWaitlisted);
+        }
+        Ok(JoinRequest {
+            id,
+            circle_id: circle.id.clone(),
+            member_id,
+            status: JoinRequestStatus::Pending,
+        })
+    }
+
+    pub fn reconstruct(
+        id: JoinRequestId,
+        circle_id: CircleId,
+        member_id: MemberId,
+        status: JoinRequestStatus,
+    ) -> Self {
+        JoinRequest {
+            id,
+            circle_id,
+            member_id,
+            status,
+        }
+    }
+
+    /// Accepts the request. The member is added on its own, through `Circle::add_member`.
+    pub fn approve(self) -> Result<Self, DomainError> {
+        self.decide(JoinRequestStatus::Approved)
+    }
+
+    pub fn reject(self) -> Result<Self, DomainError> {
+        self.decide(JoinRequestStatus::Rejected)
+    }
+
+    pub fn withdraw(self) -> Result<Self, DomainError> {
+        self.decide(JoinRequestStatus::Withdrawn)
+    }
+
+    // only a pending request can be decided, and only once
+    fn decide(self, status: JoinRequestStatus) -> Result<Self, DomainError> {
+        if self.status != JoinRequestStatus::Pending {
+            return Err(DomainError::JoinRequestNotPending(self.status));
+        }
+        Ok(JoinRequest { status, ..self })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate::{
+        member::Member,
+        value_object::{
+            age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+        },
+    };
+
+    fn create_circle() -> Circle {
+        Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("test circle").unwrap(),
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21).unwrap(),
+                Grade::Third,
+                Major::ComputerScience,
+            ),
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_submit() {
+        let circle = create_circle();
+        let member_id = MemberId::gen();
+        let join_request =
+            JoinRequest::submit(JoinRequestId::gen(), &circle, member_id.clone()).unwrap();
+        assert_eq!(join_request.circle_id, circle.id);
+        assert_eq!(join_request.member_id, member_id);
+        assert_eq!(join_request.status, JoinRequestStatus::Pending);
+
+        assert_eq!(
+            JoinRequest::submit(JoinRequestId::gen(), &circle, circle.owner.id.clone()),
+            Err(DomainError::AlreadyMember)
+        );
+        assert_eq!(
+            JoinRequest::submit(
+                JoinRequestId::gen(),
+                &circle.clone().disband().unwrap(),
+                member_id
+            ),
+            Err(DomainError::CircleDisbanded)
+        );
+
+        // a member already queued for a seat waits for it rather than asking again
+        let full = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("full circle").unwrap(),
+            circle.owner.clone(),
+            Capacity::try_from(1).unwrap(),
+        )
+        .unwrap();
+        let waiting = Member::new(
+            MemberId::gen(),
+            "waiting".to_string(),
+            Age::try_from(19).unwrap(),
+            Grade::First,
+            Major::Art,
+        );
+        let full = full.add_member(waiting.clone()).unwrap();
+        assert_eq!(
+            JoinRequest::submit(JoinRequestId::gen(), &full, waiting.id),
+            Err(DomainError::AlreadyWaitlisted)
+        );
+    }
+
+    #[test]
+    fn test_decide() {
+        let join_request =
+            JoinRequest::submit(JoinRequestId::gen(), &create_circle(), MemberId::gen()).unwrap();
+        assert_eq!(
+            join_request.clone().approve().unwrap().status,
+            JoinRequestStatus::Approved
+        );
+        assert_eq!(
+            join_request.clone().withdraw().unwrap().status,
+            JoinRequestStatus::Withdrawn
+        );
+
+        let rejected = join_request.reject().unwrap();
+        assert_eq!(rejected.status, JoinRequestStatus::Rejected);
+        let error = rejected.approve().unwrap_err();
+        assert_eq!(
+            error,
+            DomainError::JoinRequestNotPending(JoinRequestStatus::Rejected)
+        );
+        assert_eq!(error.to_string(), "Join request is already Rejected");
+    }
+}
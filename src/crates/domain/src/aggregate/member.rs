@@ -1,18 +1,18 @@
-use super::value_object::{grade::Grade, major::Major, member_id::MemberId};
+use super::value_object::{age::Age, grade::Grade, major::Major, member_id::MemberId};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Member {
     pub id: MemberId,
     pub name: String,
-    pub age: i16,
+    pub age: Age,
     pub grade: Grade,
     pub major: Major,
 }
 
 impl Member {
-    pub fn new(name: String, age: i16, grade: Grade, major: Major) -> Self {
+    pub fn new(id: MemberId, name: String, age: Age, grade: Grade, major: Major) -> Self {
         Member {
-            id: MemberId::gen(),
+            id,
             name,
             age,
             grade,
@@ -20,7 +20,7 @@ impl Member {
         }
     }
 
-    pub fn reconstruct(id: MemberId, name: String, age: i16, grade: Grade, major: Major) -> Self {
+    pub fn reconstruct(id: MemberId, name: String, age: Age, grade: Grade, major: Major) -> Self {
         Member {
             id,
             name,
@@ -30,21 +30,45 @@ impl Member {
         }
     }
 
+    /// Moves the member up one grade. A 4th-grade member stays where they are.
+    pub fn promote(self) -> Self {
+        Member {
+            grade: self.grade.next().unwrap_or(self.grade),
+            ..self
+        }
+    }
+
+    /// Changes the person's details. The grade only moves through the academic-year rollover.
+    pub fn update(self, name: Option<String>, age: Option<Age>, major: Option<Major>) -> Self {
+        Member {
+            name: name.unwrap_or(self.name),
+            age: age.unwrap_or(self.age),
+            major: major.unwrap_or(self.major),
+            ..self
+        }
+    }
+
     pub fn is_adult(&self) -> bool {
-        self.age >= 20
+        self.age.is_adult()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::aggregate::value_object::{grade::Grade, major::Major};
+    use crate::aggregate::value_object::{age::Age, grade::Grade, major::Major};
 
     #[test]
     fn test_member_new() {
-        let member = Member::new("test".to_string(), 20, Grade::First, Major::ComputerScience);
-        assert_eq!(member.name, "test");
-        assert_eq!(member.age, 20);
+        let member = Member::new(
+            MemberId::gen(),
+            "test".to_string(),
+            Age::try_from(20).unwrap(),
+            Grade::First,
+            Major::ComputerScience,
+        );
+        assert_eq!(member.name.as_str(), "test");
+        assert_eq!(i16::from(member.age), 20);
         assert_eq!(member.grade, Grade::First);
         assert_eq!(member.major, Major::ComputerScience);
     }
@@ -55,22 +79,55 @@ mod tests {
         let member = Member::reconstruct(
             member_id.clone(),
             "test".to_string(),
-            20,
+            Age::try_from(20).unwrap(),
             Grade::First,
             Major::ComputerScience,
         );
         assert_eq!(member.id, member_id);
-        assert_eq!(member.name, "test");
-        assert_eq!(member.age, 20);
+        assert_eq!(member.name.as_str(), "test");
+        assert_eq!(i16::from(member.age), 20);
         assert_eq!(member.grade, Grade::First);
         assert_eq!(member.major, Major::ComputerScience);
     }
 
     #[test]
     fn test_is_adult() {
-        let member1 = Member::new("test".to_string(), 20, Grade::First, Major::ComputerScience);
+        let member1 = Member::new(
+            MemberId::gen(),
+            "test".to_string(),
+            Age::try_from(20).unwrap(),
+            Grade::First,
+            Major::ComputerScience,
+        );
         assert!(member1.is_adult());
-        let member2 = Member::new("test".to_string(), 19, Grade::First, Major::ComputerScience);
+        let member2 = Member::new(
+            MemberId::gen(),
+            "test".to_string(),
+            Age::try_from(19).unwrap(),
+            Grade::First,
+            Major::ComputerScience,
+        );
         assert!(!member2.is_adult());
     }
+
+    #[test]
+    fn test_update() {
+        let member = Member::new(
+            MemberId::gen(),
+            "test".to_string(),
+            Age::try_from(19).unwrap(),
+            Grade::Second,
+            Major::ComputerScience,
+        );
+        let updated = member.clone().update(
+            Some("renamed".to_string()),
+            Some(Age::try_from(20).unwrap()),
+            None,
+        );
+        assert_eq!(updated.id, member.id);
+        assert_eq!(updated.name.as_str(), "renamed");
+        assert_eq!(i16::from(updated.age), 20);
+        assert_eq!(updated.grade, Grade::Second);
+        assert_eq!(updated.major, Major::ComputerScience);
+    }
 }
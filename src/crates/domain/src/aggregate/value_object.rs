@@ -1,4 +1,28 @@
+pub mod academic_year;
+pub mod age;
+pub mod capacity;
 pub mod circle_id;
+pub mod circle_name;
+pub mod circle_status;
 pub mod grade;
+pub mod invitation_id;
+pub mod invitation_status;
+pub mod join_request_id;
+pub mod join_request_status;
 pub mod major;
 pub mod member_id;
+pub mod permission;
+pub mod role;
+
+use uuid::Uuid;
+
+use crate::error::DomainError;
+
+/// Parses an id in the canonical lowercase hyphenated form only, so every id has exactly one
+/// spelling. `kind` names the id in the error.
+pub(crate) fn parse_uuid(s: &str, kind: &'static str) -> Result<Uuid, DomainError> {
+    if s.len() != 36 || s.bytes().any(|b| b.is_ascii_uppercase()) {
+        return Err(DomainError::InvalidId(kind));
+    }
+    Uuid::try_parse(s).map_err(|_| DomainError::InvalidId(kind))
+}
@@ -0,0 +1,57 @@
+use crate::error::DomainError;
+
+/// A school year, named by the calendar year it starts in.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AcademicYear(i16);
+
+impl AcademicYear {
+    pub const MIN: i16 = 2000;
+    pub const MAX: i16 = 2100;
+}
+
+impl std::convert::TryFrom<i16> for AcademicYear {
+    type Error = DomainError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(DomainError::InvalidAcademicYear(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<AcademicYear> for i16 {
+    fn from(academic_year: AcademicYear) -> Self {
+        academic_year.0
+    }
+}
+
+impl std::fmt::Display for AcademicYear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        for n in [AcademicYear::MIN, 2026, AcademicYear::MAX] {
+            assert_eq!(i16::from(AcademicYear::try_from(n)?), n);
+        }
+        assert!(AcademicYear::try_from(2026)? > AcademicYear::try_from(2025)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_academic_year() {
+        for n in [-1, 26, AcademicYear::MIN - 1, AcademicYear::MAX + 1] {
+            assert_eq!(
+                AcademicYear::try_from(n),
+                Err(DomainError::InvalidAcademicYear(n))
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error::DomainError;
+
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Age(i16);
+
+impl Age {
+    pub const MIN: i16 = 15;
+    pub const MAX: i16 = 100;
+    const ADULT: i16 = 20;
+
+    pub fn is_adult(self) -> bool {
+        self.0 >= Self::ADULT
+    }
+}
+
+impl std::convert::TryFrom<i16> for Age {
+    type Error = DomainError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(DomainError::InvalidAge(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<Age> for i16 {
+    fn from(age: Age) -> Self {
+        age.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        for n in [Age::MIN, 20, Age::MAX] {
+            assert_eq!(i16::from(Age::try_from(n)?), n);
+        }
+        assert!(Age::try_from(20)?.is_adult());
+        assert!(!Age::try_from(19)?.is_adult());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_age() {
+        for n in [-1, Age::MIN - 1, Age::MAX + 1] {
+            assert_eq!(Age::try_from(n), Err(DomainError::InvalidAge(n)));
+        }
+    }
+}
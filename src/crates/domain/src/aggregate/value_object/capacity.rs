@@ -0,0 +1,60 @@
+use crate::error::DomainError;
+
+/// How many people a circle can hold, owner included.
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Capacity(i16);
+
+impl Capacity {
+    pub const MIN: i16 = 1;
+    pub const MAX: i16 = 100;
+
+    pub fn can_hold(self, people: usize) -> bool {
+        people <= usize::from(self)
+    }
+}
+
+impl std::convert::TryFrom<i16> for Capacity {
+    type Error = DomainError;
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(DomainError::InvalidCapacity(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<Capacity> for i16 {
+    fn from(capacity: Capacity) -> Self {
+        capacity.0
+    }
+}
+
+// always positive, so the conversion can't wrap
+impl From<Capacity> for usize {
+    fn from(capacity: Capacity) -> Self {
+        capacity.0 as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        for n in [Capacity::MIN, 10, Capacity::MAX] {
+            assert_eq!(i16::from(Capacity::try_from(n)?), n);
+        }
+        assert!(Capacity::try_from(3)?.can_hold(3));
+        assert!(!Capacity::try_from(3)?.can_hold(4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_capacity() {
+        for n in [-1, 0, Capacity::MAX + 1] {
+            assert_eq!(Capacity::try_from(n), Err(DomainError::InvalidCapacity(n)));
+        }
+    }
+}
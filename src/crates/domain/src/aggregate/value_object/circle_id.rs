@@ -1,50 +1,38 @@
 use std::fmt;
-use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
-use rand::distr::Alphanumeric;
-use rand::RngExt;
+use uuid::Uuid;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CircleId(String);
+use super::parse_uuid;
+use crate::error::DomainError;
+
+/// A UUIDv7, so ids generated later sort after earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CircleId(Uuid);
 
 impl CircleId {
     pub fn gen() -> Self {
-        let mut rng = rand::rng();
-        let chars: String = (0..36).map(|_| rng.sample(Alphanumeric) as char).collect();
-        Self(chars)
-    }
-}
-
-impl Hash for CircleId {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
+        Self(Uuid::now_v7())
     }
 }
 
 impl fmt::Display for CircleId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.0.hyphenated())
     }
 }
 
 impl FromStr for CircleId {
-    type Err = anyhow::Error;
+    type Err = DomainError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.to_string()))
-    }
-}
-
-impl From<i16> for CircleId {
-    fn from(id: i16) -> Self {
-        Self(id.to_string())
+        parse_uuid(s, "Circle").map(Self)
     }
 }
 
 impl From<CircleId> for String {
     fn from(circle_id: CircleId) -> Self {
-        circle_id.0
+        circle_id.to_string()
     }
 }
 
@@ -55,11 +43,28 @@ mod tests {
     #[test]
     fn test() -> anyhow::Result<()> {
         let circle_id = CircleId::gen();
-        assert_eq!(circle_id.to_string().len(), 36);
+        assert_eq!(CircleId::from_str(&circle_id.to_string())?, circle_id);
+        assert!(CircleId::gen() > circle_id);
 
-        let str = "0123456789abcdef0123456789abcdef";
+        let str = "01890a5d-ac96-774b-bcce-b302099a8057";
         let circle_id = CircleId::from_str(str)?;
         assert_eq!(circle_id.to_string(), str);
         Ok(())
     }
+
+    #[test]
+    fn test_invalid_circle_id() {
+        for str in [
+            "0",
+            "0123456789abcdef0123456789abcdef",
+            "01890a5d-ac96-774b-bcce-b302099a805z",
+            "{01890a5d-ac96-774b-bcce-b302099a8057}",
+            "01890A5D-AC96-774B-BCCE-B302099A8057",
+        ] {
+            assert_eq!(
+                CircleId::from_str(str),
+                Err(DomainError::InvalidId("Circle"))
+            );
+        }
+    }
 }
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::error::DomainError;
+
+/// A circle's display name: trimmed, non-empty and at most `MAX_LENGTH` characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CircleName(String);
+
+impl CircleName {
+    pub const MAX_LENGTH: usize = 50;
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CircleName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::convert::TryFrom<String> for CircleName {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let name = value.trim();
+        if name.is_empty() {
+            return Err(DomainError::EmptyCircleName);
+        }
+        if name.chars().count() > Self::MAX_LENGTH {
+            return Err(DomainError::CircleNameTooLong);
+        }
+        Ok(Self(name.to_string()))
+    }
+}
+
+impl std::convert::TryFrom<&str> for CircleName {
+    type Error = DomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+impl From<CircleName> for String {
+    fn from(name: CircleName) -> Self {
+        name.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        assert_eq!(
+            CircleName::try_from("  Music club ")?.as_str(),
+            "Music club"
+        );
+        let longest = "a".repeat(CircleName::MAX_LENGTH);
+        assert_eq!(
+            String::from(CircleName::try_from(longest.clone())?),
+            longest
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_circle_name() {
+        assert_eq!(
+            CircleName::try_from("   "),
+            Err(DomainError::EmptyCircleName)
+        );
+        assert_eq!(
+            CircleName::try_from("a".repeat(CircleName::MAX_LENGTH + 1)),
+            Err(DomainError::CircleNameTooLong)
+        );
+    }
+}
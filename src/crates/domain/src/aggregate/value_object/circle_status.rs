@@ -0,0 +1,66 @@
+use crate::error::DomainError;
+
+/// Where a circle is in its lifecycle.
+#[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
+pub enum CircleStatus {
+    /// Not enough members to run yet.
+    Recruiting,
+    Active,
+    /// Was active, but has since dropped below the members it needs to run.
+    Dormant,
+    /// Closed by its owner; it takes no new members and can't be changed until reactivated.
+    Disbanded,
+}
+
+impl std::fmt::Display for CircleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            CircleStatus::Recruiting => "Recruiting",
+            CircleStatus::Active => "Active",
+            CircleStatus::Dormant => "Dormant",
+            CircleStatus::Disbanded => "Disbanded",
+        })
+    }
+}
+
+impl std::convert::From<CircleStatus> for String {
+    fn from(value: CircleStatus) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::convert::TryFrom<&str> for CircleStatus {
+    type Error = DomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "Recruiting" => CircleStatus::Recruiting,
+            "Active" => CircleStatus::Active,
+            "Dormant" => CircleStatus::Dormant,
+            "Disbanded" => CircleStatus::Disbanded,
+            _ => return Err(DomainError::InvalidCircleStatus(value.to_string())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        for (v, s) in [
+            (CircleStatus::Recruiting, "Recruiting"),
+            (CircleStatus::Active, "Active"),
+            (CircleStatus::Dormant, "Dormant"),
+            (CircleStatus::Disbanded, "Disbanded"),
+        ] {
+            assert_eq!(CircleStatus::try_from(s), Ok(v));
+            assert_eq!(String::from(v), s);
+        }
+        assert_eq!(
+            CircleStatus::try_from("active"),
+            Err(DomainError::InvalidCircleStatus("active".to_string()))
+        );
+    }
+}
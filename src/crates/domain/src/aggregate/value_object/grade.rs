@@ -1,3 +1,5 @@
+use crate::error::DomainError;
+
 #[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
 pub enum Grade {
     First,
@@ -6,6 +8,18 @@ pub enum Grade {
     Fourth,
 }
 
+impl Grade {
+    /// The grade for the next academic year; `None` for 4th grade, who graduate instead.
+    pub fn next(self) -> Option<Grade> {
+        match self {
+            Grade::First => Some(Grade::Second),
+            Grade::Second => Some(Grade::Third),
+            Grade::Third => Some(Grade::Fourth),
+            Grade::Fourth => None,
+        }
+    }
+}
+
 impl std::convert::From<Grade> for i16 {
     fn from(value: Grade) -> Self {
         match value {
@@ -18,7 +32,7 @@ impl std::convert::From<Grade> for i16 {
 }
 
 impl std::convert::TryFrom<i16> for Grade {
-    type Error = anyhow::Error;
+    type Error = DomainError;
 
     fn try_from(value: i16) -> Result<Self, Self::Error> {
         Ok(match value {
@@ -26,7 +40,7 @@ impl std::convert::TryFrom<i16> for Grade {
             2 => Grade::Second,
             3 => Grade::Third,
             4 => Grade::Fourth,
-            _ => anyhow::bail!("error"),
+            _ => return Err(DomainError::InvalidGrade(value)),
         })
     }
 }
@@ -48,4 +62,17 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(Grade::First.next(), Some(Grade::Second));
+        assert_eq!(Grade::Second.next(), Some(Grade::Third));
+        assert_eq!(Grade::Third.next(), Some(Grade::Fourth));
+        assert_eq!(Grade::Fourth.next(), None);
+    }
+
+    #[test]
+    fn test_invalid_grade() {
+        assert_eq!(Grade::try_from(5), Err(DomainError::InvalidGrade(5)));
+    }
 }
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use super::parse_uuid;
+use crate::error::DomainError;
+
+/// A UUIDv7, so ids generated later sort after earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InvitationId(Uuid);
+
+impl InvitationId {
+    pub fn gen() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl fmt::Display for InvitationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl FromStr for InvitationId {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_uuid(s, "Invitation").map(Self)
+    }
+}
+
+impl From<InvitationId> for String {
+    fn from(invitation_id: InvitationId) -> Self {
+        invitation_id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let invitation_id = InvitationId::gen();
+        assert_eq!(
+            InvitationId::from_str(&invitation_id.to_string())?,
+            invitation_id
+        );
+        assert!(InvitationId::gen() > invitation_id);
+
+        let str = "01890a5d-ac96-774b-bcce-b302099a8057";
+        let invitation_id = InvitationId::from_str(str)?;
+        assert_eq!(invitation_id.to_string(), str);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_invitation_id() {
+        for str in [
+            "0",
+            "0123456789abcdef0123456789abcdef",
+            "01890a5d-ac96-774b-bcce-b302099a805z",
+            "{01890a5d-ac96-774b-bcce-b302099a8057}",
+        ] {
+            assert_eq!(
+                InvitationId::from_str(str),
+                Err(DomainError::InvalidId("Invitation"))
+            );
+        }
+    }
+}
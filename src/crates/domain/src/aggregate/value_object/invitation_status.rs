@@ -0,0 +1,59 @@
+use crate::error::DomainError;
+
+#[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    /// Taken back by the circle before it was accepted.
+    Revoked,
+}
+
+impl std::fmt::Display for InvitationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            InvitationStatus::Pending => "Pending",
+            InvitationStatus::Accepted => "Accepted",
+            InvitationStatus::Revoked => "Revoked",
+        })
+    }
+}
+
+impl std::convert::From<InvitationStatus> for String {
+    fn from(value: InvitationStatus) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::convert::TryFrom<&str> for InvitationStatus {
+    type Error = DomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "Pending" => InvitationStatus::Pending,
+            "Accepted" => InvitationStatus::Accepted,
+            "Revoked" => InvitationStatus::Revoked,
+            _ => return Err(DomainError::InvalidInvitationStatus(value.to_string())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        for (v, s) in [
+            (InvitationStatus::Pending, "Pending"),
+            (InvitationStatus::Accepted, "Accepted"),
+            (InvitationStatus::Revoked, "Revoked"),
+        ] {
+            assert_eq!(InvitationStatus::try_from(s), Ok(v));
+            assert_eq!(String::from(v), s);
+        }
+        assert_eq!(
+            InvitationStatus::try_from("pending"),
+            Err(DomainError::InvalidInvitationStatus("pending".to_string()))
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use super::parse_uuid;
+use crate::error::DomainError;
+
+/// A UUIDv7, so ids generated later sort after earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct JoinRequestId(Uuid);
+
+impl JoinRequestId {
+    pub fn gen() -> Self {
+        Self(Uuid::now_v7())
+    }
+}
+
+impl fmt::Display for JoinRequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl FromStr for JoinRequestId {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_uuid(s, "Join request").map(Self)
+    }
+}
+
+impl From<JoinRequestId> for String {
+    fn from(join_request_id: JoinRequestId) -> Self {
+        join_request_id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() -> anyhow::Result<()> {
+        let join_request_id = JoinRequestId::gen();
+        assert_eq!(
+            JoinRequestId::from_str(&join_request_id.to_string())?,
+            join_request_id
+        );
+        assert!(JoinRequestId::gen() > join_request_id);
+
+        let str = "01890a5d-ac96-774b-bcce-b302099a8057";
+        let join_request_id = JoinRequestId::from_str(str)?;
+        assert_eq!(join_request_id.to_string(), str);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_join_request_id() {
+        for str in [
+            "0",
+            "0123456789abcdef0123456789abcdef",
+            "01890a5d-ac96-774b-bcce-b302099a805z",
+            "{01890a5d-ac96-774b-bcce-b302099a8057}",
+        ] {
+            assert_eq!(
+                JoinRequestId::from_str(str),
+                Err(DomainError::InvalidId("Join request"))
+            );
+        }
+    }
+}
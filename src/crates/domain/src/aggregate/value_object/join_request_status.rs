@@ -0,0 +1,63 @@
+use crate::error::DomainError;
+
+#[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
+pub enum JoinRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    /// Taken back by the member who asked.
+    Withdrawn,
+}
+
+impl std::fmt::Display for JoinRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            JoinRequestStatus::Pending => "Pending",
+            JoinRequestStatus::Approved => "Approved",
+            JoinRequestStatus::Rejected => "Rejected",
+            JoinRequestStatus::Withdrawn => "Withdrawn",
+        })
+    }
+}
+
+impl std::convert::From<JoinRequestStatus> for String {
+    fn from(value: JoinRequestStatus) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::convert::TryFrom<&str> for JoinRequestStatus {
+    type Error = DomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "Pending" => JoinRequestStatus::Pending,
+            "Approved" => JoinRequestStatus::Approved,
+            "Rejected" => JoinRequestStatus::Rejected,
+            "Withdrawn" => JoinRequestStatus::Withdrawn,
+            _ => return Err(DomainError::InvalidJoinRequestStatus(value.to_string())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        for (v, s) in [
+            (JoinRequestStatus::Pending, "Pending"),
+            (JoinRequestStatus::Approved, "Approved"),
+            (JoinRequestStatus::Rejected, "Rejected"),
+            (JoinRequestStatus::Withdrawn, "Withdrawn"),
+        ] {
+            assert_eq!(JoinRequestStatus::try_from(s), Ok(v));
+            assert_eq!(String::from(v), s);
+        }
+        assert_eq!(
+            JoinRequestStatus::try_from("pending"),
+            Err(DomainError::InvalidJoinRequestStatus("pending".to_string()))
+        );
+    }
+}
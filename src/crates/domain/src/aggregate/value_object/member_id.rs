@@ -1,66 +1,70 @@
 use std::fmt;
-use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
-use rand::distr::Alphanumeric;
-use rand::RngExt;
+use uuid::Uuid;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct MemberId(String);
+use super::parse_uuid;
+use crate::error::DomainError;
+
+/// A UUIDv7, so ids generated later sort after earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MemberId(Uuid);
 
 impl MemberId {
     pub fn gen() -> Self {
-        let mut rng = rand::rng();
-        let chars: String = (0..36).map(|_| rng.sample(Alphanumeric) as char).collect();
-        Self(chars)
-    }
-}
-
-impl Hash for MemberId {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.hash(state);
+        Self(Uuid::now_v7())
     }
 }
 
 impl fmt::Display for MemberId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.0.hyphenated())
     }
 }
 
-impl std::str::FromStr for MemberId {
-    type Err = anyhow::Error;
+impl FromStr for MemberId {
+    type Err = DomainError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.to_string()))
+        parse_uuid(s, "Member").map(Self)
     }
 }
 
-impl std::convert::From<i16> for MemberId {
-    fn from(id: i16) -> Self {
-        Self(id.to_string())
-    }
-}
-
-impl std::convert::From<MemberId> for String {
+impl From<MemberId> for String {
     fn from(member_id: MemberId) -> Self {
-        member_id.0
+        member_id.to_string()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
-
     use super::*;
 
     #[test]
     fn test() -> anyhow::Result<()> {
         let member_id = MemberId::gen();
-        assert_eq!(member_id.to_string().len(), 36);
+        assert_eq!(MemberId::from_str(&member_id.to_string())?, member_id);
+        assert!(MemberId::gen() > member_id);
 
-        let str = "0123456789abcdef0123456789abcdef";
+        let str = "01890a5d-ac96-774b-bcce-b302099a8057";
         let member_id = MemberId::from_str(str)?;
         assert_eq!(member_id.to_string(), str);
         Ok(())
     }
+
+    #[test]
+    fn test_invalid_member_id() {
+        for str in [
+            "0",
+            "0123456789abcdef0123456789abcdef",
+            "01890a5d-ac96-774b-bcce-b302099a805z",
+            "{01890a5d-ac96-774b-bcce-b302099a8057}",
+            "01890A5D-AC96-774B-BCCE-B302099A8057",
+        ] {
+            assert_eq!(
+                MemberId::from_str(str),
+                Err(DomainError::InvalidId("Member"))
+            );
+        }
+    }
 }
@@ -0,0 +1,25 @@
+/// Something only some roles in a circle may do; see `Role::can`.
+#[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Permission {
+    Rename,
+    ChangeCapacity,
+    RemoveMember,
+    /// Covers deciding on join requests and inviting members.
+    ApproveJoin,
+    AssignRole,
+    /// Covers handing the circle over and disbanding, reactivating or deleting it.
+    ManageCircle,
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Permission::Rename => "rename the circle",
+            Permission::ChangeCapacity => "change the capacity",
+            Permission::RemoveMember => "remove members",
+            Permission::ApproveJoin => "approve joins",
+            Permission::AssignRole => "assign roles",
+            Permission::ManageCircle => "manage the circle",
+        })
+    }
+}
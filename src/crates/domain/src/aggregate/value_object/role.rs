@@ -0,0 +1,103 @@
+use super::permission::Permission;
+use crate::error::DomainError;
+
+/// What a member does in their circle. The owner is always `Owner`; everyone else is a plain
+/// `Member` unless the owner has given them another role.
+#[derive(Copy, Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Role {
+    Owner,
+    ViceOwner,
+    Treasurer,
+    Member,
+}
+
+impl Role {
+    /// The permission matrix: the owner may do anything, the vice-owner helps run the circle's
+    /// membership, and the treasurer looks after the budget the capacity implies.
+    pub fn can(self, permission: Permission) -> bool {
+        match permission {
+            Permission::Rename | Permission::RemoveMember | Permission::ApproveJoin => {
+                matches!(self, Role::Owner | Role::ViceOwner)
+            }
+            Permission::ChangeCapacity => matches!(self, Role::Owner | Role::Treasurer),
+            Permission::AssignRole | Permission::ManageCircle => self == Role::Owner,
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Role::Owner => "owner",
+            Role::ViceOwner => "vice_owner",
+            Role::Treasurer => "treasurer",
+            Role::Member => "member",
+        })
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(value: Role) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::convert::TryFrom<&str> for Role {
+    type Error = DomainError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "owner" => Role::Owner,
+            "vice_owner" => Role::ViceOwner,
+            "treasurer" => Role::Treasurer,
+            "member" => Role::Member,
+            _ => return Err(DomainError::InvalidRole(value.to_string())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        for (v, s) in [
+            (Role::Owner, "owner"),
+            (Role::ViceOwner, "vice_owner"),
+            (Role::Treasurer, "treasurer"),
+            (Role::Member, "member"),
+        ] {
+            assert_eq!(Role::try_from(s), Ok(v));
+            assert_eq!(String::from(v), s);
+        }
+        assert_eq!(
+            Role::try_from("Owner"),
+            Err(DomainError::InvalidRole("Owner".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_can() {
+        for (role, allowed) in [
+            (Role::Owner, [true, true, true, true, true, true]),
+            (Role::ViceOwner, [true, false, true, true, false, false]),
+            (Role::Treasurer, [false, true, false, false, false, false]),
+            (Role::Member, [false, false, false, false, false, false]),
+        ] {
+            for (permission, allowed) in [
+                Permission::Rename,
+                Permission::ChangeCapacity,
+                Permission::RemoveMember,
+                Permission::ApproveJoin,
+                Permission::AssignRole,
+                Permission::ManageCircle,
+            ]
+            .into_iter()
+            .zip(allowed)
+            {
+                assert_eq!(role.can(permission), allowed, "{role} {permission}");
+            }
+        }
+    }
+}
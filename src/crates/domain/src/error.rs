@@ -0,0 +1,71 @@
+use crate::aggregate::value_object::{
+    academic_year::AcademicYear, age::Age, capacity::Capacity, circle_name::CircleName,
+    circle_status::CircleStatus, invitation_status::InvitationStatus,
+    join_request_status::JoinRequestStatus, permission::Permission,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DomainError {
+    #[error("{0} not found")]
+    NotFound(&'static str),
+    #[error("Invalid {0} id")]
+    InvalidId(&'static str),
+    #[error("{0} already exists")]
+    Duplicate(&'static str),
+    #[error("{0} has been modified concurrently")]
+    VersionConflict(&'static str),
+    #[error("Owner must be 3rd grade")]
+    InvalidOwnerGrade,
+    #[error("Successor must be 2nd or 3rd grade")]
+    InvalidSuccessorGrade,
+    #[error("Capacity must be between {min} and {max}, got {0}", min = Capacity::MIN, max = Capacity::MAX)]
+    InvalidCapacity(i16),
+    #[error("Capacity can't be lower than the current {0} members")]
+    CapacityBelowMemberCount(usize),
+    #[error("Invalid grade: {0}")]
+    InvalidGrade(i16),
+    #[error("Age must be between {min} and {max}, got {0}", min = Age::MIN, max = Age::MAX)]
+    InvalidAge(i16),
+    #[error("Circle name must not be empty")]
+    EmptyCircleName,
+    #[error("Circle name must be at most {max} characters", max = CircleName::MAX_LENGTH)]
+    CircleNameTooLong,
+    #[error("4th grade can't join circle")]
+    FourthGradeCannotJoin,
+    #[error("Owner can't be removed")]
+    OwnerCannotBeRemoved,
+    #[error("Member not found in circle")]
+    NotMember,
+    #[error("Member already belongs to the circle")]
+    AlreadyMember,
+    #[error("Member is already on the waitlist")]
+    AlreadyWaitlisted,
+    #[error("Invalid circle status: {0}")]
+    InvalidCircleStatus(String),
+    #[error("Circle has been disbanded")]
+    CircleDisbanded,
+    #[error("Circle is {0} and can't be reactivated")]
+    NotReactivatable(CircleStatus),
+    #[error("Invalid join request status: {0}")]
+    InvalidJoinRequestStatus(String),
+    #[error("Join request is already {0}")]
+    JoinRequestNotPending(JoinRequestStatus),
+    #[error("Invalid invitation status: {0}")]
+    InvalidInvitationStatus(String),
+    #[error("Invitation is already {0}")]
+    InvitationNotPending(InvitationStatus),
+    #[error("Invitation has expired")]
+    InvitationExpired,
+    #[error("Invalid invitation token")]
+    InvalidInvitationToken,
+    #[error("Invalid role: {0}")]
+    InvalidRole(String),
+    #[error("Owner role can only change hands through an ownership transfer")]
+    OwnerRoleNotAssignable,
+    #[error("Not allowed to {0}")]
+    Forbidden(Permission),
+    #[error("Academic year must be between {min} and {max}, got {0}", min = AcademicYear::MIN, max = AcademicYear::MAX)]
+    InvalidAcademicYear(i16),
+    #[error("Academic year {0} has already been rolled over")]
+    AlreadyRolledOver(AcademicYear),
+}
@@ -0,0 +1,75 @@
+use crate::aggregate::value_object::{
+    capacity::Capacity, circle_id::CircleId, circle_name::CircleName, circle_status::CircleStatus,
+    member_id::MemberId, role::Role,
+};
+
+/// Something that happened to a circle, recorded by the aggregate and published once persisted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DomainEvent {
+    CircleCreated {
+        circle_id: CircleId,
+        name: CircleName,
+        owner_id: MemberId,
+    },
+    MemberJoined {
+        circle_id: CircleId,
+        member_id: MemberId,
+    },
+    MemberLeft {
+        circle_id: CircleId,
+        member_id: MemberId,
+    },
+    /// The circle was full, so the member joined the end of its waitlist.
+    MemberWaitlisted {
+        circle_id: CircleId,
+        member_id: MemberId,
+    },
+    MemberLeftWaitlist {
+        circle_id: CircleId,
+        member_id: MemberId,
+    },
+    CircleRenamed {
+        circle_id: CircleId,
+        name: CircleName,
+    },
+    CapacityChanged {
+        circle_id: CircleId,
+        capacity: Capacity,
+    },
+    MembersGraduated {
+        circle_id: CircleId,
+        member_ids: Vec<MemberId>,
+    },
+    OwnershipTransferred {
+        circle_id: CircleId,
+        previous_owner_id: MemberId,
+        new_owner_id: MemberId,
+    },
+    StatusChanged {
+        circle_id: CircleId,
+        status: CircleStatus,
+    },
+    RoleAssigned {
+        circle_id: CircleId,
+        member_id: MemberId,
+        role: Role,
+    },
+}
+
+impl DomainEvent {
+    pub fn circle_id(&self) -> &CircleId {
+        match self {
+            DomainEvent::CircleCreated { circle_id, .. }
+            | DomainEvent::MemberJoined { circle_id, .. }
+            | DomainEvent::MemberLeft { circle_id, .. }
+            | DomainEvent::MemberWaitlisted { circle_id, .. }
+            | DomainEvent::MemberLeftWaitlist { circle_id, .. }
+            | DomainEvent::CircleRenamed { circle_id, .. }
+            | DomainEvent::CapacityChanged { circle_id, .. }
+            | DomainEvent::MembersGraduated { circle_id, .. }
+            | DomainEvent::OwnershipTransferred { circle_id, .. }
+            | DomainEvent::StatusChanged { circle_id, .. }
+            | DomainEvent::RoleAssigned { circle_id, .. } => circle_id,
+        }
+    }
+}
@@ -1,2 +1,8 @@
 pub mod circle_duplicate_checker_interface;
 pub mod circle_repository_interface;
+pub mod domain_event_publisher_interface;
+pub mod id_generator_interface;
+pub mod invitation_repository_interface;
+pub mod invitation_token_interface;
+pub mod join_request_repository_interface;
+pub mod member_repository_interface;
@@ -1,12 +1,76 @@
-use crate::aggregate::{circle::Circle, value_object::circle_id::CircleId};
+use crate::aggregate::{
+    circle::Circle,
+    invitation::Invitation,
+    join_request::JoinRequest,
+    member::Member,
+    value_object::{
+        academic_year::AcademicYear, circle_id::CircleId, circle_status::CircleStatus,
+        major::Major, member_id::MemberId,
+    },
+};
 use anyhow::Error;
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CircleSort {
+    #[default]
+    Name,
+    Capacity,
+}
+
+/// Filters, sort order and keyset pagination for listing circles.
+/// `cursor` is the id of the last circle of the previous page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircleQuery {
+    pub name_prefix: Option<String>,
+    pub owner_major: Option<Major>,
+    pub has_free_seats: Option<bool>,
+    pub status: Option<CircleStatus>,
+    pub sort: CircleSort,
+    pub cursor: Option<CircleId>,
+    pub limit: usize,
+}
+
 #[mockall::automock]
 #[async_trait::async_trait]
 pub trait CircleRepositoryInterface {
     async fn find_all(&self) -> Result<Vec<Circle>, Error>;
+    async fn find_by_query(&self, query: &CircleQuery) -> Result<Vec<Circle>, Error>;
     async fn find_by_id(&self, circle_id: &CircleId) -> Result<Circle, Error>;
+    /// Circles the member belongs to, as owner or member, ordered by id.
+    async fn find_by_member_id(&self, member_id: &MemberId) -> Result<Vec<Circle>, Error>;
     async fn create(&self, circle: &Circle) -> Result<(), Error>;
+    /// Persists `circle` only if the stored version still equals `circle.version`,
+    /// failing with `DomainError::VersionConflict` otherwise. Returns the circle with its new version.
     async fn update(&self, circle: &Circle) -> Result<Circle, Error>;
+    /// Stores the circles and the promoted members of the rollover that ends `academic_year` in a
+    /// single transaction, checking each circle's version like `update`, and records the year as
+    /// rolled over. Fails with `DomainError::AlreadyRolledOver` if that year or a later one already
+    /// was; if anything fails, nothing is persisted. Returns the circles with their new versions.
+    async fn rollover(
+        &self,
+        academic_year: AcademicYear,
+        circles: &[Circle],
+        members: &[Member],
+    ) -> Result<Vec<Circle>, Error>;
+    /// Adds `member` through `Circle::add_member`, checked against the circle as it is stored at
+    /// the moment of the write, so concurrent joins can neither overfill the circle nor fail each
+    /// other with `DomainError::VersionConflict`. Returns the circle with its new version.
+    async fn add_member(&self, circle_id: &CircleId, member: &Member) -> Result<Circle, Error>;
+    /// Stores the approved `join_request`, with the pending check of
+    /// `JoinRequestRepositoryInterface::update`, and seats `member` in its circle like
+    /// `add_member`, in a single transaction: if either fails, the request stays pending.
+    async fn add_member_by_join_request(
+        &self,
+        join_request: &JoinRequest,
+        member: &Member,
+    ) -> Result<Circle, Error>;
+    /// Stores the accepted `invitation`, with the pending check of
+    /// `InvitationRepositoryInterface::update`, and seats `member` in its circle like
+    /// `add_member`, in a single transaction: if either fails, the invitation stays usable.
+    async fn add_member_by_invitation(
+        &self,
+        invitation: &Invitation,
+        member: &Member,
+    ) -> Result<Circle, Error>;
     async fn delete(&self, circle: &Circle) -> Result<(), Error>;
 }
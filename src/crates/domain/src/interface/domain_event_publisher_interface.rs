@@ -0,0 +1,8 @@
+use crate::event::DomainEvent;
+use anyhow::Error;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait DomainEventPublisherInterface {
+    async fn publish(&self, events: &[DomainEvent]) -> Result<(), Error>;
+}
@@ -0,0 +1,12 @@
+use crate::aggregate::value_object::{
+    circle_id::CircleId, invitation_id::InvitationId, join_request_id::JoinRequestId,
+    member_id::MemberId,
+};
+
+#[mockall::automock]
+pub trait IdGeneratorInterface {
+    fn circle_id(&self) -> CircleId;
+    fn member_id(&self) -> MemberId;
+    fn join_request_id(&self) -> JoinRequestId;
+    fn invitation_id(&self) -> InvitationId;
+}
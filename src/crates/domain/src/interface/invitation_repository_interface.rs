@@ -0,0 +1,25 @@
+use crate::aggregate::{
+    invitation::Invitation,
+    value_object::{
+        circle_id::CircleId, invitation_id::InvitationId, invitation_status::InvitationStatus,
+    },
+};
+use anyhow::Error;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait InvitationRepositoryInterface {
+    async fn find_by_id(&self, invitation_id: &InvitationId) -> Result<Invitation, Error>;
+    /// Invitations sent by the circle, oldest first, optionally only those with the given status.
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<InvitationStatus>,
+    ) -> Result<Vec<Invitation>, Error>;
+    /// Fails with `DomainError::Duplicate` while the member has another pending invitation to the
+    /// same circle.
+    async fn create(&self, invitation: &Invitation) -> Result<(), Error>;
+    /// Stores an accepted or revoked invitation, but only while the stored one is still pending;
+    /// fails with `DomainError::InvitationNotPending` otherwise, so a token is used at most once.
+    async fn update(&self, invitation: &Invitation) -> Result<(), Error>;
+}
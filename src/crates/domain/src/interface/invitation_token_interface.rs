@@ -0,0 +1,9 @@
+use crate::{aggregate::value_object::invitation_id::InvitationId, error::DomainError};
+
+/// Turns invitation ids into the tokens handed to invitees, and back.
+#[mockall::automock]
+pub trait InvitationTokenInterface {
+    fn sign(&self, invitation_id: &InvitationId) -> String;
+    /// Fails with `DomainError::InvalidInvitationToken` unless the token was signed by `sign`.
+    fn verify(&self, token: &str) -> Result<InvitationId, DomainError>;
+}
@@ -0,0 +1,25 @@
+use crate::aggregate::{
+    join_request::JoinRequest,
+    value_object::{
+        circle_id::CircleId, join_request_id::JoinRequestId, join_request_status::JoinRequestStatus,
+    },
+};
+use anyhow::Error;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait JoinRequestRepositoryInterface {
+    async fn find_by_id(&self, join_request_id: &JoinRequestId) -> Result<JoinRequest, Error>;
+    /// Requests made to the circle, oldest first, optionally only those with the given status.
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<JoinRequestStatus>,
+    ) -> Result<Vec<JoinRequest>, Error>;
+    /// Fails with `DomainError::Duplicate` while the member has another pending request for the
+    /// same circle.
+    async fn create(&self, join_request: &JoinRequest) -> Result<(), Error>;
+    /// Stores a decision, but only while the stored request is still pending; fails with
+    /// `DomainError::JoinRequestNotPending` otherwise, so a request is decided exactly once.
+    async fn update(&self, join_request: &JoinRequest) -> Result<(), Error>;
+}
@@ -0,0 +1,12 @@
+use crate::aggregate::{member::Member, value_object::member_id::MemberId};
+use anyhow::Error;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait MemberRepositoryInterface {
+    async fn find_all(&self) -> Result<Vec<Member>, Error>;
+    async fn find_by_id(&self, member_id: &MemberId) -> Result<Member, Error>;
+    async fn create(&self, member: &Member) -> Result<(), Error>;
+    /// Overwrites the stored member, failing with `DomainError::NotFound` if it doesn't exist.
+    async fn update(&self, member: &Member) -> Result<(), Error>;
+}
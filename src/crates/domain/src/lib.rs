@@ -1,2 +1,4 @@
 pub mod aggregate;
+pub mod error;
+pub mod event;
 pub mod interface;
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS circles (
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    capacity INT NOT NULL,
+    owner_id CHAR(36) NOT NULL
+);
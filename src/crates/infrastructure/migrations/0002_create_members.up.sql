@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS members (
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    grade INT NOT NULL,
+    circle_id CHAR(36),
+    age INT NOT NULL DEFAULT 20,
+    major VARCHAR(255) NOT NULL DEFAULT 'other',
+    FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE
+);
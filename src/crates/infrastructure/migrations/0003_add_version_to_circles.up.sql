@@ -0,0 +1 @@
+ALTER TABLE circles ADD COLUMN version INT UNSIGNED NOT NULL DEFAULT 1;
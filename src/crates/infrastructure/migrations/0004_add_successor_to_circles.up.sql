@@ -0,0 +1 @@
+ALTER TABLE circles ADD COLUMN successor_id CHAR(36) NULL;
@@ -0,0 +1,2 @@
+ALTER TABLE members DROP FOREIGN KEY fk_members_circle;
+ALTER TABLE members ADD CONSTRAINT members_ibfk_1 FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE;
@@ -0,0 +1,2 @@
+ALTER TABLE members DROP FOREIGN KEY members_ibfk_1;
+ALTER TABLE members ADD CONSTRAINT fk_members_circle FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE SET NULL;
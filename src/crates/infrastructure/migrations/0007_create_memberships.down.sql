@@ -0,0 +1,13 @@
+ALTER TABLE members ADD COLUMN circle_id CHAR(36) NULL;
+ALTER TABLE members ADD CONSTRAINT fk_members_circle FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE SET NULL;
+
+-- a member can only point at one circle again, so only one of its memberships survives
+UPDATE members m
+JOIN (
+    SELECT member_id, MIN(circle_id) AS circle_id
+    FROM memberships
+    GROUP BY member_id
+) ms ON ms.member_id = m.id
+SET m.circle_id = ms.circle_id;
+
+DROP TABLE IF EXISTS memberships;
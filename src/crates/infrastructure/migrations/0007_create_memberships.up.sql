@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS memberships (
+    circle_id CHAR(36) NOT NULL,
+    member_id CHAR(36) NOT NULL,
+    role VARCHAR(32) NOT NULL DEFAULT 'member',
+    joined_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    PRIMARY KEY (circle_id, member_id),
+    INDEX idx_memberships_member (member_id),
+    FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members(id) ON DELETE CASCADE
+);
+
+-- every member row linked to a circle becomes that circle's membership
+INSERT INTO memberships (circle_id, member_id, role)
+SELECT m.circle_id, m.id, CASE WHEN c.owner_id = m.id THEN 'owner' ELSE 'member' END
+FROM members m
+JOIN circles c ON c.id = m.circle_id;
+
+ALTER TABLE members DROP FOREIGN KEY fk_members_circle;
+ALTER TABLE members DROP COLUMN circle_id;
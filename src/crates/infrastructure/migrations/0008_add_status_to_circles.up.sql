@@ -0,0 +1,6 @@
+ALTER TABLE circles ADD COLUMN status VARCHAR(16) NOT NULL DEFAULT 'Recruiting';
+
+-- circles that already have the members to run start out active
+UPDATE circles c
+SET c.status = 'Active'
+WHERE (SELECT COUNT(*) FROM memberships ms WHERE ms.circle_id = c.id) >= 3;
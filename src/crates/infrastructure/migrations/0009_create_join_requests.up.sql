@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS join_requests (
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    circle_id CHAR(36) NOT NULL,
+    member_id CHAR(36) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'Pending',
+    -- only set while the request is pending, so a member has at most one open request per circle
+    pending_member_id CHAR(36) AS (IF(status = 'Pending', member_id, NULL)) STORED,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    UNIQUE INDEX idx_join_requests_pending (circle_id, pending_member_id),
+    FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members(id) ON DELETE CASCADE
+);
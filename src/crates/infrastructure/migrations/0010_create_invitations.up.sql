@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS invitations (
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    circle_id CHAR(36) NOT NULL,
+    member_id CHAR(36) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'Pending',
+    -- unix seconds
+    expires_at BIGINT NOT NULL,
+    -- only set while the invitation is pending, so a member has at most one open invitation per circle
+    pending_member_id CHAR(36) AS (IF(status = 'Pending', member_id, NULL)) STORED,
+    created_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    UNIQUE INDEX idx_invitations_pending (circle_id, pending_member_id),
+    FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members(id) ON DELETE CASCADE
+);
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS waitlist_entries (
+    circle_id CHAR(36) NOT NULL,
+    member_id CHAR(36) NOT NULL,
+    -- 1 is next in line; rewritten whenever the circle is saved
+    position INT NOT NULL,
+    PRIMARY KEY (circle_id, member_id),
+    INDEX idx_waitlist_entries_member (member_id),
+    FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members(id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+ALTER TABLE outbox
+    DROP INDEX idx_outbox_pending,
+    DROP COLUMN dead_lettered_at,
+    ADD INDEX idx_outbox_pending (delivered_at, id);
@@ -0,0 +1,5 @@
+-- rows that keep failing are set aside here instead of blocking the rows behind them
+ALTER TABLE outbox
+    ADD COLUMN dead_lettered_at DATETIME(6) NULL,
+    DROP INDEX idx_outbox_pending,
+    ADD INDEX idx_outbox_pending (delivered_at, dead_lettered_at, id);
@@ -0,0 +1,2 @@
+-- the original out-of-range values aren't kept, so there is nothing to restore
+DO 0;
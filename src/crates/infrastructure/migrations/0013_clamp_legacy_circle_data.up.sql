@@ -0,0 +1,25 @@
+-- rows written before CircleName, Capacity and Age were validated must still load, so bring
+-- them inside the ranges the domain enforces
+
+-- names are trimmed and cut to 50 characters; a blank name falls back to one derived from the id
+UPDATE circles
+SET name = TRIM(LEFT(TRIM(name), 50))
+WHERE name <> TRIM(LEFT(TRIM(name), 50));
+
+UPDATE circles
+SET name = CONCAT('circle ', LEFT(id, 8))
+WHERE name = '';
+
+-- capacity stays within 1..=100 and never drops below the members the circle already has
+UPDATE circles c
+SET c.capacity = LEAST(
+    100,
+    GREATEST(c.capacity, 1, (SELECT COUNT(*) FROM memberships ms WHERE ms.circle_id = c.id))
+)
+WHERE c.capacity < 1
+    OR c.capacity > 100
+    OR c.capacity < (SELECT COUNT(*) FROM memberships ms WHERE ms.circle_id = c.id);
+
+UPDATE members
+SET age = LEAST(100, GREATEST(age, 15))
+WHERE age < 15 OR age > 100;
@@ -0,0 +1,2 @@
+-- the legacy ids aren't kept, so there is nothing to restore
+DO 0;
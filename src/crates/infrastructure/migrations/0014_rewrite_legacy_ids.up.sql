@@ -0,0 +1,40 @@
+-- ids issued before the switch to UUIDs don't parse any more, so give every such row a fresh
+-- UUID and carry it into each column that refers to it. Undelivered outbox events keep the old
+-- ids in their payload and are dead-lettered by the dispatcher.
+SET FOREIGN_KEY_CHECKS = 0;
+
+CREATE TEMPORARY TABLE legacy_member_ids (
+    old_id CHAR(36) NOT NULL PRIMARY KEY,
+    new_id CHAR(36) NOT NULL
+);
+INSERT INTO legacy_member_ids (old_id, new_id)
+SELECT id, UUID() FROM members WHERE id NOT REGEXP '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$';
+
+UPDATE members t JOIN legacy_member_ids l ON t.id = l.old_id SET t.id = l.new_id;
+UPDATE memberships t JOIN legacy_member_ids l ON t.member_id = l.old_id SET t.member_id = l.new_id;
+UPDATE waitlist_entries t JOIN legacy_member_ids l ON t.member_id = l.old_id SET t.member_id = l.new_id;
+UPDATE join_requests t JOIN legacy_member_ids l ON t.member_id = l.old_id SET t.member_id = l.new_id;
+UPDATE invitations t JOIN legacy_member_ids l ON t.member_id = l.old_id SET t.member_id = l.new_id;
+UPDATE circles t JOIN legacy_member_ids l ON t.owner_id = l.old_id SET t.owner_id = l.new_id;
+UPDATE circles t JOIN legacy_member_ids l ON t.successor_id = l.old_id SET t.successor_id = l.new_id;
+
+CREATE TEMPORARY TABLE legacy_circle_ids (
+    old_id CHAR(36) NOT NULL PRIMARY KEY,
+    new_id CHAR(36) NOT NULL
+);
+INSERT INTO legacy_circle_ids (old_id, new_id)
+SELECT id, UUID() FROM circles WHERE id NOT REGEXP '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$';
+
+UPDATE circles t JOIN legacy_circle_ids l ON t.id = l.old_id SET t.id = l.new_id;
+UPDATE memberships t JOIN legacy_circle_ids l ON t.circle_id = l.old_id SET t.circle_id = l.new_id;
+UPDATE waitlist_entries t JOIN legacy_circle_ids l ON t.circle_id = l.old_id SET t.circle_id = l.new_id;
+UPDATE join_requests t JOIN legacy_circle_ids l ON t.circle_id = l.old_id SET t.circle_id = l.new_id;
+UPDATE invitations t JOIN legacy_circle_ids l ON t.circle_id = l.old_id SET t.circle_id = l.new_id;
+UPDATE outbox t JOIN legacy_circle_ids l ON t.circle_id = l.old_id SET t.circle_id = l.new_id;
+
+-- nothing refers to these, so they only need a new id of their own
+UPDATE join_requests SET id = UUID() WHERE id NOT REGEXP '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$';
+UPDATE invitations SET id = UUID() WHERE id NOT REGEXP '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$';
+
+DROP TEMPORARY TABLE legacy_member_ids, legacy_circle_ids;
+SET FOREIGN_KEY_CHECKS = 1;
@@ -0,0 +1,2 @@
+-- the original spelling isn't kept, so there is nothing to restore
+DO 0;
@@ -0,0 +1,13 @@
+-- ids only parse in lowercase, but the case-insensitive check in 0014 let uppercase UUIDs
+-- through, so spell every stored id the one accepted way
+SET FOREIGN_KEY_CHECKS = 0;
+
+UPDATE members SET id = LOWER(id);
+UPDATE circles SET id = LOWER(id), owner_id = LOWER(owner_id), successor_id = LOWER(successor_id);
+UPDATE memberships SET circle_id = LOWER(circle_id), member_id = LOWER(member_id);
+UPDATE waitlist_entries SET circle_id = LOWER(circle_id), member_id = LOWER(member_id);
+UPDATE join_requests SET id = LOWER(id), circle_id = LOWER(circle_id), member_id = LOWER(member_id);
+UPDATE invitations SET id = LOWER(id), circle_id = LOWER(circle_id), member_id = LOWER(member_id);
+UPDATE outbox SET circle_id = LOWER(circle_id);
+
+SET FOREIGN_KEY_CHECKS = 1;
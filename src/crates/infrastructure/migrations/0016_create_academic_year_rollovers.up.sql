@@ -0,0 +1,5 @@
+-- one row per academic year that has been rolled over, so the same year can't be rolled over twice
+CREATE TABLE IF NOT EXISTS academic_year_rollovers (
+    academic_year SMALLINT NOT NULL PRIMARY KEY,
+    rolled_over_at DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6)
+);
@@ -1,2 +1,5 @@
 pub mod circle_data;
+pub mod event_data;
+pub mod invitation_data;
+pub mod join_request_data;
 pub mod member_data;
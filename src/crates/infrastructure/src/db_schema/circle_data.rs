@@ -1,9 +1,12 @@
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
 use domain::aggregate::{
     circle::Circle,
     member::Member,
-    value_object::{circle_id::CircleId, member_id::MemberId},
+    value_object::{
+        capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+        circle_status::CircleStatus, member_id::MemberId, role::Role,
+    },
 };
 
 use super::member_data::MemberData;
@@ -16,6 +19,13 @@ pub struct CircleData {
     pub owner: MemberData,
     pub capacity: i16,
     pub members: Vec<MemberData>,
+    /// In waitlist order.
+    pub waitlist: Vec<MemberData>,
+    /// Role of each member by id. Members missing here are plain members.
+    pub roles: BTreeMap<String, String>,
+    pub successor_id: Option<String>,
+    pub status: String,
+    pub version: u32,
 }
 
 impl std::convert::TryFrom<CircleData> for Circle {
@@ -24,23 +34,43 @@ impl std::convert::TryFrom<CircleData> for Circle {
     fn try_from(data: CircleData) -> Result<Self, Self::Error> {
         let circle_id = CircleId::from_str(data.id.as_str())?;
         let owner_id = MemberId::from_str(data.owner_id.as_str())?;
+        let owner = Member::try_from(data.owner)?;
+        if owner.id != owner_id {
+            return Err(anyhow::Error::msg("Owner not found"));
+        }
+        // the members table also holds the owner's row, which is not a regular member
         let members = data
             .members
             .into_iter()
-            .map(|member_data| MemberData::try_into(member_data))
+            .filter(|member_data| member_data.id != data.owner_id)
+            .map(MemberData::try_into)
             .collect::<Result<Vec<Member>, _>>()?;
-        let owner = members
+        // the owner's role follows from `owner_id`, and plain members need no entry
+        let roles = data
+            .roles
             .iter()
-            .find(|member| member.id == owner_id)
-            .ok_or_else(|| anyhow::Error::msg("Owner not found"))?
-            .clone();
+            .map(|(id, role)| Ok((MemberId::from_str(id)?, Role::try_from(role.as_str())?)))
+            .filter(|entry| !matches!(entry, Ok((_, Role::Owner | Role::Member))))
+            .collect::<Result<BTreeMap<MemberId, Role>, anyhow::Error>>()?;
+        let waitlist = data
+            .waitlist
+            .into_iter()
+            .map(MemberData::try_into)
+            .collect::<Result<Vec<Member>, _>>()?;
 
         Ok(Circle::reconstruct(
             circle_id,
-            data.name,
+            CircleName::try_from(data.name)?,
             owner,
-            data.capacity,
+            Capacity::try_from(data.capacity)?,
             members,
+            waitlist,
+            roles,
+            data.successor_id
+                .map(|id| MemberId::from_str(id.as_str()))
+                .transpose()?,
+            CircleStatus::try_from(data.status.as_str())?,
+            data.version,
         ))
     }
 }
@@ -49,11 +79,20 @@ impl std::convert::From<Circle> for CircleData {
     fn from(circle: Circle) -> Self {
         Self {
             id: circle.id.into(),
-            name: circle.name,
+            name: circle.name.into(),
             owner_id: circle.owner.clone().id.into(),
             owner: MemberData::from(circle.owner),
-            capacity: circle.capacity as i16,
+            capacity: circle.capacity.into(),
             members: circle.members.into_iter().map(MemberData::from).collect(),
+            waitlist: circle.waitlist.into_iter().map(MemberData::from).collect(),
+            roles: circle
+                .roles
+                .into_iter()
+                .map(|(id, role)| (id.into(), role.into()))
+                .collect(),
+            successor_id: circle.successor_id.map(String::from),
+            status: circle.status.into(),
+            version: circle.version,
         }
     }
 }
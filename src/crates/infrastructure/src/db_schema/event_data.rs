@@ -0,0 +1,253 @@
+use std::str::FromStr;
+
+use domain::{
+    aggregate::value_object::{
+        capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+        circle_status::CircleStatus, member_id::MemberId, role::Role,
+    },
+    event::DomainEvent,
+};
+
+/// The stored form of a `DomainEvent`, as written to the outbox payload.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum EventData {
+    CircleCreated {
+        circle_id: String,
+        name: String,
+        owner_id: String,
+    },
+    MemberJoined {
+        circle_id: String,
+        member_id: String,
+    },
+    MemberLeft {
+        circle_id: String,
+        member_id: String,
+    },
+    MemberWaitlisted {
+        circle_id: String,
+        member_id: String,
+    },
+    MemberLeftWaitlist {
+        circle_id: String,
+        member_id: String,
+    },
+    CircleRenamed {
+        circle_id: String,
+        name: String,
+    },
+    CapacityChanged {
+        circle_id: String,
+        capacity: i16,
+    },
+    MembersGraduated {
+        circle_id: String,
+        member_ids: Vec<String>,
+    },
+    OwnershipTransferred {
+        circle_id: String,
+        previous_owner_id: String,
+        new_owner_id: String,
+    },
+    StatusChanged {
+        circle_id: String,
+        status: String,
+    },
+    RoleAssigned {
+        circle_id: String,
+        member_id: String,
+        role: String,
+    },
+}
+
+impl EventData {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            EventData::CircleCreated { .. } => "CircleCreated",
+            EventData::MemberJoined { .. } => "MemberJoined",
+            EventData::MemberLeft { .. } => "MemberLeft",
+            EventData::MemberWaitlisted { .. } => "MemberWaitlisted",
+            EventData::MemberLeftWaitlist { .. } => "MemberLeftWaitlist",
+            EventData::CircleRenamed { .. } => "CircleRenamed",
+            EventData::CapacityChanged { .. } => "CapacityChanged",
+            EventData::MembersGraduated { .. } => "MembersGraduated",
+            EventData::OwnershipTransferred { .. } => "OwnershipTransferred",
+            EventData::StatusChanged { .. } => "StatusChanged",
+            EventData::RoleAssigned { .. } => "RoleAssigned",
+        }
+    }
+}
+
+impl std::convert::From<DomainEvent> for EventData {
+    fn from(event: DomainEvent) -> Self {
+        match event {
+            DomainEvent::CircleCreated {
+                circle_id,
+                name,
+                owner_id,
+            } => EventData::CircleCreated {
+                circle_id: circle_id.into(),
+                name: name.into(),
+                owner_id: owner_id.into(),
+            },
+            DomainEvent::MemberJoined {
+                circle_id,
+                member_id,
+            } => EventData::MemberJoined {
+                circle_id: circle_id.into(),
+                member_id: member_id.into(),
+            },
+            DomainEvent::MemberLeft {
+                circle_id,
+                member_id,
+            } => EventData::MemberLeft {
+                circle_id: circle_id.into(),
+                member_id: member_id.into(),
+            },
+            DomainEvent::MemberWaitlisted {
+                circle_id,
+                member_id,
+            } => EventData::MemberWaitlisted {
+                circle_id: circle_id.into(),
+                member_id: member_id.into(),
+            },
+            DomainEvent::MemberLeftWaitlist {
+                circle_id,
+                member_id,
+            } => EventData::MemberLeftWaitlist {
+                circle_id: circle_id.into(),
+                member_id: member_id.into(),
+            },
+            DomainEvent::CircleRenamed { circle_id, name } => EventData::CircleRenamed {
+                circle_id: circle_id.into(),
+                name: name.into(),
+            },
+            DomainEvent::CapacityChanged {
+                circle_id,
+                capacity,
+            } => EventData::CapacityChanged {
+                circle_id: circle_id.into(),
+                capacity: capacity.into(),
+            },
+            DomainEvent::MembersGraduated {
+                circle_id,
+                member_ids,
+            } => EventData::MembersGraduated {
+                circle_id: circle_id.into(),
+                member_ids: member_ids.into_iter().map(String::from).collect(),
+            },
+            DomainEvent::OwnershipTransferred {
+                circle_id,
+                previous_owner_id,
+                new_owner_id,
+            } => EventData::OwnershipTransferred {
+                circle_id: circle_id.into(),
+                previous_owner_id: previous_owner_id.into(),
+                new_owner_id: new_owner_id.into(),
+            },
+            DomainEvent::StatusChanged { circle_id, status } => EventData::StatusChanged {
+                circle_id: circle_id.into(),
+                status: status.into(),
+            },
+            DomainEvent::RoleAssigned {
+                circle_id,
+                member_id,
+                role,
+            } => EventData::RoleAssigned {
+                circle_id: circle_id.into(),
+                member_id: member_id.into(),
+                role: role.into(),
+            },
+        }
+    }
+}
+
+impl std::convert::TryFrom<EventData> for DomainEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(data: EventData) -> Result<Self, Self::Error> {
+        Ok(match data {
+            EventData::CircleCreated {
+                circle_id,
+                name,
+                owner_id,
+            } => DomainEvent::CircleCreated {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                name: CircleName::try_from(name)?,
+                owner_id: MemberId::from_str(owner_id.as_str())?,
+            },
+            EventData::MemberJoined {
+                circle_id,
+                member_id,
+            } => DomainEvent::MemberJoined {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_id: MemberId::from_str(member_id.as_str())?,
+            },
+            EventData::MemberLeft {
+                circle_id,
+                member_id,
+            } => DomainEvent::MemberLeft {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_id: MemberId::from_str(member_id.as_str())?,
+            },
+            EventData::MemberWaitlisted {
+                circle_id,
+                member_id,
+            } => DomainEvent::MemberWaitlisted {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_id: MemberId::from_str(member_id.as_str())?,
+            },
+            EventData::MemberLeftWaitlist {
+                circle_id,
+                member_id,
+            } => DomainEvent::MemberLeftWaitlist {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_id: MemberId::from_str(member_id.as_str())?,
+            },
+            EventData::CircleRenamed { circle_id, name } => DomainEvent::CircleRenamed {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                name: CircleName::try_from(name)?,
+            },
+            EventData::CapacityChanged {
+                circle_id,
+                capacity,
+            } => DomainEvent::CapacityChanged {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                capacity: Capacity::try_from(capacity)?,
+            },
+            EventData::MembersGraduated {
+                circle_id,
+                member_ids,
+            } => DomainEvent::MembersGraduated {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_ids: member_ids
+                    .iter()
+                    .map(|id| MemberId::from_str(id.as_str()))
+                    .collect::<Result<Vec<_>, _>>()?,
+            },
+            EventData::OwnershipTransferred {
+                circle_id,
+                previous_owner_id,
+                new_owner_id,
+            } => DomainEvent::OwnershipTransferred {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                previous_owner_id: MemberId::from_str(previous_owner_id.as_str())?,
+                new_owner_id: MemberId::from_str(new_owner_id.as_str())?,
+            },
+            EventData::StatusChanged { circle_id, status } => DomainEvent::StatusChanged {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                status: CircleStatus::try_from(status.as_str())?,
+            },
+            EventData::RoleAssigned {
+                circle_id,
+                member_id,
+                role,
+            } => DomainEvent::RoleAssigned {
+                circle_id: CircleId::from_str(circle_id.as_str())?,
+                member_id: MemberId::from_str(member_id.as_str())?,
+                role: Role::try_from(role.as_str())?,
+            },
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use std::{
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use domain::aggregate::{
+    invitation::Invitation,
+    value_object::{
+        circle_id::CircleId, invitation_id::InvitationId, invitation_status::InvitationStatus,
+        member_id::MemberId,
+    },
+};
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+pub struct InvitationData {
+    pub id: String,
+    pub circle_id: String,
+    pub member_id: String,
+    /// unix seconds
+    pub expires_at: i64,
+    pub status: String,
+}
+
+impl std::convert::From<Invitation> for InvitationData {
+    fn from(value: Invitation) -> Self {
+        Self {
+            id: value.id.into(),
+            circle_id: value.circle_id.into(),
+            member_id: value.member_id.into(),
+            expires_at: value
+                .expires_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64),
+            status: value.status.into(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<InvitationData> for Invitation {
+    type Error = anyhow::Error;
+
+    fn try_from(value: InvitationData) -> Result<Self, Self::Error> {
+        Ok(Invitation::reconstruct(
+            InvitationId::from_str(value.id.as_str())?,
+            CircleId::from_str(value.circle_id.as_str())?,
+            MemberId::from_str(value.member_id.as_str())?,
+            UNIX_EPOCH + Duration::from_secs(u64::try_from(value.expires_at)?),
+            InvitationStatus::try_from(value.status.as_str())?,
+        ))
+    }
+}
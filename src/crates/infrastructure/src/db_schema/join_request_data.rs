@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use domain::aggregate::{
+    join_request::JoinRequest,
+    value_object::{
+        circle_id::CircleId, join_request_id::JoinRequestId,
+        join_request_status::JoinRequestStatus, member_id::MemberId,
+    },
+};
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+pub struct JoinRequestData {
+    pub id: String,
+    pub circle_id: String,
+    pub member_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<JoinRequest> for JoinRequestData {
+    fn from(value: JoinRequest) -> Self {
+        Self {
+            id: value.id.into(),
+            circle_id: value.circle_id.into(),
+            member_id: value.member_id.into(),
+            status: value.status.into(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<JoinRequestData> for JoinRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(value: JoinRequestData) -> Result<Self, Self::Error> {
+        Ok(JoinRequest::reconstruct(
+            JoinRequestId::from_str(value.id.as_str())?,
+            CircleId::from_str(value.circle_id.as_str())?,
+            MemberId::from_str(value.member_id.as_str())?,
+            JoinRequestStatus::try_from(value.status.as_str())?,
+        ))
+    }
+}
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use domain::aggregate::{
     member::Member,
-    value_object::{grade::Grade, major::Major, member_id::MemberId},
+    value_object::{age::Age, grade::Grade, major::Major, member_id::MemberId},
 };
 
 #[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
@@ -19,7 +19,7 @@ impl std::convert::From<Member> for MemberData {
         Self {
             id: value.id.into(),
             name: value.name,
-            age: value.age,
+            age: value.age.into(),
             grade: value.grade.into(),
             major: value.major.into(),
         }
@@ -33,7 +33,7 @@ impl std::convert::TryFrom<MemberData> for Member {
         Ok(Member::reconstruct(
             MemberId::from_str(value.id.as_str())?,
             value.name,
-            value.age,
+            Age::try_from(value.age)?,
             Grade::try_from(value.grade)?,
             Major::from(value.major.as_str()),
         ))
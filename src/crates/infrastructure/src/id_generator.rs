@@ -0,0 +1,35 @@
+use domain::{
+    aggregate::value_object::{
+        circle_id::CircleId, invitation_id::InvitationId, join_request_id::JoinRequestId,
+        member_id::MemberId,
+    },
+    interface::id_generator_interface::IdGeneratorInterface,
+};
+
+/// Hands out fresh time-ordered UUIDs.
+#[derive(Clone, Debug, Default)]
+pub struct IdGenerator;
+
+impl IdGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl IdGeneratorInterface for IdGenerator {
+    fn circle_id(&self) -> CircleId {
+        CircleId::gen()
+    }
+
+    fn member_id(&self) -> MemberId {
+        MemberId::gen()
+    }
+
+    fn join_request_id(&self) -> JoinRequestId {
+        JoinRequestId::gen()
+    }
+
+    fn invitation_id(&self) -> InvitationId {
+        InvitationId::gen()
+    }
+}
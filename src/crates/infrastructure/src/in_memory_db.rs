@@ -1,3 +1,6 @@
 pub mod circle_duplicate_checker;
 pub mod circle_repository;
 pub mod db;
+pub mod invitation_repository;
+pub mod join_request_repository;
+pub mod member_repository;
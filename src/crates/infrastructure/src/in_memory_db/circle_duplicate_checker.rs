@@ -1,11 +1,11 @@
 use anyhow::{Error, Result};
 use async_trait::async_trait;
 use domain::{
-    aggregate::circle::Circle,
+    aggregate::circle::Circle, error::DomainError,
     interface::circle_duplicate_checker_interface::CircleDuplicateCheckerInterface,
 };
 
-use crate::{db_schema::circle_data::CircleData, in_memory_db::db::Db};
+use crate::in_memory_db::{circle_repository::circle_data, db::Db};
 
 #[derive(Clone, Debug)]
 pub struct CircleDuplicateChecker {
@@ -13,18 +13,96 @@ pub struct CircleDuplicateChecker {
 }
 
 impl CircleDuplicateChecker {
-    pub fn new() -> Self {
-        Self { db: Db::new() }
+    pub fn new(db: Db) -> Self {
+        Self { db }
     }
 }
 
 #[async_trait]
 impl CircleDuplicateCheckerInterface for CircleDuplicateChecker {
     async fn check_circle_duplicate(&self, circle: &Circle) -> Result<(), Error> {
-        let id = circle.id();
-        match self.db.get::<CircleData, _>(&id.to_string())? {
-            Some(_) => Err(Error::msg("Circle already exists")),
-            None => Ok(()),
+        if circle_data(&self.db)?
+            .iter()
+            .any(|data| data.name == circle.name())
+        {
+            return Err(DomainError::Duplicate("Circle name").into());
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_db::{
+        circle_repository::CircleRepository, member_repository::MemberRepository,
+    };
+    use domain::{
+        aggregate::{
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                grade::Grade, major::Major, member_id::MemberId,
+            },
+        },
+        interface::{
+            circle_repository_interface::CircleRepositoryInterface,
+            member_repository_interface::MemberRepositoryInterface,
+        },
+    };
+
+    fn create_test_circle(name: &str) -> Circle {
+        let owner = Member::new(
+            MemberId::gen(),
+            "owner".to_string(),
+            Age::try_from(21).unwrap(),
+            Grade::Third,
+            Major::Music,
+        );
+        Circle::create(
+            CircleId::gen(),
+            CircleName::try_from(name).unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn check_circle_duplicate_exists() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let checker = CircleDuplicateChecker::new(db.clone());
+        let circle = create_test_circle("Music club");
+        MemberRepository::new(db.clone())
+            .create(&circle.owner)
+            .await?;
+        repository.create(&circle).await?;
+
+        let result = checker
+            .check_circle_duplicate(&create_test_circle("Music club"))
+            .await;
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>()?,
+            DomainError::Duplicate("Circle name")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn check_circle_duplicate_not_exists() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let checker = CircleDuplicateChecker::new(db.clone());
+        let circle = create_test_circle("Music club");
+        MemberRepository::new(db.clone())
+            .create(&circle.owner)
+            .await?;
+        repository.create(&circle).await?;
+
+        checker
+            .check_circle_duplicate(&create_test_circle("Soccer club"))
+            .await?;
+        Ok(())
     }
 }
@@ -1,187 +1,1056 @@
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
 
 use anyhow::Error;
 use domain::{
     aggregate::{
         circle::Circle,
+        invitation::Invitation,
+        join_request::JoinRequest,
         member::Member,
-        value_object::{circle_id::CircleId, grade::Grade, major::Major, member_id::MemberId},
+        value_object::{
+            academic_year::AcademicYear, capacity::Capacity, circle_id::CircleId,
+            circle_name::CircleName, circle_status::CircleStatus, member_id::MemberId, role::Role,
+        },
+    },
+    error::DomainError,
+    event::DomainEvent,
+    interface::{
+        circle_repository_interface::{CircleQuery, CircleRepositoryInterface, CircleSort},
+        domain_event_publisher_interface::DomainEventPublisherInterface,
     },
-    interface::circle_repository_interface::CircleRepositoryInterface,
 };
 
-use crate::in_memory_db::db::Db;
+use crate::in_memory_db::{
+    db::{Db, DbRead, DbTransaction},
+    invitation_repository::update_invitation,
+    join_request_repository::update_join_request,
+    member_repository::{member_key, update_member, MemberData},
+};
+
+const CIRCLE_KEY_PREFIX: &str = "circle:";
+// the last academic year that was rolled over
+const ROLLED_OVER_KEY: &str = "rolled_over_academic_year";
+
+pub(super) fn circle_key(circle_id: &CircleId) -> String {
+    format!("{CIRCLE_KEY_PREFIX}{circle_id}")
+}
+
+pub(super) fn circle_data(db: &impl DbRead) -> Result<Vec<CircleData>, Error> {
+    let mut circles = Vec::new();
+    for key in db.keys() {
+        if !key.starts_with(CIRCLE_KEY_PREFIX) {
+            continue;
+        }
+        if let Some(data) = db.get::<CircleData, _>(&key)? {
+            circles.push(data);
+        }
+    }
+    Ok(circles)
+}
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct CircleRepository {
     db: Db,
+    event_publisher: Option<Arc<dyn DomainEventPublisherInterface + Send + Sync>>,
+}
+
+impl std::fmt::Debug for CircleRepository {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircleRepository")
+            .field("db", &self.db)
+            .finish_non_exhaustive()
+    }
 }
 
 impl CircleRepository {
-    pub fn new() -> Self {
-        Self { db: Db::new() }
+    pub fn new(db: Db) -> Self {
+        Self {
+            db,
+            event_publisher: None,
+        }
+    }
+
+    /// Publishes the events recorded on each circle once it has been stored.
+    pub fn with_event_publisher(
+        self,
+        event_publisher: Arc<dyn DomainEventPublisherInterface + Send + Sync>,
+    ) -> Self {
+        Self {
+            event_publisher: Some(event_publisher),
+            ..self
+        }
+    }
+
+    // the write has already happened, so a failing publisher must not turn it into an error
+    async fn publish(&self, events: &[DomainEvent]) {
+        let Some(event_publisher) = &self.event_publisher else {
+            return;
+        };
+        if events.is_empty() {
+            return;
+        }
+        if let Err(e) = event_publisher.publish(events).await {
+            tracing::error!("Failed to publish domain events: {:?}", e);
+        }
+    }
+
+    fn circles(&self) -> Result<Vec<Circle>, Error> {
+        circle_data(&self.db)?
+            .into_iter()
+            .map(|data| reconstruct(&self.db, data))
+            .collect()
     }
 }
 
+fn member(db: &impl DbRead, member_id: &str) -> Result<Member, Error> {
+    match db.get::<MemberData, _>(member_key(&MemberId::from_str(member_id)?))? {
+        Some(data) => Member::try_from(data),
+        None => Err(DomainError::NotFound("Member").into()),
+    }
+}
+
+fn reconstruct(db: &impl DbRead, data: CircleData) -> Result<Circle, Error> {
+    Ok(Circle::reconstruct(
+        CircleId::from_str(&data.id)?,
+        CircleName::try_from(data.name)?,
+        member(db, &data.owner_id)?,
+        Capacity::try_from(data.capacity)?,
+        data.member_ids
+            .iter()
+            .map(|id| member(db, id))
+            .collect::<Result<Vec<Member>, Error>>()?,
+        data.waitlist_ids
+            .iter()
+            .map(|id| member(db, id))
+            .collect::<Result<Vec<Member>, Error>>()?,
+        data.roles
+            .iter()
+            .map(|(id, role)| Ok((MemberId::from_str(id)?, Role::try_from(role.as_str())?)))
+            .collect::<Result<BTreeMap<MemberId, Role>, Error>>()?,
+        data.successor_id
+            .map(|id| MemberId::from_str(&id))
+            .transpose()?,
+        CircleStatus::try_from(data.status.as_str())?,
+        data.version,
+    ))
+}
+
+// the version check and the write happen under the same lock, so no other writer can slip in
+// between them
+fn seat_member(
+    transaction: &mut DbTransaction<'_>,
+    circle_id: &CircleId,
+    member: &Member,
+) -> Result<(Circle, Vec<DomainEvent>), Error> {
+    let Some(data) = transaction.get::<CircleData, _>(circle_key(circle_id))? else {
+        return Err(DomainError::NotFound("Circle").into());
+    };
+    let circle = reconstruct(transaction, data)?.add_member(member.clone())?;
+    let updated = store_next_version(transaction, &circle)?;
+    Ok((updated, circle.events))
+}
+
+fn store_next_version(
+    transaction: &mut DbTransaction<'_>,
+    circle: &Circle,
+) -> Result<Circle, Error> {
+    match transaction.get::<CircleData, _>(circle_key(&circle.id))? {
+        Some(stored) if stored.version != circle.version => {
+            return Err(DomainError::VersionConflict("Circle").into())
+        }
+        Some(_) => {}
+        None => return Err(DomainError::NotFound("Circle").into()),
+    }
+    let updated = Circle {
+        version: circle.version + 1,
+        events: vec![],
+        ..circle.clone()
+    };
+    store(transaction, &updated)?;
+    Ok(updated)
+}
+
+// members are stored on their own; the circle only keeps their ids
+fn store(transaction: &mut DbTransaction<'_>, circle: &Circle) -> Result<(), Error> {
+    let mut seen = std::collections::HashSet::new();
+    if !std::iter::once(&circle.owner)
+        .chain(&circle.members)
+        .chain(&circle.waitlist)
+        .all(|member| seen.insert(&member.id))
+    {
+        return Err(DomainError::AlreadyMember.into());
+    }
+    for member in std::iter::once(&circle.owner)
+        .chain(&circle.members)
+        .chain(&circle.waitlist)
+    {
+        if transaction
+            .get::<MemberData, _>(member_key(&member.id))?
+            .is_none()
+        {
+            return Err(DomainError::NotFound("Member").into());
+        }
+    }
+    transaction.set(circle_key(&circle.id), &CircleData::from(circle.clone()))
+}
+
 #[async_trait::async_trait]
 impl CircleRepositoryInterface for CircleRepository {
     async fn find_all(&self) -> Result<Vec<Circle>, Error> {
-        todo!("Implement this method")
+        let mut circles = self.circles()?;
+        circles.sort_by_key(|circle| circle.id.to_string());
+        Ok(circles)
+    }
+
+    async fn find_by_query(&self, query: &CircleQuery) -> Result<Vec<Circle>, Error> {
+        let mut circles = self.circles()?;
+        let order = |a: &Circle, b: &Circle| match query.sort {
+            CircleSort::Name => (&a.name, a.id.to_string()).cmp(&(&b.name, b.id.to_string())),
+            CircleSort::Capacity => {
+                (a.capacity, a.id.to_string()).cmp(&(b.capacity, b.id.to_string()))
+            }
+        };
+        // like MySQL, resume after the cursor's sort key even if that circle no longer matches
+        // the filter, and return nothing once it is gone
+        let after = match &query.cursor {
+            Some(cursor) => match circles.iter().find(|circle| &circle.id == cursor) {
+                Some(circle) => Some(circle.clone()),
+                None => return Ok(vec![]),
+            },
+            None => None,
+        };
+        circles.retain(|circle| {
+            query
+                .name_prefix
+                .as_ref()
+                .is_none_or(|prefix| circle.name.as_str().starts_with(prefix.as_str()))
+                && query
+                    .owner_major
+                    .is_none_or(|major| circle.owner.major == major)
+                && query
+                    .has_free_seats
+                    .is_none_or(|has_free_seats| circle.is_full() != has_free_seats)
+                && query.status.is_none_or(|status| circle.status == status)
+        });
+        if let Some(after) = &after {
+            circles.retain(|circle| order(circle, after).is_gt());
+        }
+        circles.sort_by(order);
+        Ok(circles.into_iter().take(query.limit).collect())
     }
 
     async fn find_by_id(&self, circle_id: &CircleId) -> Result<Circle, Error> {
-        match self.db.get::<CircleData, _>(&circle_id.to_string())? {
-            Some(data) => Ok(Circle::try_from(data)?),
-            None => Err(Error::msg("Circle not found")),
+        match self.db.get::<CircleData, _>(circle_key(circle_id))? {
+            Some(data) => reconstruct(&self.db, data),
+            None => Err(DomainError::NotFound("Circle").into()),
         }
     }
 
+    async fn find_by_member_id(&self, member_id: &MemberId) -> Result<Vec<Circle>, Error> {
+        let mut circles = self.circles()?;
+        circles.retain(|circle| circle.member(member_id).is_some());
+        circles.sort_by_key(|circle| circle.id.to_string());
+        Ok(circles)
+    }
+
     async fn create(&self, circle: &Circle) -> Result<(), Error> {
-        match self.db.get::<CircleData, _>(&circle.id.to_string())? {
-            Some(_) => Err(Error::msg("Circle already exists")),
-            None => {
-                self.db
-                    .set(circle.id.to_string(), &CircleData::from(circle.clone()))?;
-                Ok(())
+        self.db.with_write_lock(|transaction| {
+            if transaction
+                .get::<CircleData, _>(circle_key(&circle.id))?
+                .is_some()
+            {
+                return Err(DomainError::Duplicate("Circle").into());
             }
-        }
+            store(transaction, circle)
+        })?;
+        self.publish(&circle.events).await;
+        Ok(())
     }
 
     async fn update(&self, circle: &Circle) -> Result<Circle, Error> {
-        match self.db.get::<CircleData, _>(&circle.id.to_string())? {
-            Some(_) => self
-                .db
-                .set(circle.id.to_string(), &CircleData::from(circle.clone()))
-                .and_then(|_| self.db.get::<CircleData, _>(&circle.id.to_string()))
-                .map(|data| match data {
-                    Some(data) => Circle::try_from(data),
-                    None => Err(Error::msg("Failed to convert circle data")),
-                })?,
-            None => Err(Error::msg("Circle not found")),
+        let updated = self
+            .db
+            .with_write_lock(|transaction| store_next_version(transaction, circle))?;
+        self.publish(&circle.events).await;
+        Ok(updated)
+    }
+
+    async fn rollover(
+        &self,
+        academic_year: AcademicYear,
+        circles: &[Circle],
+        members: &[Member],
+    ) -> Result<Vec<Circle>, Error> {
+        // a conflict on any circle or a missing member leaves everything untouched
+        let updated = self.db.with_write_lock(|transaction| {
+            if transaction
+                .get::<i16, _>(ROLLED_OVER_KEY)?
+                .is_some_and(|rolled_over| rolled_over >= i16::from(academic_year))
+            {
+                return Err(DomainError::AlreadyRolledOver(academic_year).into());
+            }
+            transaction.set(ROLLED_OVER_KEY, &i16::from(academic_year))?;
+            let updated = circles
+                .iter()
+                .map(|circle| store_next_version(transaction, circle))
+                .collect::<Result<Vec<Circle>, Error>>()?;
+            for member in members {
+                update_member(transaction, member)?;
+            }
+            Ok(updated)
+        })?;
+        for circle in circles {
+            self.publish(&circle.events).await;
         }
+        Ok(updated)
+    }
+
+    async fn add_member(&self, circle_id: &CircleId, member: &Member) -> Result<Circle, Error> {
+        // the circle is read and written under one lock, so concurrent joins see each other
+        let (updated, events) = self
+            .db
+            .with_write_lock(|transaction| seat_member(transaction, circle_id, member))?;
+        self.publish(&events).await;
+        Ok(updated)
+    }
+
+    async fn add_member_by_join_request(
+        &self,
+        join_request: &JoinRequest,
+        member: &Member,
+    ) -> Result<Circle, Error> {
+        let (updated, events) = self.db.with_write_lock(|transaction| {
+            update_join_request(transaction, join_request)?;
+            seat_member(transaction, &join_request.circle_id, member)
+        })?;
+        self.publish(&events).await;
+        Ok(updated)
+    }
+
+    async fn add_member_by_invitation(
+        &self,
+        invitation: &Invitation,
+        member: &Member,
+    ) -> Result<Circle, Error> {
+        let (updated, events) = self.db.with_write_lock(|transaction| {
+            update_invitation(transaction, invitation)?;
+            seat_member(transaction, &invitation.circle_id, member)
+        })?;
+        self.publish(&events).await;
+        Ok(updated)
     }
 
     async fn delete(&self, circle: &Circle) -> Result<(), Error> {
-        match self.db.get::<CircleData, _>(&circle.id.to_string())? {
-            Some(_) => self.db.remove(circle.id.to_string()),
-            None => Err(Error::msg("Circle not found")),
-        }
+        self.db.with_write_lock(|transaction| {
+            if transaction
+                .get::<CircleData, _>(circle_key(&circle.id))?
+                .is_none()
+            {
+                return Err(DomainError::NotFound("Circle").into());
+            }
+            transaction.remove(circle_key(&circle.id))
+        })
     }
 }
 
 // schema for OnMemoryDB
 #[derive(serde::Deserialize, serde::Serialize)]
-struct CircleData {
+pub(super) struct CircleData {
     id: String,
-    name: String,
-    owner: MemberData,
+    pub(super) name: String,
+    owner_id: String,
     capacity: i16,
-    members: Vec<MemberData>,
+    member_ids: Vec<String>,
+    waitlist_ids: Vec<String>,
+    roles: BTreeMap<String, String>,
+    successor_id: Option<String>,
+    status: String,
+    version: u32,
 }
 
 impl std::convert::From<Circle> for CircleData {
     fn from(circle: Circle) -> Self {
         CircleData {
             id: circle.id.into(),
-            name: circle.name,
-            owner: MemberData::from(circle.owner),
-            capacity: circle.capacity,
-            members: circle.members.into_iter().map(MemberData::from).collect(),
-        }
-    }
-}
-
-impl std::convert::TryFrom<CircleData> for Circle {
-    type Error = Error;
-
-    fn try_from(data: CircleData) -> Result<Self, Self::Error> {
-        Ok(Circle::reconstruct(
-            CircleId::from_str(&data.id)?,
-            data.name,
-            Member::reconstruct(
-                MemberId::from_str(&data.owner.id)?,
-                data.owner.name,
-                data.owner.age,
-                Grade::try_from(data.owner.grade)?,
-                Major::from(data.owner.major.as_str()),
-            ),
-            data.capacity,
-            data.members
+            name: circle.name.into(),
+            owner_id: circle.owner.id.into(),
+            capacity: circle.capacity.into(),
+            member_ids: circle.members.into_iter().map(|m| m.id.into()).collect(),
+            waitlist_ids: circle.waitlist.into_iter().map(|m| m.id.into()).collect(),
+            roles: circle
+                .roles
                 .into_iter()
-                .map(Member::try_from)
-                .collect::<Result<Vec<Member>, Error>>()?,
-        ))
-    }
-}
-
-#[derive(serde::Deserialize, serde::Serialize)]
-struct MemberData {
-    id: String,
-    name: String,
-    age: i16,
-    grade: i16,
-    major: String,
-}
-
-impl std::convert::From<Member> for MemberData {
-    fn from(value: Member) -> Self {
-        Self {
-            id: value.id.into(),
-            name: value.name,
-            age: value.age,
-            grade: value.grade.into(),
-            major: value.major.into(),
+                .map(|(id, role)| (id.into(), role.into()))
+                .collect(),
+            successor_id: circle.successor_id.map(String::from),
+            status: circle.status.into(),
+            version: circle.version,
         }
     }
 }
 
-impl std::convert::TryFrom<MemberData> for Member {
-    type Error = Error;
-
-    fn try_from(value: MemberData) -> Result<Self, Self::Error> {
-        Ok(Member::reconstruct(
-            MemberId::from_str(value.id.as_str())?,
-            value.name,
-            value.age,
-            Grade::try_from(value.grade)?,
-            Major::from(value.major.as_str()),
-        ))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use domain::{
         aggregate::{
             circle::Circle,
+            invitation::Invitation,
+            join_request::JoinRequest,
             member::Member,
-            value_object::{grade::Grade, major::Major},
+            value_object::{
+                academic_year::AcademicYear, age::Age, capacity::Capacity, circle_id::CircleId,
+                circle_name::CircleName, circle_status::CircleStatus, grade::Grade,
+                invitation_id::InvitationId, invitation_status::InvitationStatus,
+                join_request_id::JoinRequestId, join_request_status::JoinRequestStatus,
+                major::Major, member_id::MemberId,
+            },
+        },
+        interface::{
+            circle_repository_interface::{CircleQuery, CircleRepositoryInterface, CircleSort},
+            invitation_repository_interface::InvitationRepositoryInterface,
+            join_request_repository_interface::JoinRequestRepositoryInterface,
+            member_repository_interface::MemberRepositoryInterface,
         },
-        interface::circle_repository_interface::CircleRepositoryInterface,
+    };
+
+    use std::{
+        sync::Arc,
+        time::{Duration, SystemTime},
     };
 
     use super::CircleRepository;
+    use crate::in_memory_db::{
+        db::Db, invitation_repository::InvitationRepository,
+        join_request_repository::JoinRequestRepository, member_repository::MemberRepository,
+    };
+    use crate::in_process::domain_event_publisher::DomainEventPublisher;
+    use domain::error::DomainError;
+    use domain::event::DomainEvent;
 
     #[tokio::test]
     async fn test() -> anyhow::Result<()> {
         let mut circle1 = build_circle()?;
-        let repository = CircleRepository::new();
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
         assert!(repository.find_by_id(&circle1.id).await.is_err());
+        create_members(&db, &circle1).await?;
         repository.create(&circle1).await?;
         assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
-        circle1.name = "circle_name2".to_string();
-        repository.update(&circle1).await?;
-        assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
+        circle1.name = CircleName::try_from("circle_name2")?;
+        let updated = repository.update(&circle1).await?;
+        assert_eq!(updated.version, circle1.version + 1);
+        assert_eq!(repository.find_by_id(&circle1.id).await?, updated);
+        // a write based on the old version is rejected
+        assert_eq!(
+            repository
+                .update(&circle1)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::VersionConflict("Circle")
+        );
+        let circle1 = updated;
         repository.delete(&circle1).await?;
         assert!(repository.find_by_id(&circle1.id).await.is_err());
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_find_by_query() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let age = Age::try_from(21)?;
+        let owner = |major| {
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                age,
+                Grade::Third,
+                major,
+            )
+        };
+        let art = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Art club")?,
+            owner(Major::Art),
+            Capacity::try_from(1)?,
+        )?;
+        let music = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            owner(Major::Music),
+            Capacity::try_from(3)?,
+        )?;
+        let musical = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Musical club")?,
+            owner(Major::Art),
+            Capacity::try_from(2)?,
+        )?;
+        for circle in [&art, &music, &musical] {
+            create_members(&db, circle).await?;
+            repository.create(circle).await?;
+        }
+        let query = CircleQuery {
+            name_prefix: None,
+            owner_major: None,
+            has_free_seats: None,
+            status: None,
+            sort: CircleSort::Name,
+            cursor: None,
+            limit: 10,
+        };
+
+        let names = |circles: Vec<Circle>| -> Vec<String> {
+            circles
+                .into_iter()
+                .map(|circle| circle.name.into())
+                .collect()
+        };
+        assert_eq!(
+            names(repository.find_by_query(&query).await?),
+            ["Art club", "Music club", "Musical club"]
+        );
+        let by_capacity = CircleQuery {
+            sort: CircleSort::Capacity,
+            ..query.clone()
+        };
+        assert_eq!(
+            names(repository.find_by_query(&by_capacity).await?),
+            ["Art club", "Musical club", "Music club"]
+        );
+        let filtered = CircleQuery {
+            name_prefix: Some("Music".to_string()),
+            owner_major: Some(Major::Art),
+            has_free_seats: Some(true),
+            ..query.clone()
+        };
+        assert_eq!(
+            names(repository.find_by_query(&filtered).await?),
+            ["Musical club"]
+        );
+        let first_page = CircleQuery {
+            limit: 2,
+            ..query.clone()
+        };
+        assert_eq!(
+            names(repository.find_by_query(&first_page).await?),
+            ["Art club", "Music club"]
+        );
+        let second_page = CircleQuery {
+            cursor: Some(music.id.clone()),
+            ..first_page
+        };
+        assert_eq!(
+            names(repository.find_by_query(&second_page).await?),
+            ["Musical club"]
+        );
+
+        repository.update(&music.disband()?).await?;
+        let disbanded = CircleQuery {
+            status: Some(CircleStatus::Disbanded),
+            ..query.clone()
+        };
+        assert_eq!(
+            names(repository.find_by_query(&disbanded).await?),
+            ["Music club"]
+        );
+        // the cursor circle dropped out of the filter between pages
+        let recruiting_second_page = CircleQuery {
+            status: Some(CircleStatus::Recruiting),
+            ..second_page
+        };
+        assert_eq!(
+            names(repository.find_by_query(&recruiting_second_page).await?),
+            ["Musical club"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let circle1 = build_circle()?;
+        let circle2 = build_circle()?;
+        create_members(&db, &circle1).await?;
+        repository.create(&circle1).await?;
+        create_members(&db, &circle2).await?;
+        repository.create(&circle2).await?;
+        let promoted = vec![circle1.owner.clone().promote()];
+        let academic_year = AcademicYear::try_from(2025)?;
+        let missing = Member::new(
+            MemberId::gen(),
+            "missing".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+
+        // a conflict on circle2 leaves circle1 and the promotion unwritten
+        let stale = repository.update(&circle2).await?;
+        let result = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover(), circle2.clone().rollover()],
+                &promoted,
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>()?,
+            DomainError::VersionConflict("Circle")
+        );
+        // and a missing member rolls back the circles written before it
+        let result = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover()],
+                &[promoted[0].clone(), missing],
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
+
+        let updated = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover(), stale.clone().rollover()],
+                &promoted,
+            )
+            .await?;
+        let circle1 = repository.find_by_id(&circle1.id).await?;
+        assert_eq!(circle1.version, updated[0].version);
+        assert_eq!(circle1.owner, promoted[0]);
+        assert_eq!(repository.find_by_id(&stale.id).await?, updated[1]);
+
+        // the failed attempts didn't record the year, the successful one did
+        for academic_year in [academic_year, AcademicYear::try_from(2024)?] {
+            let result = repository
+                .rollover(academic_year, &[circle1.clone().rollover()], &[])
+                .await;
+            assert_eq!(
+                result.unwrap_err().downcast::<DomainError>()?,
+                DomainError::AlreadyRolledOver(academic_year)
+            );
+        }
+        assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
+        repository
+            .rollover(AcademicYear::try_from(2026)?, &[], &[])
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_publishes_events() -> anyhow::Result<()> {
+        let publisher = DomainEventPublisher::new(16);
+        let mut receiver = publisher.subscribe();
+        let db = Db::new();
+        let repository =
+            CircleRepository::new(db.clone()).with_event_publisher(Arc::new(publisher));
+
+        let circle = build_circle()?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+        assert!(matches!(
+            receiver.recv().await?,
+            DomainEvent::CircleCreated { circle_id, .. } if circle_id == circle.id
+        ));
+
+        let member = Member::new(
+            MemberId::gen(),
+            "member_name2".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&member).await?;
+        let circle = repository.find_by_id(&circle.id).await?;
+        let updated = repository
+            .update(&circle.add_member(member.clone())?)
+            .await?;
+        assert!(updated.events.is_empty());
+        assert_eq!(
+            receiver.recv().await?,
+            DomainEvent::MemberJoined {
+                circle_id: updated.id.clone(),
+                member_id: member.id,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_all() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        assert!(repository.find_all().await?.is_empty());
+
+        let circle1 = build_circle()?;
+        let circle2 = build_circle()?;
+        create_members(&db, &circle1).await?;
+        repository.create(&circle1).await?;
+        create_members(&db, &circle2).await?;
+        repository.create(&circle2).await?;
+
+        let mut expected = vec![circle1, circle2];
+        expected.sort_by_key(|circle| circle.id.to_string());
+        assert_eq!(repository.find_all().await?, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_members_are_referenced() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let member_repository = MemberRepository::new(db.clone());
+        let circle = build_circle()?;
+        assert_eq!(
+            repository
+                .create(&circle)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+
+        // the circle sees the member's own changes
+        let owner = circle
+            .owner
+            .clone()
+            .update(Some("renamed".to_string()), None, None);
+        member_repository.update(&owner).await?;
+        assert_eq!(repository.find_by_id(&circle.id).await?.owner, owner);
+
+        // the same person can belong to several circles
+        let other = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Art club")?,
+            owner.clone(),
+            Capacity::try_from(3)?,
+        )?;
+        repository.create(&other).await?;
+        let mut expected = vec![circle.id, other.id];
+        expected.sort_by_key(|id| id.to_string());
+        let found: Vec<CircleId> = repository
+            .find_by_member_id(&owner.id)
+            .await?
+            .into_iter()
+            .map(|circle| circle.id)
+            .collect();
+        assert_eq!(found, expected);
+        assert!(repository
+            .find_by_member_id(&MemberId::gen())
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejects_duplicate_members() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let circle = build_circle()?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+
+        let mut duplicated = circle.clone();
+        duplicated.members.push(circle.owner.clone());
+        assert_eq!(
+            repository
+                .update(&duplicated)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, circle);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let circle = build_circle()?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&member).await?;
+
+        let joined = repository.add_member(&circle.id, &member).await?;
+        assert_eq!(joined.version, circle.version + 1);
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+        assert_eq!(
+            repository
+                .add_member(&circle.id, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member_by_join_request() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let join_request_repository = JoinRequestRepository::new(db.clone());
+        let circle = build_circle()?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&member).await?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        join_request_repository.create(&join_request).await?;
+
+        // the member joined directly in the meantime, so the approval can't seat them and the
+        // request stays pending
+        let joined = repository.add_member(&circle.id, &member).await?;
+        let approved = join_request.clone().approve()?;
+        assert_eq!(
+            repository
+                .add_member_by_join_request(&approved, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            join_request_repository
+                .find_by_id(&join_request.id)
+                .await?
+                .status,
+            JoinRequestStatus::Pending
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+
+        let other = Member::new(
+            MemberId::gen(),
+            "other".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&other).await?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, other.id.clone())?;
+        join_request_repository.create(&join_request).await?;
+        let approved = join_request.approve()?;
+        let updated = repository
+            .add_member_by_join_request(&approved, &other)
+            .await?;
+        assert!(updated.member(&other.id).is_some());
+        assert_eq!(
+            join_request_repository
+                .find_by_id(&approved.id)
+                .await?
+                .status,
+            JoinRequestStatus::Approved
+        );
+        // only one approval wins the pending request
+        assert_eq!(
+            repository
+                .add_member_by_join_request(&approved, &other)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::JoinRequestNotPending(JoinRequestStatus::Approved)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member_by_invitation() -> anyhow::Result<()> {
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let invitation_repository = InvitationRepository::new(db.clone());
+        let circle = build_circle()?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&member).await?;
+        let issue = |member_id: &MemberId| {
+            Invitation::issue(
+                InvitationId::gen(),
+                &circle,
+                member_id.clone(),
+                SystemTime::now(),
+                Duration::from_secs(60),
+            )
+        };
+        let invitation = issue(&member.id)?;
+        invitation_repository.create(&invitation).await?;
+
+        // the member joined directly in the meantime, so accepting can't seat them and the
+        // invitation stays usable
+        let joined = repository.add_member(&circle.id, &member).await?;
+        let accepted = invitation.clone().accept(SystemTime::now())?;
+        assert_eq!(
+            repository
+                .add_member_by_invitation(&accepted, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            invitation_repository
+                .find_by_id(&invitation.id)
+                .await?
+                .status,
+            InvitationStatus::Pending
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+
+        let other = Member::new(
+            MemberId::gen(),
+            "other".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(db.clone()).create(&other).await?;
+        let invitation = issue(&other.id)?;
+        invitation_repository.create(&invitation).await?;
+        let accepted = invitation.accept(SystemTime::now())?;
+        let updated = repository
+            .add_member_by_invitation(&accepted, &other)
+            .await?;
+        assert!(updated.member(&other.id).is_some());
+        assert_eq!(
+            invitation_repository.find_by_id(&accepted.id).await?.status,
+            InvitationStatus::Accepted
+        );
+        // the token can't be used twice
+        assert_eq!(
+            repository
+                .add_member_by_invitation(&accepted, &other)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::InvitationNotPending(InvitationStatus::Accepted)
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn stress_concurrent_joins_respect_capacity() -> anyhow::Result<()> {
+        const CAPACITY: i16 = 5;
+        const JOINS: usize = 40;
+
+        let db = Db::new();
+        let repository = CircleRepository::new(db.clone());
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(CAPACITY)?,
+        )?;
+        create_members(&db, &circle).await?;
+        repository.create(&circle).await?;
+
+        let member_repository = MemberRepository::new(db.clone());
+        let mut members = Vec::with_capacity(JOINS);
+        for i in 0..JOINS {
+            let member = Member::new(
+                MemberId::gen(),
+                format!("member{i}"),
+                Age::try_from(19)?,
+                Grade::First,
+                Major::Art,
+            );
+            member_repository.create(&member).await?;
+            members.push(member);
+        }
+
+        let joins = members.into_iter().map(|member| {
+            let repository = repository.clone();
+            let circle_id = circle.id.clone();
+            tokio::spawn(async move { repository.add_member(&circle_id, &member).await })
+        });
+        for join in joins.collect::<Vec<_>>() {
+            join.await??;
+        }
+
+        let stored = repository.find_by_id(&circle.id).await?;
+        assert!(stored.is_full());
+        assert_eq!(stored.member_count(), CAPACITY as usize);
+        assert_eq!(stored.waitlist.len(), JOINS - (CAPACITY as usize - 1));
+        assert_eq!(stored.version, circle.version + JOINS as u32);
+
+        // writers holding the same version race, and exactly one of them wins
+        let updates = (0..JOINS).map(|i| {
+            let repository = repository.clone();
+            let stale = stored.clone();
+            tokio::spawn(async move {
+                let renamed =
+                    stale.update(Some(CircleName::try_from(format!("club{i}"))?), None)?;
+                repository.update(&renamed).await
+            })
+        });
+        let mut won = 0;
+        for update in updates.collect::<Vec<_>>() {
+            match update.await? {
+                Ok(_) => won += 1,
+                Err(e) => assert_eq!(
+                    e.downcast::<DomainError>()?,
+                    DomainError::VersionConflict("Circle")
+                ),
+            }
+        }
+        assert_eq!(won, 1);
+        Ok(())
+    }
+
+    async fn create_members(db: &Db, circle: &Circle) -> anyhow::Result<()> {
+        let member_repository = MemberRepository::new(db.clone());
+        for member in std::iter::once(&circle.owner).chain(&circle.members) {
+            member_repository.create(member).await?;
+        }
+        Ok(())
+    }
+
     fn build_circle() -> anyhow::Result<Circle> {
-        Circle::create(
-            "Music club".to_string(),
-            Member::new("member_name1".to_string(), 21, Grade::Third, Major::Art),
-            3,
-        )
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "member_name1".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Art,
+            ),
+            Capacity::try_from(3)?,
+        )?)
     }
 }
@@ -8,6 +8,23 @@ pub struct Db {
     db: Arc<RwLock<HashMap<String, String>>>,
 }
 
+/// Reads shared by `Db` and `DbTransaction`, so lookups can run with or without the write lock.
+pub trait DbRead {
+    fn get<D, K>(&self, key: K) -> anyhow::Result<Option<D>>
+    where
+        K: AsRef<str>,
+        D: serde::de::DeserializeOwned;
+
+    fn keys(&self) -> Vec<String>;
+}
+
+/// The database as seen while `Db::with_write_lock` holds the lock. Writes are staged and only
+/// applied once the closure succeeds, so a failure part-way leaves nothing behind.
+pub struct DbTransaction<'a> {
+    data: &'a HashMap<String, String>,
+    writes: HashMap<String, Option<String>>,
+}
+
 impl Db {
     pub fn new() -> Self {
         Self {
@@ -24,15 +41,7 @@ impl Db {
             .db
             .read()
             .map_err(|e| anyhow::anyhow!("Error reading from database: {:?}", e))?;
-
-        match db.get(key.as_ref()) {
-            Some(value) => {
-                let deserialized_value = serde_json::from_str(value)
-                    .map_err(|e| anyhow::anyhow!("Error deserializing value: {:?}", e))?;
-                Ok(Some(deserialized_value))
-            }
-            None => Ok(None),
-        }
+        deserialize(db.get(key.as_ref()))
     }
 
     pub fn keys(&self) -> Vec<String> {
@@ -40,6 +49,31 @@ impl Db {
         db.keys().cloned().collect()
     }
 
+    /// Runs `f` while holding the write lock, so whatever it checks still holds when its writes
+    /// land. The writes are applied only if `f` returns `Ok`.
+    pub fn with_write_lock<T>(
+        &self,
+        f: impl FnOnce(&mut DbTransaction<'_>) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let mut db = self
+            .db
+            .write()
+            .map_err(|e| anyhow::anyhow!("Error writing to database: {:?}", e))?;
+        let mut transaction = DbTransaction {
+            data: &db,
+            writes: HashMap::new(),
+        };
+        let result = f(&mut transaction)?;
+        let writes = transaction.writes;
+        for (key, value) in writes {
+            match value {
+                Some(value) => db.insert(key, value),
+                None => db.remove(&key),
+            };
+        }
+        Ok(result)
+    }
+
     pub fn remove<K>(&self, key: K) -> anyhow::Result<()>
     where
         K: AsRef<str>,
@@ -68,6 +102,93 @@ impl Db {
     }
 }
 
+impl DbRead for Db {
+    fn get<D, K>(&self, key: K) -> anyhow::Result<Option<D>>
+    where
+        K: AsRef<str>,
+        D: serde::de::DeserializeOwned,
+    {
+        Db::get(self, key)
+    }
+
+    fn keys(&self) -> Vec<String> {
+        Db::keys(self)
+    }
+}
+
+impl DbTransaction<'_> {
+    pub fn set<S, K>(&mut self, key: K, value: &S) -> anyhow::Result<()>
+    where
+        K: Into<String>,
+        S: serde::ser::Serialize,
+    {
+        let value = serde_json::to_string(value)?;
+        self.writes.insert(key.into(), Some(value));
+        Ok(())
+    }
+
+    pub fn remove<K>(&mut self, key: K) -> anyhow::Result<()>
+    where
+        K: AsRef<str>,
+    {
+        if self.value(key.as_ref()).is_none() {
+            return Err(anyhow::anyhow!("Key not found in database"));
+        }
+        self.writes.insert(key.as_ref().to_string(), None);
+        Ok(())
+    }
+
+    fn value(&self, key: &str) -> Option<&String> {
+        match self.writes.get(key) {
+            Some(staged) => staged.as_ref(),
+            None => self.data.get(key),
+        }
+    }
+}
+
+impl DbRead for DbTransaction<'_> {
+    fn get<D, K>(&self, key: K) -> anyhow::Result<Option<D>>
+    where
+        K: AsRef<str>,
+        D: serde::de::DeserializeOwned,
+    {
+        deserialize(self.value(key.as_ref()))
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.data
+            .keys()
+            .filter(|key| !self.writes.contains_key(*key))
+            .chain(
+                self.writes
+                    .iter()
+                    .filter(|(_, value)| value.is_some())
+                    .map(|(key, _)| key),
+            )
+            .cloned()
+            .collect()
+    }
+}
+
+fn deserialize<D: serde::de::DeserializeOwned>(
+    value: Option<&String>,
+) -> anyhow::Result<Option<D>> {
+    match value {
+        Some(value) => {
+            let deserialized_value = serde_json::from_str(value)
+                .map_err(|e| anyhow::anyhow!("Error deserializing value: {:?}", e))?;
+            Ok(Some(deserialized_value))
+        }
+        None => Ok(None),
+    }
+}
+
+impl Default for Db {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Ok;
@@ -101,4 +222,35 @@ mod tests {
         assert!(keys.contains(&String::from("key2")));
         Ok(())
     }
+
+    #[test]
+    fn test_with_write_lock() -> anyhow::Result<()> {
+        use super::DbRead;
+
+        let db = super::Db::new();
+        db.set("key1", &"value1".to_string())?;
+        db.with_write_lock(|transaction| {
+            transaction.set("key2", &"value2".to_string())?;
+            transaction.remove("key1")?;
+            // staged writes are visible inside the transaction
+            assert_eq!(
+                transaction.get::<String, _>("key2")?.as_deref(),
+                Some("value2")
+            );
+            assert!(transaction.get::<String, _>("key1")?.is_none());
+            assert_eq!(transaction.keys(), vec!["key2".to_string()]);
+            Ok(())
+        })?;
+        assert!(db.get::<String, _>("key1")?.is_none());
+        assert_eq!(db.get::<String, _>("key2")?.as_deref(), Some("value2"));
+
+        // a failing closure leaves nothing behind
+        let result = db.with_write_lock(|transaction| {
+            transaction.set("key3", &"value3".to_string())?;
+            transaction.remove("missing")
+        });
+        assert!(result.is_err());
+        assert!(db.get::<String, _>("key3")?.is_none());
+        Ok(())
+    }
 }
@@ -0,0 +1,252 @@
+use std::{
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use anyhow::Error;
+use domain::{
+    aggregate::{
+        invitation::Invitation,
+        value_object::{
+            circle_id::CircleId, invitation_id::InvitationId, invitation_status::InvitationStatus,
+            member_id::MemberId,
+        },
+    },
+    error::DomainError,
+    interface::invitation_repository_interface::InvitationRepositoryInterface,
+};
+
+use crate::in_memory_db::db::{Db, DbRead, DbTransaction};
+
+const INVITATION_KEY_PREFIX: &str = "invitation:";
+
+fn invitation_key(invitation_id: &InvitationId) -> String {
+    format!("{INVITATION_KEY_PREFIX}{invitation_id}")
+}
+
+#[derive(Clone, Debug)]
+pub struct InvitationRepository {
+    db: Db,
+}
+
+impl InvitationRepository {
+    pub fn new(db: Db) -> Self {
+        Self { db }
+    }
+
+    fn invitations(&self) -> Result<Vec<Invitation>, Error> {
+        invitations(&self.db)
+    }
+}
+
+fn invitations(db: &impl DbRead) -> Result<Vec<Invitation>, Error> {
+    let mut invitations = Vec::new();
+    for key in db.keys() {
+        if !key.starts_with(INVITATION_KEY_PREFIX) {
+            continue;
+        }
+        if let Some(data) = db.get::<InvitationData, _>(&key)? {
+            invitations.push(Invitation::try_from(data)?);
+        }
+    }
+    invitations.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(invitations)
+}
+
+#[async_trait::async_trait]
+impl InvitationRepositoryInterface for InvitationRepository {
+    async fn find_by_id(&self, invitation_id: &InvitationId) -> Result<Invitation, Error> {
+        match self
+            .db
+            .get::<InvitationData, _>(invitation_key(invitation_id))?
+        {
+            Some(data) => Invitation::try_from(data),
+            None => Err(DomainError::NotFound("Invitation").into()),
+        }
+    }
+
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<InvitationStatus>,
+    ) -> Result<Vec<Invitation>, Error> {
+        let mut invitations = self.invitations()?;
+        invitations.retain(|invitation| {
+            &invitation.circle_id == circle_id
+                && status.is_none_or(|status| invitation.status == status)
+        });
+        Ok(invitations)
+    }
+
+    async fn create(&self, invitation: &Invitation) -> Result<(), Error> {
+        self.db.with_write_lock(|transaction| {
+            let pending = invitations(transaction)?.into_iter().any(|other| {
+                other.id == invitation.id
+                    || (other.circle_id == invitation.circle_id
+                        && other.member_id == invitation.member_id
+                        && other.status == InvitationStatus::Pending)
+            });
+            if pending {
+                return Err(DomainError::Duplicate("Invitation").into());
+            }
+            transaction.set(
+                invitation_key(&invitation.id),
+                &InvitationData::from(invitation.clone()),
+            )
+        })
+    }
+
+    async fn update(&self, invitation: &Invitation) -> Result<(), Error> {
+        self.db
+            .with_write_lock(|transaction| update_invitation(transaction, invitation))
+    }
+}
+
+pub(super) fn update_invitation(
+    transaction: &mut DbTransaction<'_>,
+    invitation: &Invitation,
+) -> Result<(), Error> {
+    match transaction.get::<InvitationData, _>(invitation_key(&invitation.id))? {
+        Some(stored) => {
+            let stored = Invitation::try_from(stored)?;
+            if stored.status != InvitationStatus::Pending {
+                return Err(DomainError::InvitationNotPending(stored.status).into());
+            }
+            transaction.set(
+                invitation_key(&invitation.id),
+                &InvitationData::from(invitation.clone()),
+            )
+        }
+        None => Err(DomainError::NotFound("Invitation").into()),
+    }
+}
+
+// schema for OnMemoryDB
+#[derive(serde::Deserialize, serde::Serialize)]
+struct InvitationData {
+    id: String,
+    circle_id: String,
+    member_id: String,
+    // unix seconds
+    expires_at: u64,
+    status: String,
+}
+
+impl std::convert::From<Invitation> for InvitationData {
+    fn from(value: Invitation) -> Self {
+        Self {
+            id: value.id.into(),
+            circle_id: value.circle_id.into(),
+            member_id: value.member_id.into(),
+            expires_at: value
+                .expires_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            status: value.status.into(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<InvitationData> for Invitation {
+    type Error = Error;
+
+    fn try_from(value: InvitationData) -> Result<Self, Self::Error> {
+        Ok(Invitation::reconstruct(
+            InvitationId::from_str(value.id.as_str())?,
+            CircleId::from_str(value.circle_id.as_str())?,
+            MemberId::from_str(value.member_id.as_str())?,
+            UNIX_EPOCH + Duration::from_secs(value.expires_at),
+            InvitationStatus::try_from(value.status.as_str())?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use domain::{
+        aggregate::{
+            invitation::Invitation,
+            value_object::{
+                circle_id::CircleId, invitation_id::InvitationId,
+                invitation_status::InvitationStatus, member_id::MemberId,
+            },
+        },
+        error::DomainError,
+        interface::invitation_repository_interface::InvitationRepositoryInterface,
+    };
+
+    use super::InvitationRepository;
+    use crate::in_memory_db::db::Db;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let repository = InvitationRepository::new(Db::new());
+        let circle_id = CircleId::gen();
+        let member_id = MemberId::gen();
+        let expires_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let invitation = Invitation::reconstruct(
+            InvitationId::gen(),
+            circle_id.clone(),
+            member_id.clone(),
+            expires_at,
+            InvitationStatus::Pending,
+        );
+        assert_eq!(
+            repository
+                .find_by_id(&invitation.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Invitation")
+        );
+        repository.create(&invitation).await?;
+        assert_eq!(repository.find_by_id(&invitation.id).await?, invitation);
+
+        // one pending invitation per member and circle
+        let again = Invitation::reconstruct(
+            InvitationId::gen(),
+            circle_id.clone(),
+            member_id.clone(),
+            expires_at,
+            InvitationStatus::Pending,
+        );
+        assert_eq!(
+            repository
+                .create(&again)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Invitation")
+        );
+
+        let revoked = invitation.clone().revoke()?;
+        repository.update(&revoked).await?;
+        assert_eq!(
+            repository
+                .update(&invitation.accept(SystemTime::UNIX_EPOCH)?)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::InvitationNotPending(InvitationStatus::Revoked)
+        );
+        repository.create(&again).await?;
+
+        assert_eq!(
+            repository.find_by_circle_id(&circle_id, None).await?,
+            vec![revoked, again.clone()]
+        );
+        assert_eq!(
+            repository
+                .find_by_circle_id(&circle_id, Some(InvitationStatus::Pending))
+                .await?,
+            vec![again]
+        );
+        assert!(repository
+            .find_by_circle_id(&CircleId::gen(), None)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use std::str::FromStr;
+
+use anyhow::Error;
+use domain::{
+    aggregate::{
+        join_request::JoinRequest,
+        value_object::{
+            circle_id::CircleId, join_request_id::JoinRequestId,
+            join_request_status::JoinRequestStatus, member_id::MemberId,
+        },
+    },
+    error::DomainError,
+    interface::join_request_repository_interface::JoinRequestRepositoryInterface,
+};
+
+use crate::in_memory_db::db::{Db, DbRead, DbTransaction};
+
+const JOIN_REQUEST_KEY_PREFIX: &str = "join_request:";
+
+fn join_request_key(join_request_id: &JoinRequestId) -> String {
+    format!("{JOIN_REQUEST_KEY_PREFIX}{join_request_id}")
+}
+
+#[derive(Clone, Debug)]
+pub struct JoinRequestRepository {
+    db: Db,
+}
+
+impl JoinRequestRepository {
+    pub fn new(db: Db) -> Self {
+        Self { db }
+    }
+
+    fn join_requests(&self) -> Result<Vec<JoinRequest>, Error> {
+        join_requests(&self.db)
+    }
+}
+
+fn join_requests(db: &impl DbRead) -> Result<Vec<JoinRequest>, Error> {
+    let mut join_requests = Vec::new();
+    for key in db.keys() {
+        if !key.starts_with(JOIN_REQUEST_KEY_PREFIX) {
+            continue;
+        }
+        if let Some(data) = db.get::<JoinRequestData, _>(&key)? {
+            join_requests.push(JoinRequest::try_from(data)?);
+        }
+    }
+    join_requests.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(join_requests)
+}
+
+#[async_trait::async_trait]
+impl JoinRequestRepositoryInterface for JoinRequestRepository {
+    async fn find_by_id(&self, join_request_id: &JoinRequestId) -> Result<JoinRequest, Error> {
+        match self
+            .db
+            .get::<JoinRequestData, _>(join_request_key(join_request_id))?
+        {
+            Some(data) => JoinRequest::try_from(data),
+            None => Err(DomainError::NotFound("Join request").into()),
+        }
+    }
+
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<JoinRequestStatus>,
+    ) -> Result<Vec<JoinRequest>, Error> {
+        let mut join_requests = self.join_requests()?;
+        join_requests.retain(|join_request| {
+            &join_request.circle_id == circle_id
+                && status.is_none_or(|status| join_request.status == status)
+        });
+        Ok(join_requests)
+    }
+
+    async fn create(&self, join_request: &JoinRequest) -> Result<(), Error> {
+        self.db.with_write_lock(|transaction| {
+            let pending = join_requests(transaction)?.into_iter().any(|other| {
+                other.id == join_request.id
+                    || (other.circle_id == join_request.circle_id
+                        && other.member_id == join_request.member_id
+                        && other.status == JoinRequestStatus::Pending)
+            });
+            if pending {
+                return Err(DomainError::Duplicate("Join request").into());
+            }
+            transaction.set(
+                join_request_key(&join_request.id),
+                &JoinRequestData::from(join_request.clone()),
+            )
+        })
+    }
+
+    async fn update(&self, join_request: &JoinRequest) -> Result<(), Error> {
+        self.db
+            .with_write_lock(|transaction| update_join_request(transaction, join_request))
+    }
+}
+
+pub(super) fn update_join_request(
+    transaction: &mut DbTransaction<'_>,
+    join_request: &JoinRequest,
+) -> Result<(), Error> {
+    match transaction.get::<JoinRequestData, _>(join_request_key(&join_request.id))? {
+        Some(stored) => {
+            let stored = JoinRequest::try_from(stored)?;
+            if stored.status != JoinRequestStatus::Pending {
+                return Err(DomainError::JoinRequestNotPending(stored.status).into());
+            }
+            transaction.set(
+                join_request_key(&join_request.id),
+                &JoinRequestData::from(join_request.clone()),
+            )
+        }
+        None => Err(DomainError::NotFound("Join request").into()),
+    }
+}
+
+// schema for OnMemoryDB
+#[derive(serde::Deserialize, serde::Serialize)]
+struct JoinRequestData {
+    id: String,
+    circle_id: String,
+    member_id: String,
+    status: String,
+}
+
+impl std::convert::From<JoinRequest> for JoinRequestData {
+    fn from(value: JoinRequest) -> Self {
+        Self {
+            id: value.id.into(),
+            circle_id: value.circle_id.into(),
+            member_id: value.member_id.into(),
+            status: value.status.into(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<JoinRequestData> for JoinRequest {
+    type Error = Error;
+
+    fn try_from(value: JoinRequestData) -> Result<Self, Self::Error> {
+        Ok(JoinRequest::reconstruct(
+            JoinRequestId::from_str(value.id.as_str())?,
+            CircleId::from_str(value.circle_id.as_str())?,
+            MemberId::from_str(value.member_id.as_str())?,
+            JoinRequestStatus::try_from(value.status.as_str())?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            join_request::JoinRequest,
+            value_object::{
+                circle_id::CircleId, join_request_id::JoinRequestId,
+                join_request_status::JoinRequestStatus, member_id::MemberId,
+            },
+        },
+        error::DomainError,
+        interface::join_request_repository_interface::JoinRequestRepositoryInterface,
+    };
+
+    use super::JoinRequestRepository;
+    use crate::in_memory_db::db::Db;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let repository = JoinRequestRepository::new(Db::new());
+        let circle_id = CircleId::gen();
+        let member_id = MemberId::gen();
+        let join_request = JoinRequest::reconstruct(
+            JoinRequestId::gen(),
+            circle_id.clone(),
+            member_id.clone(),
+            JoinRequestStatus::Pending,
+        );
+        assert_eq!(
+            repository
+                .find_by_id(&join_request.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Join request")
+        );
+        repository.create(&join_request).await?;
+        assert_eq!(repository.find_by_id(&join_request.id).await?, join_request);
+
+        // one pending request per member and circle
+        let again = JoinRequest::reconstruct(
+            JoinRequestId::gen(),
+            circle_id.clone(),
+            member_id.clone(),
+            JoinRequestStatus::Pending,
+        );
+        assert_eq!(
+            repository
+                .create(&again)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Join request")
+        );
+
+        let rejected = join_request.clone().reject()?;
+        repository.update(&rejected).await?;
+        assert_eq!(
+            repository
+                .update(&join_request.approve()?)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::JoinRequestNotPending(JoinRequestStatus::Rejected)
+        );
+        repository.create(&again).await?;
+
+        assert_eq!(
+            repository.find_by_circle_id(&circle_id, None).await?,
+            vec![rejected, again.clone()]
+        );
+        assert_eq!(
+            repository
+                .find_by_circle_id(&circle_id, Some(JoinRequestStatus::Pending))
+                .await?,
+            vec![again]
+        );
+        assert!(repository
+            .find_by_circle_id(&CircleId::gen(), None)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+}
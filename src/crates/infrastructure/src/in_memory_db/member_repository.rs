@@ -0,0 +1,189 @@
+use std::str::FromStr;
+
+use anyhow::Error;
+use domain::{
+    aggregate::{
+        member::Member,
+        value_object::{age::Age, grade::Grade, major::Major, member_id::MemberId},
+    },
+    error::DomainError,
+    interface::member_repository_interface::MemberRepositoryInterface,
+};
+
+use crate::in_memory_db::db::{Db, DbRead, DbTransaction};
+
+const MEMBER_KEY_PREFIX: &str = "member:";
+
+pub(super) fn member_key(member_id: &MemberId) -> String {
+    format!("{MEMBER_KEY_PREFIX}{member_id}")
+}
+
+#[derive(Clone, Debug)]
+pub struct MemberRepository {
+    db: Db,
+}
+
+impl MemberRepository {
+    pub fn new(db: Db) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl MemberRepositoryInterface for MemberRepository {
+    async fn find_all(&self) -> Result<Vec<Member>, Error> {
+        let mut members = Vec::new();
+        for key in self.db.keys() {
+            if !key.starts_with(MEMBER_KEY_PREFIX) {
+                continue;
+            }
+            if let Some(data) = self.db.get::<MemberData, _>(&key)? {
+                members.push(Member::try_from(data)?);
+            }
+        }
+        members.sort_by_key(|member| member.id.to_string());
+        Ok(members)
+    }
+
+    async fn find_by_id(&self, member_id: &MemberId) -> Result<Member, Error> {
+        match self.db.get::<MemberData, _>(member_key(member_id))? {
+            Some(data) => Member::try_from(data),
+            None => Err(DomainError::NotFound("Member").into()),
+        }
+    }
+
+    async fn create(&self, member: &Member) -> Result<(), Error> {
+        self.db.with_write_lock(|transaction| {
+            if transaction
+                .get::<MemberData, _>(member_key(&member.id))?
+                .is_some()
+            {
+                return Err(DomainError::Duplicate("Member").into());
+            }
+            transaction.set(member_key(&member.id), &MemberData::from(member.clone()))
+        })
+    }
+
+    async fn update(&self, member: &Member) -> Result<(), Error> {
+        self.db
+            .with_write_lock(|transaction| update_member(transaction, member))
+    }
+}
+
+pub(super) fn update_member(
+    transaction: &mut DbTransaction<'_>,
+    member: &Member,
+) -> Result<(), Error> {
+    if transaction
+        .get::<MemberData, _>(member_key(&member.id))?
+        .is_none()
+    {
+        return Err(DomainError::NotFound("Member").into());
+    }
+    transaction.set(member_key(&member.id), &MemberData::from(member.clone()))
+}
+
+// schema for OnMemoryDB
+#[derive(serde::Deserialize, serde::Serialize)]
+pub(super) struct MemberData {
+    id: String,
+    name: String,
+    age: i16,
+    grade: i16,
+    major: String,
+}
+
+impl std::convert::From<Member> for MemberData {
+    fn from(value: Member) -> Self {
+        Self {
+            id: value.id.into(),
+            name: value.name,
+            age: value.age.into(),
+            grade: value.grade.into(),
+            major: value.major.into(),
+        }
+    }
+}
+
+impl std::convert::TryFrom<MemberData> for Member {
+    type Error = Error;
+
+    fn try_from(value: MemberData) -> Result<Self, Self::Error> {
+        Ok(Member::reconstruct(
+            MemberId::from_str(value.id.as_str())?,
+            value.name,
+            Age::try_from(value.age)?,
+            Grade::try_from(value.grade)?,
+            Major::from(value.major.as_str()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            member::Member,
+            value_object::{age::Age, grade::Grade, major::Major, member_id::MemberId},
+        },
+        error::DomainError,
+        interface::member_repository_interface::MemberRepositoryInterface,
+    };
+
+    use super::MemberRepository;
+    use crate::in_memory_db::db::Db;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let repository = MemberRepository::new(Db::new());
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        assert_eq!(
+            repository
+                .find_by_id(&member.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        repository.create(&member).await?;
+        assert_eq!(repository.find_by_id(&member.id).await?, member);
+        assert_eq!(
+            repository
+                .create(&member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Member")
+        );
+
+        let member = member.update(Some("renamed".to_string()), None, Some(Major::Music));
+        repository.update(&member).await?;
+        assert_eq!(repository.find_by_id(&member.id).await?, member);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_all() -> anyhow::Result<()> {
+        let repository = MemberRepository::new(Db::new());
+        let mut members = Vec::new();
+        for grade in [Grade::First, Grade::Third] {
+            let member = Member::new(
+                MemberId::gen(),
+                "member".to_string(),
+                Age::try_from(19)?,
+                grade,
+                Major::Art,
+            );
+            repository.create(&member).await?;
+            members.push(member);
+        }
+        assert_eq!(repository.find_all().await?, members);
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use domain::{
+    event::DomainEvent, interface::domain_event_publisher_interface::DomainEventPublisherInterface,
+};
+use tokio::sync::broadcast;
+
+/// Fans published events out to every subscriber in this process.
+/// Subscribers that fall more than `capacity` events behind miss the oldest ones.
+#[derive(Clone, Debug)]
+pub struct DomainEventPublisher {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl DomainEventPublisher {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisherInterface for DomainEventPublisher {
+    async fn publish(&self, events: &[DomainEvent]) -> Result<(), Error> {
+        for event in events {
+            // sending only fails when nobody is subscribed, in which case there is nobody to tell
+            let _ = self.sender.send(event.clone());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::value_object::{circle_id::CircleId, member_id::MemberId};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_publish() -> anyhow::Result<()> {
+        let publisher = DomainEventPublisher::new(16);
+        // publishing without subscribers is fine
+        publisher.publish(&[]).await?;
+
+        let mut receiver = publisher.subscribe();
+        let event = DomainEvent::MemberJoined {
+            circle_id: CircleId::gen(),
+            member_id: MemberId::gen(),
+        };
+        publisher.publish(std::slice::from_ref(&event)).await?;
+        assert_eq!(receiver.recv().await?, event);
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use domain::{
+    aggregate::value_object::invitation_id::InvitationId, error::DomainError,
+    interface::invitation_token_interface::InvitationTokenInterface,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs invitation ids with HMAC-SHA256, so a token can't be made up or pointed at another
+/// invitation without the secret.
+#[derive(Clone)]
+pub struct InvitationTokenSigner {
+    secret: Vec<u8>,
+}
+
+impl InvitationTokenSigner {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    /// Tokens signed with a random secret stop working once the process exits.
+    pub fn random() -> Self {
+        Self::new(rand::random::<[u8; 32]>())
+    }
+
+    fn mac(&self, payload: &[u8]) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC takes keys of any length");
+        mac.update(payload);
+        mac
+    }
+}
+
+// keeps the secret out of logs
+impl std::fmt::Debug for InvitationTokenSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InvitationTokenSigner")
+            .finish_non_exhaustive()
+    }
+}
+
+impl InvitationTokenInterface for InvitationTokenSigner {
+    fn sign(&self, invitation_id: &InvitationId) -> String {
+        let payload = invitation_id.to_string();
+        let signature = self.mac(payload.as_bytes()).finalize().into_bytes();
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        )
+    }
+
+    fn verify(&self, token: &str) -> Result<InvitationId, DomainError> {
+        let (payload, signature) = token
+            .split_once('.')
+            .ok_or(DomainError::InvalidInvitationToken)?;
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload)
+            .map_err(|_| DomainError::InvalidInvitationToken)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|_| DomainError::InvalidInvitationToken)?;
+        // constant time, so the signature can't be guessed byte by byte
+        self.mac(&payload)
+            .verify_slice(&signature)
+            .map_err(|_| DomainError::InvalidInvitationToken)?;
+
+        std::str::from_utf8(&payload)
+            .ok()
+            .and_then(|payload| InvitationId::from_str(payload).ok())
+            .ok_or(DomainError::InvalidInvitationToken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let signer = InvitationTokenSigner::new("secret");
+        let invitation_id = InvitationId::gen();
+        let token = signer.sign(&invitation_id);
+        assert_eq!(signer.verify(&token), Ok(invitation_id.clone()));
+
+        // another secret, a tampered payload or a truncated token are all rejected
+        let other_id = InvitationId::gen();
+        let (_, signature) = token.split_once('.').unwrap();
+        let forged = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(other_id.to_string()),
+            signature
+        );
+        for token in [
+            InvitationTokenSigner::new("other secret").sign(&invitation_id),
+            forged,
+            token[..token.len() - 2].to_string(),
+            invitation_id.to_string(),
+            String::new(),
+        ] {
+            assert_eq!(
+                signer.verify(&token),
+                Err(DomainError::InvalidInvitationToken)
+            );
+        }
+    }
+}
@@ -1,3 +1,6 @@
 pub mod db_schema;
+pub mod id_generator;
 pub mod in_memory_db;
+pub mod in_process;
+pub mod invitation_token;
 pub mod mysql;
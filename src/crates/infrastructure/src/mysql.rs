@@ -1,3 +1,8 @@
 pub mod circle_duplicate_checker;
 pub mod circle_repository;
+pub mod invitation_repository;
+pub mod join_request_repository;
+pub mod member_repository;
+pub mod migration;
+pub mod outbox;
 pub(crate) mod test_utils;
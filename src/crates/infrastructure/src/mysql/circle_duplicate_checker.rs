@@ -1,7 +1,7 @@
 use anyhow::{Error, Result};
 use async_trait::async_trait;
 use domain::{
-    aggregate::circle::Circle,
+    aggregate::circle::Circle, error::DomainError,
     interface::circle_duplicate_checker_interface::CircleDuplicateCheckerInterface,
 };
 use sqlx::MySqlPool;
@@ -27,7 +27,7 @@ impl CircleDuplicateCheckerInterface for CircleDuplicateChecker {
             .await?;
 
         if record.is_some() {
-            return Err(anyhow::anyhow!("Circle name already exists"));
+            return Err(DomainError::Duplicate("Circle name").into());
         }
 
         Ok(())
@@ -42,14 +42,29 @@ mod tests {
     use domain::aggregate::{
         circle::Circle,
         member::Member,
-        value_object::{circle_id::CircleId, grade::Grade, major::Major},
+        value_object::{
+            age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+            grade::Grade, major::Major, member_id::MemberId,
+        },
     };
 
     fn create_test_circle(name: &str) -> Circle {
         let owner_grade = Grade::Third;
-        let owner_major = Major::try_from("Computer Science").unwrap();
-        let owner = Member::new("owner".to_string(), 21, owner_grade, owner_major);
-        Circle::create(name.to_string(), owner, 10).unwrap()
+        let owner_major = Major::from("Computer Science");
+        let owner = Member::new(
+            MemberId::gen(),
+            "owner".to_string(),
+            Age::try_from(21).unwrap(),
+            owner_grade,
+            owner_major,
+        );
+        Circle::create(
+            CircleId::gen(),
+            CircleName::try_from(name).unwrap(),
+            owner,
+            Capacity::try_from(10).unwrap(),
+        )
+        .unwrap()
     }
 
     #[tokio::test]
@@ -65,13 +80,16 @@ mod tests {
             .bind(circle.id().to_string())
             .bind(circle.name())
             .bind(circle.owner.id.to_string())
-            .bind(circle.capacity)
+            .bind(i16::from(circle.capacity))
             .execute(&pool)
             .await
             .unwrap();
 
         let result = checker.check_circle_duplicate(&circle).await;
-        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>().unwrap(),
+            DomainError::Duplicate("Circle name")
+        );
     }
 
     #[tokio::test]
@@ -1,12 +1,46 @@
+use std::collections::BTreeMap;
+
 use anyhow::Context;
 use domain::{
-    aggregate::{circle::Circle, value_object::circle_id::CircleId},
-    interface::circle_repository_interface::CircleRepositoryInterface,
+    aggregate::{
+        circle::Circle,
+        invitation::Invitation,
+        join_request::JoinRequest,
+        member::Member,
+        value_object::{academic_year::AcademicYear, circle_id::CircleId, member_id::MemberId},
+    },
+    error::DomainError,
+    interface::circle_repository_interface::{CircleQuery, CircleRepositoryInterface, CircleSort},
+};
+use sqlx::{
+    mysql::{MySqlConnection, MySqlRow},
+    MySql, QueryBuilder, Row,
 };
-use sqlx::Row;
 
+use super::{
+    invitation_repository::update_invitation, join_request_repository::update_join_request,
+    member_repository::update_member, outbox,
+};
 use crate::db_schema::{circle_data::CircleData, member_data::MemberData};
 
+// columns selected from `circles c` joined with `MEMBERS_JOIN`, as read by `circles_from_rows`
+const CIRCLE_WITH_MEMBERS_COLUMNS: &str = "
+    c.id AS circle_id, c.name AS circle_name, c.owner_id, c.capacity, c.successor_id, c.status, c.version,
+    m.id AS member_id, m.name AS member_name, m.age AS member_age, m.grade AS member_grade, m.major AS member_major,
+    ms.role AS member_role, ms.waitlist_position
+";
+
+// the owner has a membership as well, so this yields every person in the circle, followed by
+// everyone on its waitlist; membership rows carry a role and waitlist rows a position
+const MEMBERS_JOIN: &str = "
+    LEFT JOIN (
+        SELECT circle_id, member_id, role, CAST(NULL AS SIGNED) AS waitlist_position FROM memberships
+        UNION ALL
+        SELECT circle_id, member_id, NULL, position FROM waitlist_entries
+    ) ms ON ms.circle_id = c.id
+    LEFT JOIN members m ON m.id = ms.member_id
+";
+
 #[derive(Clone, Debug)]
 pub struct CircleRepository {
     db: sqlx::MySqlPool,
@@ -22,161 +56,237 @@ impl CircleRepository {
 impl CircleRepositoryInterface for CircleRepository {
     async fn find_all(&self) -> Result<Vec<Circle>, anyhow::Error> {
         tracing::info!("find_all_circles");
-        let circle_query = sqlx::query("SELECT * FROM circles");
 
-        let circle_rows = circle_query.fetch_all(&self.db).await.map_err(|e| {
-            eprintln!("Failed to fetch circles: {:?}", e);
-            anyhow::Error::msg("Failed to fetch circles")
-        })?;
-
-        let mut circles = Vec::new();
-        for circle_row in circle_rows {
-            let member_query = sqlx::query("SELECT * FROM members WHERE circle_id = ?")
-                .bind(circle_row.get::<i16, _>("id"));
-
-            let members_row = member_query.fetch_all(&self.db).await.map_err(|e| {
-                eprintln!("Failed to fetch members by circle id: {:?}", e);
-                anyhow::Error::msg("Failed to fetch members by circle id")
-            })?;
+        let query = format!(
+            "SELECT {CIRCLE_WITH_MEMBERS_COLUMNS}
+            FROM circles c
+            {MEMBERS_JOIN}
+            ORDER BY c.id, m.id"
+        );
 
-            let members: Vec<MemberData> = members_row
-                .into_iter()
-                .map(|member| MemberData {
-                    id: member.get::<String, _>("id"),
-                    name: member.get::<String, _>("name"),
-                    age: member.get::<i16, _>("age"),
-                    grade: member.get::<i16, _>("grade"),
-                    major: member.get::<String, _>("major"),
-                })
-                .collect();
+        let rows = sqlx::query(&query).fetch_all(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to fetch circles and members: {:?}", e);
+            anyhow::Error::msg("Failed to fetch circles and members")
+        })?;
 
-            let owner: MemberData = members
-                .iter()
-                .find(|member| member.id == circle_row.get::<String, _>("owner_id"))
-                .ok_or_else(|| anyhow::Error::msg("Owner not found"))?
-                .clone();
+        circles_from_rows(rows)
+    }
 
-            let circle_data = CircleData {
-                id: circle_row.get::<String, _>("id"),
-                name: circle_row.get::<String, _>("name"),
-                owner_id: circle_row.get::<String, _>("owner_id"),
-                owner,
-                capacity: circle_row.get::<i16, _>("capacity"),
-                members,
-            };
+    async fn find_by_query(&self, query: &CircleQuery) -> Result<Vec<Circle>, anyhow::Error> {
+        tracing::info!("find_circles_by_query: {:?}", query);
+        let sort_column = match query.sort {
+            CircleSort::Name => "name",
+            CircleSort::Capacity => "capacity",
+        };
 
-            circles.push(Circle::try_from(circle_data)?);
+        // page through circles in a derived table first, so LIMIT counts circles rather than member rows
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new("SELECT ");
+        builder.push(CIRCLE_WITH_MEMBERS_COLUMNS);
+        builder.push(
+            " FROM (
+                SELECT c.id, c.name, c.owner_id, c.capacity, c.successor_id, c.status, c.version
+                FROM circles c
+                JOIN members o ON o.id = c.owner_id
+                WHERE 1 = 1",
+        );
+        if let Some(name_prefix) = &query.name_prefix {
+            builder
+                .push(" AND c.name LIKE ")
+                .push_bind(format!("{}%", escape_like(name_prefix)));
+        }
+        if let Some(owner_major) = query.owner_major {
+            builder
+                .push(" AND o.major = ")
+                .push_bind(String::from(owner_major));
+        }
+        if let Some(has_free_seats) = query.has_free_seats {
+            builder.push(if has_free_seats {
+                " AND c.capacity > (SELECT COUNT(*) FROM memberships WHERE circle_id = c.id)"
+            } else {
+                " AND c.capacity <= (SELECT COUNT(*) FROM memberships WHERE circle_id = c.id)"
+            });
+        }
+        if let Some(status) = query.status {
+            builder
+                .push(" AND c.status = ")
+                .push_bind(String::from(status));
+        }
+        if let Some(cursor) = &query.cursor {
+            builder
+                .push(format!(
+                    " AND (c.{sort_column}, c.id) > (SELECT {sort_column}, id FROM circles WHERE id = "
+                ))
+                .push_bind(cursor.to_string())
+                .push(")");
         }
+        builder
+            .push(format!(" ORDER BY c.{sort_column}, c.id LIMIT "))
+            .push_bind(query.limit as u64)
+            .push(") c")
+            .push(MEMBERS_JOIN)
+            .push(format!(" ORDER BY c.{sort_column}, c.id, m.id"));
 
-        Ok(circles)
+        let rows = builder.build().fetch_all(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to fetch circles by query: {:?}", e);
+            anyhow::Error::msg("Failed to fetch circles by query")
+        })?;
+
+        circles_from_rows(rows)
     }
 
     async fn find_by_id(&self, circle_id: &CircleId) -> Result<Circle, anyhow::Error> {
         tracing::info!("find_circle_by_id: {:?}", circle_id);
+        fetch_circle(&self.db, circle_id).await
+    }
 
-        let query = "
-            SELECT 
-                c.id AS circle_id, c.name AS circle_name, c.owner_id, c.capacity,
-                m.id AS member_id, m.name AS member_name, m.age AS member_age, m.grade AS member_grade, m.major AS member_major
+    async fn find_by_member_id(&self, member_id: &MemberId) -> Result<Vec<Circle>, anyhow::Error> {
+        tracing::info!("find_circles_by_member_id: {:?}", member_id);
+
+        let query = format!(
+            "SELECT {CIRCLE_WITH_MEMBERS_COLUMNS}
             FROM circles c
-            LEFT JOIN members m ON c.id = m.circle_id
-            WHERE c.id = ?
-        ";
+            {MEMBERS_JOIN}
+            WHERE c.id IN (SELECT circle_id FROM memberships WHERE member_id = ?)
+            ORDER BY c.id, m.id"
+        );
 
-        let rows = sqlx::query(query)
-            .bind(circle_id.to_string())
+        let rows = sqlx::query(&query)
+            .bind(member_id.to_string())
             .fetch_all(&self.db)
             .await
             .map_err(|e| {
-                tracing::error!("Failed to fetch circle and members: {:?}", e);
-                anyhow::Error::msg("Failed to fetch circle and members")
+                tracing::error!("Failed to fetch circles of member: {:?}", e);
+                anyhow::Error::msg("Failed to fetch circles of member")
             })?;
 
-        if rows.is_empty() {
-            return Err(anyhow::Error::msg("Circle not found"));
-        }
+        circles_from_rows(rows)
+    }
 
-        let first_row = &rows[0];
-        let mut members: Vec<MemberData> = Vec::new();
-        let mut owner_data: Option<MemberData> = None;
+    async fn create(&self, circle: &Circle) -> Result<(), anyhow::Error> {
+        tracing::info!("create_circle : {:?}", circle);
+        let circle_data = CircleData::from(circle.clone());
 
-        for row in &rows {
-            let member = MemberData {
-                id: row.get::<String, _>("member_id"),
-                name: row.get::<String, _>("member_name"),
-                age: row.get::<i16, _>("member_age"),
-                grade: row.get::<i16, _>("member_grade"),
-                major: row.get::<String, _>("member_major"),
-            };
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to start transaction")?;
 
-            if member.id == first_row.get::<String, _>("owner_id") {
-                owner_data = Some(member.clone());
-            }
-            members.push(member);
-        }
+        let circle_query = sqlx::query(
+            "INSERT INTO circles (id, name, owner_id, capacity, successor_id, status, version) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(circle_data.id.as_str())
+        .bind(circle_data.name)
+        .bind(circle_data.owner_id.as_str())
+        .bind(circle_data.capacity)
+        .bind(circle_data.successor_id)
+        .bind(circle_data.status)
+        .bind(circle_data.version);
 
-        let owner = owner_data.ok_or_else(|| anyhow::Error::msg("Owner not found"))?;
+        circle_query.execute(&mut *tx).await.map_err(|e| {
+            eprintln!("Failed to insert circle: {:?}", e);
+            anyhow::Error::msg("Failed to insert circle")
+        })?;
 
-        let circle_data = CircleData {
-            id: first_row.get::<String, _>("circle_id"),
-            name: first_row.get::<String, _>("circle_name"),
-            owner_id: first_row.get::<String, _>("owner_id"),
+        let owner = std::slice::from_ref(&circle_data.owner);
+        sync_memberships(
+            &mut tx,
+            &circle_data.owner_id,
+            &circle_data.roles,
+            circle_data.id.as_str(),
             owner,
-            capacity: first_row.get::<i16, _>("capacity"),
-            members,
-        };
+        )
+        .await?;
+
+        outbox::enqueue(&mut tx, &circle.events).await?;
 
-        Ok(Circle::try_from(circle_data)?)
+        // Commit transaction
+        tx.commit().await.context("Failed to commit transaction")?;
+        Ok(())
     }
 
-    async fn create(&self, circle: &Circle) -> Result<(), anyhow::Error> {
-        tracing::info!("create_circle : {:?}", circle);
-        let circle_data = CircleData::try_from(circle.clone())?;
+    async fn update(&self, circle: &Circle) -> Result<Circle, anyhow::Error> {
+        tracing::info!("update_circle : {:?}", circle);
 
+        // Start transaction
         let mut tx = self
             .db
             .begin()
             .await
             .context("Failed to start transaction")?;
 
-        let circle_query =
-            sqlx::query("INSERT INTO circles (id, name, owner_id, capacity) VALUES (?, ?, ?, ?)")
-                .bind(circle_data.id.as_str())
-                .bind(circle_data.name)
-                .bind(circle_data.owner_id)
-                .bind(circle_data.capacity);
+        let updated = update_circle(&mut tx, circle).await?;
 
-        circle_query.execute(&mut *tx).await.map_err(|e| {
-            eprintln!("Failed to insert circle: {:?}", e);
-            anyhow::Error::msg("Failed to insert circle")
-        })?;
+        // Commit transaction
+        tx.commit().await.context("Failed to commit transaction")?;
 
-        let owner_query = sqlx::query(
-            "INSERT INTO members (id, name, age, grade, major, circle_id) VALUES (?, ?, ?, ?, ?, ?)",
+        Ok(updated)
+    }
+
+    async fn rollover(
+        &self,
+        academic_year: AcademicYear,
+        circles: &[Circle],
+        members: &[Member],
+    ) -> Result<Vec<Circle>, anyhow::Error> {
+        tracing::info!(
+            "rollover {} : {} circles, {} members",
+            academic_year,
+            circles.len(),
+            members.len()
         );
 
-        owner_query
-            .bind(circle_data.owner.id)
-            .bind(circle_data.owner.name)
-            .bind(circle_data.owner.age)
-            .bind(circle_data.owner.grade)
-            .bind(circle_data.owner.major)
-            .bind(circle_data.id.as_str())
+        // Start transaction; returning early drops it, which rolls every update back
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to start transaction")?;
+
+        // claimed first, so a concurrent rollover of the same year fails here before touching
+        // any circle
+        sqlx::query("INSERT INTO academic_year_rollovers (academic_year) VALUES (?)")
+            .bind(i16::from(academic_year))
             .execute(&mut *tx)
             .await
-            .map_err(|e| {
-                eprintln!("Failed to insert owner: {:?}", e);
-                anyhow::Error::msg("Failed to insert owner")
+            .map_err(|e| match e {
+                sqlx::Error::Database(e) if e.is_unique_violation() => {
+                    DomainError::AlreadyRolledOver(academic_year).into()
+                }
+                e => {
+                    tracing::error!("Failed to record academic year rollover: {:?}", e);
+                    anyhow::Error::msg("Failed to record academic year rollover")
+                }
             })?;
+        let later = sqlx::query("SELECT 1 FROM academic_year_rollovers WHERE academic_year > ?")
+            .bind(i16::from(academic_year))
+            .fetch_optional(&mut *tx)
+            .await
+            .context("Failed to check later rollovers")?;
+        if later.is_some() {
+            return Err(DomainError::AlreadyRolledOver(academic_year).into());
+        }
+
+        let mut updated = Vec::with_capacity(circles.len());
+        for circle in circles {
+            updated.push(update_circle(&mut tx, circle).await?);
+        }
+        for member in members {
+            update_member(&mut tx, member).await?;
+        }
 
         // Commit transaction
         tx.commit().await.context("Failed to commit transaction")?;
-        Ok(())
+
+        Ok(updated)
     }
 
-    async fn update(&self, circle: &Circle) -> Result<Circle, anyhow::Error> {
-        tracing::info!("update_circle : {:?}", circle);
-        let circle_data = CircleData::try_from(circle.clone())?;
+    async fn add_member(
+        &self,
+        circle_id: &CircleId,
+        member: &Member,
+    ) -> Result<Circle, anyhow::Error> {
+        tracing::info!("add_member : {:?} {:?}", circle_id, member.id);
 
         // Start transaction
         let mut tx = self
@@ -185,31 +295,1209 @@ impl CircleRepositoryInterface for CircleRepository {
             .await
             .context("Failed to start transaction")?;
 
-        // Update circle
-        let circle_query =
-            sqlx::query("UPDATE circles SET name = ?, owner_id = ?, capacity = ? WHERE id = ?")
-                .bind(circle_data.name)
-                .bind(circle_data.owner_id)
-                .bind(circle_data.capacity)
-                .bind(circle_data.id);
+        let updated = seat_member(&mut tx, circle_id, member).await?;
 
-        circle_query.execute(&mut *tx).await.map_err(|e| {
-            eprintln!("Failed to update circle: {:?}", e);
-            anyhow::Error::msg("Failed to update circle")
-        })?;
+        // Commit transaction
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        Ok(updated)
+    }
+
+    async fn add_member_by_join_request(
+        &self,
+        join_request: &JoinRequest,
+        member: &Member,
+    ) -> Result<Circle, anyhow::Error> {
+        tracing::info!(
+            "add_member_by_join_request : {:?} {:?}",
+            join_request.id,
+            member.id
+        );
+
+        // Start transaction; returning early drops it, which rolls the approval back
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to start transaction")?;
+
+        update_join_request(&mut tx, join_request).await?;
+        let updated = seat_member(&mut tx, &join_request.circle_id, member).await?;
+
+        // Commit transaction
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        Ok(updated)
+    }
+
+    async fn add_member_by_invitation(
+        &self,
+        invitation: &Invitation,
+        member: &Member,
+    ) -> Result<Circle, anyhow::Error> {
+        tracing::info!(
+            "add_member_by_invitation : {:?} {:?}",
+            invitation.id,
+            member.id
+        );
+
+        // Start transaction; returning early drops it, which rolls the acceptance back
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to start transaction")?;
+
+        update_invitation(&mut tx, invitation).await?;
+        let updated = seat_member(&mut tx, &invitation.circle_id, member).await?;
+
+        // Commit transaction
+        tx.commit().await.context("Failed to commit transaction")?;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, circle: &Circle) -> Result<(), anyhow::Error> {
+        tracing::info!("delete_circle : {:?}", circle);
+
+        // Start transaction
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to start transaction")?;
 
-        // TODO: Update members
+        // Delete circle; its memberships go with it, the members themselves stay
+        let result = sqlx::query("DELETE FROM circles WHERE id = ?")
+            .bind(circle.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete circle: {:?}", e);
+                anyhow::Error::msg("Failed to delete circle")
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::NotFound("Circle").into());
+        }
 
         // Commit transaction
         tx.commit().await.context("Failed to commit transaction")?;
+        Ok(())
+    }
+}
+
+async fn fetch_circle<'c, E>(executor: E, circle_id: &CircleId) -> Result<Circle, anyhow::Error>
+where
+    E: sqlx::Executor<'c, Database = MySql>,
+{
+    let query = format!(
+        "SELECT {CIRCLE_WITH_MEMBERS_COLUMNS}
+        FROM circles c
+        {MEMBERS_JOIN}
+        WHERE c.id = ?"
+    );
+
+    let rows = sqlx::query(&query)
+        .bind(circle_id.to_string())
+        .fetch_all(executor)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch circle and members: {:?}", e);
+            anyhow::Error::msg("Failed to fetch circle and members")
+        })?;
+
+    circles_from_rows(rows)?
+        .pop()
+        .ok_or_else(|| DomainError::NotFound("Circle").into())
+}
+
+/// Writes `circle` and syncs its members on `conn`, guarded by the circle's version.
+async fn seat_member(
+    conn: &mut MySqlConnection,
+    circle_id: &CircleId,
+    member: &Member,
+) -> Result<Circle, anyhow::Error> {
+    // Lock the circle row first: concurrent joins queue up here, and the snapshot read below
+    // is taken only after the lock is held, so it sees the members the previous join added
+    sqlx::query("SELECT 1 FROM circles WHERE id = ? FOR UPDATE")
+        .bind(circle_id.to_string())
+        .fetch_optional(&mut *conn)
+        .await
+        .context("Failed to lock circle")?
+        .ok_or(DomainError::NotFound("Circle"))?;
+
+    let circle = fetch_circle(&mut *conn, circle_id)
+        .await?
+        .add_member(member.clone())?;
+    update_circle(conn, &circle).await
+}
+
+async fn update_circle(
+    conn: &mut MySqlConnection,
+    circle: &Circle,
+) -> Result<Circle, anyhow::Error> {
+    let circle_data = CircleData::from(circle.clone());
+
+    // Update circle, only if nobody else has bumped its version since it was read
+    let circle_query = sqlx::query(
+        "UPDATE circles SET name = ?, owner_id = ?, capacity = ?, successor_id = ?, status = ?, version = version + 1 WHERE id = ? AND version = ?",
+    )
+    .bind(circle_data.name)
+    .bind(circle_data.owner_id.as_str())
+    .bind(circle_data.capacity)
+    .bind(circle_data.successor_id)
+    .bind(circle_data.status)
+    .bind(circle_data.id.as_str())
+    .bind(circle_data.version);
+
+    let result = circle_query.execute(&mut *conn).await.map_err(|e| {
+        eprintln!("Failed to update circle: {:?}", e);
+        anyhow::Error::msg("Failed to update circle")
+    })?;
 
-        Ok(circle.clone())
+    if result.rows_affected() == 0 {
+        let exists = sqlx::query("SELECT 1 FROM circles WHERE id = ?")
+            .bind(circle_data.id.as_str())
+            .fetch_optional(&mut *conn)
+            .await
+            .context("Failed to check circle existence")?
+            .is_some();
+        return Err(if exists {
+            DomainError::VersionConflict("Circle").into()
+        } else {
+            DomainError::NotFound("Circle").into()
+        });
     }
 
-    async fn delete(&self, _circle: &Circle) -> Result<(), anyhow::Error> {
-        todo!()
+    // Sync members: the owner has a membership as well
+    let members: Vec<MemberData> = std::iter::once(circle_data.owner)
+        .chain(circle_data.members)
+        .collect();
+    sync_memberships(
+        &mut *conn,
+        &circle_data.owner_id,
+        &circle_data.roles,
+        circle_data.id.as_str(),
+        &members,
+    )
+    .await?;
+    sync_waitlist(&mut *conn, circle_data.id.as_str(), &circle_data.waitlist).await?;
+
+    outbox::enqueue(&mut *conn, &circle.events).await?;
+
+    Ok(Circle {
+        version: circle.version + 1,
+        events: vec![],
+        ..circle.clone()
+    })
+}
+
+/// Makes `members` the memberships of `circle_id` with their roles, dropping everyone else's.
+/// Members are stored on their own, so nothing about the members themselves is written.
+async fn sync_memberships(
+    conn: &mut MySqlConnection,
+    owner_id: &str,
+    roles: &BTreeMap<String, String>,
+    circle_id: &str,
+    members: &[MemberData],
+) -> Result<(), anyhow::Error> {
+    // the primary key would merge a repeated id into one row, hiding the bug that produced it
+    let mut seen = std::collections::HashSet::new();
+    if !members.iter().all(|member| seen.insert(member.id.as_str())) {
+        return Err(DomainError::AlreadyMember.into());
     }
+
+    let mut delete_query = sqlx::QueryBuilder::new("DELETE FROM memberships WHERE circle_id = ");
+    delete_query
+        .push_bind(circle_id)
+        .push(" AND member_id NOT IN (");
+    let mut separated = delete_query.separated(", ");
+    for member in members {
+        separated.push_bind(member.id.as_str());
+    }
+    separated.push_unseparated(")");
+
+    delete_query
+        .build()
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete removed memberships: {:?}", e);
+            anyhow::Error::msg("Failed to delete removed memberships")
+        })?;
+
+    // existing memberships keep their `joined_at`; only the role follows a change
+    let mut upsert_query =
+        sqlx::QueryBuilder::new("INSERT INTO memberships (circle_id, member_id, role) ");
+    upsert_query.push_values(members, |mut b, member| {
+        b.push_bind(circle_id)
+            .push_bind(member.id.as_str())
+            .push_bind(if member.id == owner_id {
+                "owner"
+            } else {
+                roles.get(&member.id).map_or("member", String::as_str)
+            });
+    });
+    upsert_query.push(" ON DUPLICATE KEY UPDATE role = VALUES(role)");
+
+    upsert_query
+        .build()
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => {
+                DomainError::NotFound("Member").into()
+            }
+            e => {
+                tracing::error!("Failed to upsert memberships: {:?}", e);
+                anyhow::Error::msg("Failed to upsert memberships")
+            }
+        })?;
+    Ok(())
+}
+
+/// Makes `waitlist` the waitlist of `circle_id`, in order.
+async fn sync_waitlist(
+    conn: &mut MySqlConnection,
+    circle_id: &str,
+    waitlist: &[MemberData],
+) -> Result<(), anyhow::Error> {
+    // positions shift whenever someone ahead leaves, so the whole line is rewritten
+    sqlx::query("DELETE FROM waitlist_entries WHERE circle_id = ?")
+        .bind(circle_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear waitlist: {:?}", e);
+            anyhow::Error::msg("Failed to clear waitlist")
+        })?;
+    if waitlist.is_empty() {
+        return Ok(());
+    }
+
+    let mut insert_query =
+        sqlx::QueryBuilder::new("INSERT INTO waitlist_entries (circle_id, member_id, position) ");
+    insert_query.push_values(waitlist.iter().zip(1_i32..), |mut b, (member, position)| {
+        b.push_bind(circle_id)
+            .push_bind(member.id.as_str())
+            .push_bind(position);
+    });
+
+    insert_query
+        .build()
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => {
+                DomainError::NotFound("Member").into()
+            }
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                DomainError::AlreadyWaitlisted.into()
+            }
+            e => {
+                tracing::error!("Failed to insert waitlist entries: {:?}", e);
+                anyhow::Error::msg("Failed to insert waitlist entries")
+            }
+        })?;
+
+    Ok(())
+}
+
+// a person joined onto a circle row: a member with their role, or someone waiting with their
+// place in line
+struct JoinedMember {
+    member: MemberData,
+    role: Option<String>,
+    waitlist_position: Option<i64>,
+}
+
+/// Folds joined circle/member rows, grouped by circle, into circles in row order.
+fn circles_from_rows(rows: Vec<MySqlRow>) -> Result<Vec<Circle>, anyhow::Error> {
+    let mut grouped: Vec<(MySqlRow, Vec<JoinedMember>)> = Vec::new();
+    for row in rows {
+        let member = row.get::<Option<String>, _>("member_id").map(|member_id| {
+            let member = MemberData {
+                id: member_id,
+                name: row.get::<String, _>("member_name"),
+                age: row.get::<i16, _>("member_age"),
+                grade: row.get::<i16, _>("member_grade"),
+                major: row.get::<String, _>("member_major"),
+            };
+            JoinedMember {
+                member,
+                role: row.get::<Option<String>, _>("member_role"),
+                waitlist_position: row.get::<Option<i64>, _>("waitlist_position"),
+            }
+        });
+
+        let circle_id = row.get::<String, _>("circle_id");
+        match grouped
+            .last_mut()
+            .filter(|(circle_row, _)| circle_row.get::<String, _>("circle_id") == circle_id)
+        {
+            Some((_, members)) => members.extend(member),
+            None => grouped.push((row, member.into_iter().collect())),
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(circle_row, rows)| {
+            let (mut waitlist, members): (Vec<_>, Vec<_>) = rows
+                .into_iter()
+                .partition(|joined| joined.waitlist_position.is_some());
+            waitlist.sort_by_key(|joined| joined.waitlist_position);
+            let roles = members
+                .iter()
+                .filter_map(|joined| Some((joined.member.id.clone(), joined.role.clone()?)))
+                .collect();
+            let members: Vec<MemberData> =
+                members.into_iter().map(|joined| joined.member).collect();
+
+            let owner_id = circle_row.get::<String, _>("owner_id");
+            let owner = members
+                .iter()
+                .find(|member| member.id == owner_id)
+                .ok_or_else(|| anyhow::Error::msg("Owner not found"))?
+                .clone();
+
+            Circle::try_from(CircleData {
+                id: circle_row.get::<String, _>("circle_id"),
+                name: circle_row.get::<String, _>("circle_name"),
+                owner_id,
+                owner,
+                capacity: circle_row.get::<i16, _>("capacity"),
+                members,
+                waitlist: waitlist.into_iter().map(|joined| joined.member).collect(),
+                roles,
+                successor_id: circle_row.get::<Option<String>, _>("successor_id"),
+                status: circle_row.get::<String, _>("status"),
+                version: circle_row.get::<u32, _>("version"),
+            })
+        })
+        .collect()
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use domain::{
+        aggregate::{
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, circle_status::CircleStatus,
+                grade::Grade, invitation_id::InvitationId, invitation_status::InvitationStatus,
+                join_request_id::JoinRequestId, join_request_status::JoinRequestStatus,
+                major::Major, member_id::MemberId, role::Role,
+            },
+        },
+        interface::{
+            invitation_repository_interface::InvitationRepositoryInterface,
+            join_request_repository_interface::JoinRequestRepositoryInterface,
+            member_repository_interface::MemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+    use crate::mysql::{
+        invitation_repository::InvitationRepository,
+        join_request_repository::JoinRequestRepository,
+        member_repository::MemberRepository,
+        test_utils::{create_members, setup, setup_with_max_connections},
+    };
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_update_syncs_members() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let member1 = Member::new(
+            MemberId::gen(),
+            "member1".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        let member2 = Member::new(
+            MemberId::gen(),
+            "member2".to_string(),
+            Age::try_from(20)?,
+            Grade::Second,
+            Major::Law,
+        );
+        let member_repository = MemberRepository::new(pool.clone());
+        member_repository.create(&member1).await?;
+        member_repository.create(&member2).await?;
+        let circle = circle
+            .add_member(member1.clone())?
+            .add_member(member2.clone())?;
+        let circle = repository.update(&circle).await?;
+        assert_eq!(repository.find_by_id(&circle.id).await?, circle);
+
+        let circle = repository.update(&circle.remove_member(&member1)?).await?;
+
+        let fetched = repository.find_by_id(&circle.id).await?;
+        assert_eq!(fetched, circle);
+        assert!(fetched.member(&member1.id).is_none());
+        assert_eq!(fetched.member(&member2.id), Some(&member2));
+        // the removed member is only detached, not deleted
+        assert_eq!(member_repository.find_by_id(&member1.id).await?, member1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_syncs_waitlist() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(2)?,
+        )?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let member_repository = MemberRepository::new(pool.clone());
+        let mut members = Vec::new();
+        for name in ["member1", "member2", "member3"] {
+            let member = Member::new(
+                MemberId::gen(),
+                name.to_string(),
+                Age::try_from(19)?,
+                Grade::First,
+                Major::Art,
+            );
+            member_repository.create(&member).await?;
+            members.push(member);
+        }
+        let circle = circle
+            .add_member(members[0].clone())?
+            .add_member(members[1].clone())?
+            .add_member(members[2].clone())?;
+        let circle = repository.update(&circle).await?;
+        let fetched = repository.find_by_id(&circle.id).await?;
+        assert_eq!(fetched, circle);
+        assert_eq!(
+            fetched.waitlist,
+            vec![members[1].clone(), members[2].clone()]
+        );
+        // waiting doesn't make anyone a member
+        assert!(repository
+            .find_by_member_id(&members[1].id)
+            .await?
+            .is_empty());
+
+        let circle = repository
+            .update(&circle.remove_member(&members[0])?)
+            .await?;
+        let fetched = repository.find_by_id(&circle.id).await?;
+        assert_eq!(fetched, circle);
+        assert_eq!(fetched.members, vec![members[1].clone()]);
+        assert_eq!(fetched.waitlist_position(&members[2].id), Some(1));
+
+        // roles ride along on the membership rows
+        let circle = repository
+            .update(&circle.assign_role(&members[1].id, Role::Treasurer)?)
+            .await?;
+        let fetched = repository.find_by_id(&circle.id).await?;
+        assert_eq!(fetched, circle);
+        assert_eq!(fetched.role(&members[1].id), Some(Role::Treasurer));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_members_are_referenced() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let member_repository = MemberRepository::new(pool.clone());
+        let circle = build_circle()?;
+        assert_eq!(
+            repository
+                .create(&circle)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        // the circle sees the member's own changes
+        let owner = circle
+            .owner
+            .clone()
+            .update(Some("renamed".to_string()), None, None);
+        member_repository.update(&owner).await?;
+        assert_eq!(repository.find_by_id(&circle.id).await?.owner, owner);
+
+        // the same person can belong to several circles
+        let other = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Art club")?,
+            owner.clone(),
+            Capacity::try_from(3)?,
+        )?;
+        repository.create(&other).await?;
+        let mut expected = vec![circle.id, other.id];
+        expected.sort_by_key(|id| id.to_string());
+        let found: Vec<CircleId> = repository
+            .find_by_member_id(&owner.id)
+            .await?
+            .into_iter()
+            .map(|circle| circle.id)
+            .collect();
+        assert_eq!(found, expected);
+        assert!(repository
+            .find_by_member_id(&MemberId::gen())
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rejects_duplicate_members() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let mut duplicated = circle.clone();
+        duplicated.members.push(circle.owner.clone());
+        assert_eq!(
+            repository
+                .update(&duplicated)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, circle);
+
+        // two joins of the same member from the same snapshot: the second one loses the race
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+        let joined = circle.add_member(member)?;
+        repository.update(&joined).await?;
+        assert_eq!(
+            repository
+                .update(&joined)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::VersionConflict("Circle")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+
+        let joined = repository.add_member(&circle.id, &member).await?;
+        assert_eq!(joined.version, circle.version + 1);
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+        assert_eq!(
+            repository
+                .add_member(&circle.id, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            repository
+                .add_member(&CircleId::gen(), &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Circle")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member_by_join_request() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let join_request_repository = JoinRequestRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        join_request_repository.create(&join_request).await?;
+
+        // the member joined directly in the meantime, so the approval can't seat them and the
+        // request stays pending
+        let joined = repository.add_member(&circle.id, &member).await?;
+        let approved = join_request.clone().approve()?;
+        assert_eq!(
+            repository
+                .add_member_by_join_request(&approved, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            join_request_repository
+                .find_by_id(&join_request.id)
+                .await?
+                .status,
+            JoinRequestStatus::Pending
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+
+        let other = Member::new(
+            MemberId::gen(),
+            "other".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&other).await?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, other.id.clone())?;
+        join_request_repository.create(&join_request).await?;
+        let approved = join_request.approve()?;
+        let updated = repository
+            .add_member_by_join_request(&approved, &other)
+            .await?;
+        assert!(updated.member(&other.id).is_some());
+        assert_eq!(
+            join_request_repository
+                .find_by_id(&approved.id)
+                .await?
+                .status,
+            JoinRequestStatus::Approved
+        );
+        // only one approval wins the pending request
+        assert_eq!(
+            repository
+                .add_member_by_join_request(&approved, &other)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::JoinRequestNotPending(JoinRequestStatus::Approved)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_add_member_by_invitation() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let invitation_repository = InvitationRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+        let issue = |member_id: &MemberId| {
+            Invitation::issue(
+                InvitationId::gen(),
+                &circle,
+                member_id.clone(),
+                SystemTime::now(),
+                Duration::from_secs(60),
+            )
+        };
+        let invitation = issue(&member.id)?;
+        invitation_repository.create(&invitation).await?;
+
+        // the member joined directly in the meantime, so accepting can't seat them and the
+        // invitation stays usable
+        let joined = repository.add_member(&circle.id, &member).await?;
+        let accepted = invitation.clone().accept(SystemTime::now())?;
+        assert_eq!(
+            repository
+                .add_member_by_invitation(&accepted, &member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::AlreadyMember
+        );
+        assert_eq!(
+            invitation_repository
+                .find_by_id(&invitation.id)
+                .await?
+                .status,
+            InvitationStatus::Pending
+        );
+        assert_eq!(repository.find_by_id(&circle.id).await?, joined);
+
+        let other = Member::new(
+            MemberId::gen(),
+            "other".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&other).await?;
+        let invitation = issue(&other.id)?;
+        invitation_repository.create(&invitation).await?;
+        let accepted = invitation.accept(SystemTime::now())?;
+        let updated = repository
+            .add_member_by_invitation(&accepted, &other)
+            .await?;
+        assert!(updated.member(&other.id).is_some());
+        assert_eq!(
+            invitation_repository.find_by_id(&accepted.id).await?.status,
+            InvitationStatus::Accepted
+        );
+        // the token can't be used twice
+        assert_eq!(
+            repository
+                .add_member_by_invitation(&accepted, &other)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::InvitationNotPending(InvitationStatus::Accepted)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stress_concurrent_joins_respect_capacity() -> anyhow::Result<()> {
+        const CAPACITY: i16 = 5;
+        const JOINS: usize = 40;
+
+        let (_container, pool) = setup_with_max_connections(JOINS as u32).await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(CAPACITY)?,
+        )?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let member_repository = MemberRepository::new(pool.clone());
+        let mut members = Vec::with_capacity(JOINS);
+        for i in 0..JOINS {
+            let member = Member::new(
+                MemberId::gen(),
+                format!("member{i}"),
+                Age::try_from(19)?,
+                Grade::First,
+                Major::Art,
+            );
+            member_repository.create(&member).await?;
+            members.push(member);
+        }
+
+        let joins = members.into_iter().map(|member| {
+            let repository = repository.clone();
+            let circle_id = circle.id.clone();
+            tokio::spawn(async move { repository.add_member(&circle_id, &member).await })
+        });
+        for join in joins.collect::<Vec<_>>() {
+            join.await??;
+        }
+
+        // the owner holds one seat; every other seat is taken exactly once, and everyone who
+        // came later waits in line
+        let memberships: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM memberships WHERE circle_id = ?")
+                .bind(circle.id.to_string())
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(memberships, i64::from(CAPACITY));
+        let stored = repository.find_by_id(&circle.id).await?;
+        assert!(stored.is_full());
+        assert_eq!(stored.waitlist.len(), JOINS - (CAPACITY as usize - 1));
+        assert_eq!(stored.version, circle.version + JOINS as u32);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_version_conflict() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let updated = repository
+            .update(
+                &circle
+                    .clone()
+                    .update(Some(CircleName::try_from("renamed")?), None)?,
+            )
+            .await?;
+        assert_eq!(updated.version, circle.version + 1);
+        assert_eq!(
+            repository.find_by_id(&circle.id).await?.version,
+            updated.version
+        );
+
+        // a second writer still holding the original version loses
+        let stale = circle.update(None, Some(Capacity::try_from(20)?))?;
+        assert_eq!(
+            repository
+                .update(&stale)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::VersionConflict("Circle")
+        );
+        assert_eq!(
+            repository.find_by_id(&stale.id).await?.capacity,
+            updated.capacity
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle1 = build_circle()?;
+        let circle2 = build_circle()?;
+        create_members(&pool, &circle1).await?;
+        repository.create(&circle1).await?;
+        create_members(&pool, &circle2).await?;
+        repository.create(&circle2).await?;
+        let promoted = vec![circle1.owner.clone().promote()];
+        let academic_year = AcademicYear::try_from(2025)?;
+        let missing = Member::new(
+            MemberId::gen(),
+            "missing".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+
+        // circle2 is bumped by someone else, so the whole rollover must roll back
+        let stale = repository.update(&circle2).await?;
+        let result = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover(), circle2.clone().rollover()],
+                &promoted,
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>()?,
+            DomainError::VersionConflict("Circle")
+        );
+        // and a missing member rolls back the circles written before it
+        let result = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover()],
+                &[promoted[0].clone(), missing],
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
+
+        let updated = repository
+            .rollover(
+                academic_year,
+                &[circle1.clone().rollover(), stale.clone().rollover()],
+                &promoted,
+            )
+            .await?;
+        let circle1 = repository.find_by_id(&circle1.id).await?;
+        assert_eq!(circle1.version, updated[0].version);
+        assert_eq!(circle1.owner, promoted[0]);
+        assert_eq!(repository.find_by_id(&stale.id).await?, updated[1]);
+
+        // the failed attempts didn't record the year, the successful one did
+        for academic_year in [academic_year, AcademicYear::try_from(2024)?] {
+            let result = repository
+                .rollover(academic_year, &[circle1.clone().rollover()], &[])
+                .await;
+            assert_eq!(
+                result.unwrap_err().downcast::<DomainError>()?,
+                DomainError::AlreadyRolledOver(academic_year)
+            );
+        }
+        assert_eq!(repository.find_by_id(&circle1.id).await?, circle1);
+        repository
+            .rollover(AcademicYear::try_from(2026)?, &[], &[])
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_by_query() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let age = Age::try_from(21)?;
+        let owner = |major| {
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                age,
+                Grade::Third,
+                major,
+            )
+        };
+        let art = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Art club")?,
+            owner(Major::Art),
+            Capacity::try_from(1)?,
+        )?;
+        let music = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            owner(Major::Music),
+            Capacity::try_from(3)?,
+        )?;
+        let musical = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Musical_club")?,
+            owner(Major::Art),
+            Capacity::try_from(2)?,
+        )?;
+        for circle in [&art, &music, &musical] {
+            create_members(&pool, circle).await?;
+            repository.create(circle).await?;
+        }
+        let query = CircleQuery {
+            name_prefix: None,
+            owner_major: None,
+            has_free_seats: None,
+            status: None,
+            sort: CircleSort::Name,
+            cursor: None,
+            limit: 10,
+        };
+
+        assert_eq!(
+            repository.find_by_query(&query).await?,
+            vec![art.clone(), music.clone(), musical.clone()]
+        );
+        let by_capacity = CircleQuery {
+            sort: CircleSort::Capacity,
+            ..query.clone()
+        };
+        assert_eq!(
+            repository.find_by_query(&by_capacity).await?,
+            vec![art.clone(), musical.clone(), music.clone()]
+        );
+        let filtered = CircleQuery {
+            name_prefix: Some("Musical_".to_string()),
+            owner_major: Some(Major::Art),
+            has_free_seats: Some(true),
+            ..query.clone()
+        };
+        assert_eq!(
+            repository.find_by_query(&filtered).await?,
+            vec![musical.clone()]
+        );
+        let first_page = CircleQuery {
+            limit: 2,
+            ..query.clone()
+        };
+        assert_eq!(
+            repository.find_by_query(&first_page).await?,
+            vec![art.clone(), music.clone()]
+        );
+        let second_page = CircleQuery {
+            cursor: Some(music.id.clone()),
+            ..first_page
+        };
+        assert_eq!(
+            repository.find_by_query(&second_page).await?,
+            vec![musical.clone()]
+        );
+
+        let disbanded = repository.update(&music.disband()?).await?;
+        let by_status = CircleQuery {
+            status: Some(CircleStatus::Disbanded),
+            ..query.clone()
+        };
+        assert_eq!(repository.find_by_query(&by_status).await?, vec![disbanded]);
+        // the cursor circle dropped out of the filter between pages
+        let recruiting_second_page = CircleQuery {
+            status: Some(CircleStatus::Recruiting),
+            ..second_page
+        };
+        assert_eq!(
+            repository.find_by_query(&recruiting_second_page).await?,
+            vec![musical]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_all() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle1 = build_circle()?.add_member(Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        ))?;
+        let circle2 = build_circle()?;
+        create_members(&pool, &circle1).await?;
+        repository.create(&circle1).await?;
+        repository.update(&circle1).await?;
+        create_members(&pool, &circle2).await?;
+        repository.create(&circle2).await?;
+
+        let mut expected = vec![circle1, circle2];
+        expected.sort_by_key(|circle| circle.id.to_string());
+        let mut found = repository.find_all().await?;
+        found.sort_by_key(|circle| circle.id.to_string());
+        assert_eq!(found, expected);
+        Ok(())
+    }
+
+    // `setup` hands out a single-connection pool, so the session counters cover every query
+    async fn questions(pool: &sqlx::MySqlPool) -> anyhow::Result<i64> {
+        let (_, value): (String, String) = sqlx::query_as("SHOW SESSION STATUS LIKE 'Questions'")
+            .fetch_one(pool)
+            .await?;
+        Ok(value.parse()?)
+    }
+
+    #[tokio::test]
+    async fn bench_find_all_query_count() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+
+        let mut query_counts = Vec::new();
+        let mut created = 0;
+        for circles in [1, 10, 100] {
+            for _ in created..circles {
+                let circle = build_circle()?;
+                create_members(&pool, &circle).await?;
+                repository.create(&circle).await?;
+            }
+            created = circles;
+
+            let before = questions(&pool).await?;
+            let started = std::time::Instant::now();
+            assert_eq!(repository.find_all().await?.len(), circles);
+            let elapsed = started.elapsed();
+            // the second SHOW STATUS counts itself, which is constant across runs
+            let queries = questions(&pool).await? - before;
+            println!("find_all: {circles} circles, {queries} queries, {elapsed:?}");
+            query_counts.push(queries);
+        }
+
+        assert!(query_counts.windows(2).all(|w| w[0] == w[1]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        repository.delete(&circle).await?;
+
+        assert!(repository.find_by_id(&circle.id).await.is_err());
+        let (members,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM memberships WHERE circle_id = ?")
+                .bind(circle.id.to_string())
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(members, 0);
+        // the owner outlives the circle
+        MemberRepository::new(pool)
+            .find_by_id(&circle.owner.id)
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_not_found() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+
+        let error = repository.delete(&circle).await.unwrap_err();
+
+        assert_eq!(
+            error.downcast::<DomainError>()?,
+            DomainError::NotFound("Circle")
+        );
+        Ok(())
+    }
+}
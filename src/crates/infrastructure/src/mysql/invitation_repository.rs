@@ -0,0 +1,273 @@
+use anyhow::Context;
+use domain::{
+    aggregate::{
+        invitation::Invitation,
+        value_object::{
+            circle_id::CircleId, invitation_id::InvitationId, invitation_status::InvitationStatus,
+        },
+    },
+    error::DomainError,
+    interface::invitation_repository_interface::InvitationRepositoryInterface,
+};
+use sqlx::{
+    mysql::{MySqlConnection, MySqlRow},
+    MySql, QueryBuilder, Row,
+};
+
+use crate::db_schema::invitation_data::InvitationData;
+
+#[derive(Clone, Debug)]
+pub struct InvitationRepository {
+    db: sqlx::MySqlPool,
+}
+
+impl InvitationRepository {
+    pub fn new(db: sqlx::MySqlPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl InvitationRepositoryInterface for InvitationRepository {
+    async fn find_by_id(&self, invitation_id: &InvitationId) -> Result<Invitation, anyhow::Error> {
+        tracing::info!("find_invitation_by_id: {:?}", invitation_id);
+
+        let row = sqlx::query(
+            "SELECT id, circle_id, member_id, expires_at, status FROM invitations WHERE id = ?",
+        )
+        .bind(invitation_id.to_string())
+        .fetch_optional(&self.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch invitation: {:?}", e);
+            anyhow::Error::msg("Failed to fetch invitation")
+        })?
+        .ok_or(DomainError::NotFound("Invitation"))?;
+
+        invitation_from_row(row)
+    }
+
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<InvitationStatus>,
+    ) -> Result<Vec<Invitation>, anyhow::Error> {
+        tracing::info!("find_invitations_by_circle_id: {:?}", circle_id);
+
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new(
+            "SELECT id, circle_id, member_id, expires_at, status FROM invitations WHERE circle_id = ",
+        );
+        builder.push_bind(circle_id.to_string());
+        if let Some(status) = status {
+            builder
+                .push(" AND status = ")
+                .push_bind(String::from(status));
+        }
+        builder.push(" ORDER BY id");
+
+        let rows = builder.build().fetch_all(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to fetch invitations: {:?}", e);
+            anyhow::Error::msg("Failed to fetch invitations")
+        })?;
+
+        rows.into_iter().map(invitation_from_row).collect()
+    }
+
+    async fn create(&self, invitation: &Invitation) -> Result<(), anyhow::Error> {
+        tracing::info!("create_invitation : {:?}", invitation);
+        let invitation_data = InvitationData::from(invitation.clone());
+
+        sqlx::query(
+            "INSERT INTO invitations (id, circle_id, member_id, expires_at, status) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(invitation_data.id)
+        .bind(invitation_data.circle_id)
+        .bind(invitation_data.member_id)
+        .bind(invitation_data.expires_at)
+        .bind(invitation_data.status)
+        .execute(&self.db)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                DomainError::Duplicate("Invitation").into()
+            }
+            e => {
+                tracing::error!("Failed to insert invitation: {:?}", e);
+                anyhow::Error::msg("Failed to insert invitation")
+            }
+        })?;
+        Ok(())
+    }
+
+    async fn update(&self, invitation: &Invitation) -> Result<(), anyhow::Error> {
+        tracing::info!("update_invitation : {:?}", invitation);
+        let mut conn = self
+            .db
+            .acquire()
+            .await
+            .context("Failed to acquire connection")?;
+        update_invitation(&mut conn, invitation).await
+    }
+}
+
+pub(super) async fn update_invitation(
+    conn: &mut MySqlConnection,
+    invitation: &Invitation,
+) -> Result<(), anyhow::Error> {
+    let invitation_data = InvitationData::from(invitation.clone());
+
+    // the pending check and the write are one statement, so a token can't be used twice
+    let result =
+        sqlx::query("UPDATE invitations SET status = ? WHERE id = ? AND status = 'Pending'")
+            .bind(invitation_data.status)
+            .bind(invitation_data.id.as_str())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to update invitation: {:?}", e);
+                anyhow::Error::msg("Failed to update invitation")
+            })?;
+
+    if result.rows_affected() == 0 {
+        let row = sqlx::query(
+            "SELECT id, circle_id, member_id, expires_at, status FROM invitations WHERE id = ?",
+        )
+        .bind(invitation_data.id.as_str())
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch invitation: {:?}", e);
+            anyhow::Error::msg("Failed to fetch invitation")
+        })?
+        .ok_or(DomainError::NotFound("Invitation"))?;
+        let stored = invitation_from_row(row)?;
+        return Err(DomainError::InvitationNotPending(stored.status).into());
+    }
+    Ok(())
+}
+
+fn invitation_from_row(row: MySqlRow) -> Result<Invitation, anyhow::Error> {
+    Invitation::try_from(InvitationData {
+        id: row.get::<String, _>("id"),
+        circle_id: row.get::<String, _>("circle_id"),
+        member_id: row.get::<String, _>("member_id"),
+        expires_at: row.get::<i64, _>("expires_at"),
+        status: row.get::<String, _>("status"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                member_id::MemberId,
+            },
+        },
+        interface::{
+            circle_repository_interface::CircleRepositoryInterface,
+            member_repository_interface::MemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+    use crate::mysql::{
+        circle_repository::CircleRepository,
+        member_repository::MemberRepository,
+        test_utils::{create_members, setup},
+    };
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(10)?,
+        )?;
+        create_members(&pool, &circle).await?;
+        CircleRepository::new(pool.clone()).create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+
+        let repository = InvitationRepository::new(pool.clone());
+        let now = SystemTime::now();
+        let valid_for = Duration::from_secs(60 * 60);
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            member.id.clone(),
+            now,
+            valid_for,
+        )?;
+        assert_eq!(
+            repository
+                .find_by_id(&invitation.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Invitation")
+        );
+        repository.create(&invitation).await?;
+        assert_eq!(repository.find_by_id(&invitation.id).await?, invitation);
+
+        // one pending invitation per member and circle
+        let again = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            member.id.clone(),
+            now,
+            valid_for,
+        )?;
+        assert_eq!(
+            repository
+                .create(&again)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Invitation")
+        );
+
+        let accepted = invitation.clone().accept(now)?;
+        repository.update(&accepted).await?;
+        assert_eq!(
+            repository
+                .update(&invitation.revoke()?)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::InvitationNotPending(InvitationStatus::Accepted)
+        );
+        repository.create(&again).await?;
+
+        assert_eq!(
+            repository.find_by_circle_id(&circle.id, None).await?,
+            vec![accepted, again.clone()]
+        );
+        assert_eq!(
+            repository
+                .find_by_circle_id(&circle.id, Some(InvitationStatus::Pending))
+                .await?,
+            vec![again]
+        );
+        Ok(())
+    }
+}
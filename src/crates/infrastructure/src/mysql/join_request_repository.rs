@@ -0,0 +1,257 @@
+use anyhow::Context;
+use domain::{
+    aggregate::{
+        join_request::JoinRequest,
+        value_object::{
+            circle_id::CircleId, join_request_id::JoinRequestId,
+            join_request_status::JoinRequestStatus,
+        },
+    },
+    error::DomainError,
+    interface::join_request_repository_interface::JoinRequestRepositoryInterface,
+};
+use sqlx::{
+    mysql::{MySqlConnection, MySqlRow},
+    MySql, QueryBuilder, Row,
+};
+
+use crate::db_schema::join_request_data::JoinRequestData;
+
+#[derive(Clone, Debug)]
+pub struct JoinRequestRepository {
+    db: sqlx::MySqlPool,
+}
+
+impl JoinRequestRepository {
+    pub fn new(db: sqlx::MySqlPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl JoinRequestRepositoryInterface for JoinRequestRepository {
+    async fn find_by_id(
+        &self,
+        join_request_id: &JoinRequestId,
+    ) -> Result<JoinRequest, anyhow::Error> {
+        tracing::info!("find_join_request_by_id: {:?}", join_request_id);
+
+        let row =
+            sqlx::query("SELECT id, circle_id, member_id, status FROM join_requests WHERE id = ?")
+                .bind(join_request_id.to_string())
+                .fetch_optional(&self.db)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch join request: {:?}", e);
+                    anyhow::Error::msg("Failed to fetch join request")
+                })?
+                .ok_or(DomainError::NotFound("Join request"))?;
+
+        join_request_from_row(row)
+    }
+
+    async fn find_by_circle_id(
+        &self,
+        circle_id: &CircleId,
+        status: Option<JoinRequestStatus>,
+    ) -> Result<Vec<JoinRequest>, anyhow::Error> {
+        tracing::info!("find_join_requests_by_circle_id: {:?}", circle_id);
+
+        let mut builder: QueryBuilder<MySql> = QueryBuilder::new(
+            "SELECT id, circle_id, member_id, status FROM join_requests WHERE circle_id = ",
+        );
+        builder.push_bind(circle_id.to_string());
+        if let Some(status) = status {
+            builder
+                .push(" AND status = ")
+                .push_bind(String::from(status));
+        }
+        builder.push(" ORDER BY id");
+
+        let rows = builder.build().fetch_all(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to fetch join requests: {:?}", e);
+            anyhow::Error::msg("Failed to fetch join requests")
+        })?;
+
+        rows.into_iter().map(join_request_from_row).collect()
+    }
+
+    async fn create(&self, join_request: &JoinRequest) -> Result<(), anyhow::Error> {
+        tracing::info!("create_join_request : {:?}", join_request);
+        let join_request_data = JoinRequestData::from(join_request.clone());
+
+        sqlx::query(
+            "INSERT INTO join_requests (id, circle_id, member_id, status) VALUES (?, ?, ?, ?)",
+        )
+        .bind(join_request_data.id)
+        .bind(join_request_data.circle_id)
+        .bind(join_request_data.member_id)
+        .bind(join_request_data.status)
+        .execute(&self.db)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                DomainError::Duplicate("Join request").into()
+            }
+            e => {
+                tracing::error!("Failed to insert join request: {:?}", e);
+                anyhow::Error::msg("Failed to insert join request")
+            }
+        })?;
+        Ok(())
+    }
+
+    async fn update(&self, join_request: &JoinRequest) -> Result<(), anyhow::Error> {
+        tracing::info!("update_join_request : {:?}", join_request);
+        let mut conn = self
+            .db
+            .acquire()
+            .await
+            .context("Failed to acquire connection")?;
+        update_join_request(&mut conn, join_request).await
+    }
+}
+
+pub(super) async fn update_join_request(
+    conn: &mut MySqlConnection,
+    join_request: &JoinRequest,
+) -> Result<(), anyhow::Error> {
+    let join_request_data = JoinRequestData::from(join_request.clone());
+
+    // the pending check and the write are one statement, so two decisions can't both land
+    let result =
+        sqlx::query("UPDATE join_requests SET status = ? WHERE id = ? AND status = 'Pending'")
+            .bind(join_request_data.status)
+            .bind(join_request_data.id.as_str())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to update join request: {:?}", e);
+                anyhow::Error::msg("Failed to update join request")
+            })?;
+
+    if result.rows_affected() == 0 {
+        let row =
+            sqlx::query("SELECT id, circle_id, member_id, status FROM join_requests WHERE id = ?")
+                .bind(join_request_data.id.as_str())
+                .fetch_optional(&mut *conn)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to fetch join request: {:?}", e);
+                    anyhow::Error::msg("Failed to fetch join request")
+                })?
+                .ok_or(DomainError::NotFound("Join request"))?;
+        let stored = join_request_from_row(row)?;
+        return Err(DomainError::JoinRequestNotPending(stored.status).into());
+    }
+    Ok(())
+}
+
+fn join_request_from_row(row: MySqlRow) -> Result<JoinRequest, anyhow::Error> {
+    JoinRequest::try_from(JoinRequestData {
+        id: row.get::<String, _>("id"),
+        circle_id: row.get::<String, _>("circle_id"),
+        member_id: row.get::<String, _>("member_id"),
+        status: row.get::<String, _>("status"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                member_id::MemberId,
+            },
+        },
+        interface::{
+            circle_repository_interface::CircleRepositoryInterface,
+            member_repository_interface::MemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+    use crate::mysql::{
+        circle_repository::CircleRepository,
+        member_repository::MemberRepository,
+        test_utils::{create_members, setup},
+    };
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("Music club")?,
+            Member::new(
+                MemberId::gen(),
+                "owner".to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ),
+            Capacity::try_from(10)?,
+        )?;
+        create_members(&pool, &circle).await?;
+        CircleRepository::new(pool.clone()).create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+
+        let repository = JoinRequestRepository::new(pool.clone());
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        assert_eq!(
+            repository
+                .find_by_id(&join_request.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Join request")
+        );
+        repository.create(&join_request).await?;
+        assert_eq!(repository.find_by_id(&join_request.id).await?, join_request);
+
+        // one pending request per member and circle
+        let again = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        assert_eq!(
+            repository
+                .create(&again)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Join request")
+        );
+
+        let rejected = join_request.clone().reject()?;
+        repository.update(&rejected).await?;
+        assert_eq!(
+            repository
+                .update(&join_request.approve()?)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::JoinRequestNotPending(JoinRequestStatus::Rejected)
+        );
+        repository.create(&again).await?;
+
+        assert_eq!(
+            repository.find_by_circle_id(&circle.id, None).await?,
+            vec![rejected, again.clone()]
+        );
+        assert_eq!(
+            repository
+                .find_by_circle_id(&circle.id, Some(JoinRequestStatus::Pending))
+                .await?,
+            vec![again]
+        );
+        Ok(())
+    }
+}
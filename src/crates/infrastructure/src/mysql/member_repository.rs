@@ -0,0 +1,202 @@
+use anyhow::Context;
+use domain::{
+    aggregate::{member::Member, value_object::member_id::MemberId},
+    error::DomainError,
+    interface::member_repository_interface::MemberRepositoryInterface,
+};
+use sqlx::{
+    mysql::{MySqlConnection, MySqlRow},
+    Row,
+};
+
+use crate::db_schema::member_data::MemberData;
+
+#[derive(Clone, Debug)]
+pub struct MemberRepository {
+    db: sqlx::MySqlPool,
+}
+
+impl MemberRepository {
+    pub fn new(db: sqlx::MySqlPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl MemberRepositoryInterface for MemberRepository {
+    async fn find_all(&self) -> Result<Vec<Member>, anyhow::Error> {
+        tracing::info!("find_all_members");
+
+        sqlx::query("SELECT id, name, age, grade, major FROM members ORDER BY id")
+            .fetch_all(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch members: {:?}", e);
+                anyhow::Error::msg("Failed to fetch members")
+            })?
+            .iter()
+            .map(member_from_row)
+            .collect()
+    }
+
+    async fn find_by_id(&self, member_id: &MemberId) -> Result<Member, anyhow::Error> {
+        tracing::info!("find_member_by_id: {:?}", member_id);
+
+        let row = sqlx::query("SELECT id, name, age, grade, major FROM members WHERE id = ?")
+            .bind(member_id.to_string())
+            .fetch_optional(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch member: {:?}", e);
+                anyhow::Error::msg("Failed to fetch member")
+            })?
+            .ok_or(DomainError::NotFound("Member"))?;
+
+        member_from_row(&row)
+    }
+
+    async fn create(&self, member: &Member) -> Result<(), anyhow::Error> {
+        tracing::info!("create_member : {:?}", member);
+        let member_data = MemberData::from(member.clone());
+
+        sqlx::query("INSERT INTO members (id, name, age, grade, major) VALUES (?, ?, ?, ?, ?)")
+            .bind(member_data.id)
+            .bind(member_data.name)
+            .bind(member_data.age)
+            .bind(member_data.grade)
+            .bind(member_data.major)
+            .execute(&self.db)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(e) if e.is_unique_violation() => {
+                    DomainError::Duplicate("Member").into()
+                }
+                e => {
+                    tracing::error!("Failed to insert member: {:?}", e);
+                    anyhow::Error::msg("Failed to insert member")
+                }
+            })?;
+        Ok(())
+    }
+
+    async fn update(&self, member: &Member) -> Result<(), anyhow::Error> {
+        tracing::info!("update_member : {:?}", member);
+        let mut conn = self
+            .db
+            .acquire()
+            .await
+            .context("Failed to acquire connection")?;
+        update_member(&mut conn, member).await
+    }
+}
+
+fn member_from_row(row: &MySqlRow) -> Result<Member, anyhow::Error> {
+    Member::try_from(MemberData {
+        id: row.get::<String, _>("id"),
+        name: row.get::<String, _>("name"),
+        age: row.get::<i16, _>("age"),
+        grade: row.get::<i16, _>("grade"),
+        major: row.get::<String, _>("major"),
+    })
+}
+
+pub(super) async fn update_member(
+    conn: &mut MySqlConnection,
+    member: &Member,
+) -> Result<(), anyhow::Error> {
+    let member_data = MemberData::from(member.clone());
+
+    // MySQL reports unchanged rows as unaffected, so existence is checked separately
+    let exists = sqlx::query("SELECT 1 FROM members WHERE id = ?")
+        .bind(member_data.id.as_str())
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check member existence: {:?}", e);
+            anyhow::Error::msg("Failed to check member existence")
+        })?
+        .is_some();
+    if !exists {
+        return Err(DomainError::NotFound("Member").into());
+    }
+
+    sqlx::query("UPDATE members SET name = ?, age = ?, grade = ?, major = ? WHERE id = ?")
+        .bind(member_data.name)
+        .bind(member_data.age)
+        .bind(member_data.grade)
+        .bind(member_data.major)
+        .bind(member_data.id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update member: {:?}", e);
+            anyhow::Error::msg("Failed to update member")
+        })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::value_object::{age::Age, grade::Grade, major::Major};
+
+    use super::*;
+    use crate::mysql::test_utils::setup;
+
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = MemberRepository::new(pool);
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        assert_eq!(
+            repository
+                .find_by_id(&member.id)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::NotFound("Member")
+        );
+        repository.create(&member).await?;
+        assert_eq!(repository.find_by_id(&member.id).await?, member);
+        assert_eq!(
+            repository
+                .create(&member)
+                .await
+                .unwrap_err()
+                .downcast::<DomainError>()?,
+            DomainError::Duplicate("Member")
+        );
+
+        let member = member.update(Some("renamed".to_string()), None, Some(Major::Music));
+        repository.update(&member).await?;
+        assert_eq!(repository.find_by_id(&member.id).await?, member);
+        // updating with unchanged values is not mistaken for a missing member
+        repository.update(&member).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_all() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        let repository = MemberRepository::new(pool);
+        let mut members = Vec::new();
+        for grade in [Grade::First, Grade::Third] {
+            let member = Member::new(
+                MemberId::gen(),
+                "member".to_string(),
+                Age::try_from(19)?,
+                grade,
+                Major::Art,
+            );
+            repository.create(&member).await?;
+            members.push(member);
+        }
+        assert_eq!(repository.find_all().await?, members);
+        Ok(())
+    }
+}
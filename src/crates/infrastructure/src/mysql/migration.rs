@@ -0,0 +1,300 @@
+use sqlx::{
+    migrate::{Migrate, Migrator},
+    MySqlPool,
+};
+
+/// Versioned schema migrations embedded from `infrastructure/migrations`.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+/// Applies every pending migration.
+pub async fn up(pool: &MySqlPool) -> anyhow::Result<()> {
+    MIGRATOR.run(pool).await?;
+    Ok(())
+}
+
+/// Reverts the most recently applied migration. Returns its version, or `None` if nothing was applied.
+pub async fn down(pool: &MySqlPool) -> anyhow::Result<Option<i64>> {
+    let mut applied = applied_versions(pool).await?;
+    let Some(latest) = applied.pop() else {
+        return Ok(None);
+    };
+    let target = applied.last().copied().unwrap_or(0);
+    MIGRATOR.undo(pool, target).await?;
+    Ok(Some(latest))
+}
+
+/// Lists every embedded migration and whether it has been applied.
+pub async fn status(pool: &MySqlPool) -> anyhow::Result<Vec<MigrationStatus>> {
+    let applied = applied_versions(pool).await?;
+    Ok(MIGRATOR
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+        .map(|migration| MigrationStatus {
+            version: migration.version,
+            description: migration.description.to_string(),
+            applied: applied.contains(&migration.version),
+        })
+        .collect())
+}
+
+async fn applied_versions(pool: &MySqlPool) -> anyhow::Result<Vec<i64>> {
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    let mut versions = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect::<Vec<_>>();
+    versions.sort();
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::interface::circle_repository_interface::CircleRepositoryInterface;
+
+    use super::*;
+    use crate::mysql::{circle_repository::CircleRepository, test_utils::setup};
+
+    #[tokio::test]
+    async fn test_up_down_status() -> anyhow::Result<()> {
+        // setup() already runs every migration
+        let (_container, pool) = setup().await;
+        let latest = MIGRATOR
+            .iter()
+            .map(|migration| migration.version)
+            .max()
+            .expect("at least one migration");
+        assert!(status(&pool).await?.iter().all(|status| status.applied));
+
+        assert_eq!(down(&pool).await?, Some(latest));
+        let statuses = status(&pool).await?;
+        assert!(statuses
+            .iter()
+            .all(|status| status.applied == (status.version != latest)));
+
+        up(&pool).await?;
+        assert!(status(&pool).await?.iter().all(|status| status.applied));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_memberships_migration() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        // back to the schema where each member row points at a single circle
+        while applied_versions(&pool).await?.contains(&7) {
+            down(&pool).await?;
+        }
+        sqlx::query(
+            "INSERT INTO circles (id, name, capacity, owner_id) VALUES ('c1', 'music', 10, 'm1')",
+        )
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO members (id, name, grade, circle_id) VALUES ('m1', 'owner', 3, 'c1'), ('m2', 'member', 1, 'c1'), ('m3', 'loner', 1, NULL)",
+        )
+        .execute(&pool)
+        .await?;
+
+        up(&pool).await?;
+        // the legacy ids are rewritten by a later migration, so match rows by name
+        let memberships: Vec<(String, String, String)> = sqlx::query_as(
+            "SELECT c.name, m.name, ms.role FROM memberships ms JOIN circles c ON c.id = ms.circle_id JOIN members m ON m.id = ms.member_id ORDER BY m.name",
+        )
+        .fetch_all(&pool)
+        .await?;
+        assert_eq!(
+            memberships,
+            [
+                (
+                    "music".to_string(),
+                    "member".to_string(),
+                    "member".to_string()
+                ),
+                (
+                    "music".to_string(),
+                    "owner".to_string(),
+                    "owner".to_string()
+                ),
+            ]
+        );
+        let members: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM members")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(members, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_status_migration() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        while applied_versions(&pool).await?.contains(&8) {
+            down(&pool).await?;
+        }
+        sqlx::query(
+            "INSERT INTO members (id, name, grade) VALUES ('m1', 'owner', 3), ('m2', 'member', 1), ('m3', 'member', 2)",
+        )
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO circles (id, name, capacity, owner_id) VALUES ('c1', 'music', 10, 'm1'), ('c2', 'art', 10, 'm1')",
+        )
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO memberships (circle_id, member_id, role) VALUES ('c1', 'm1', 'owner'), ('c1', 'm2', 'member'), ('c1', 'm3', 'member'), ('c2', 'm1', 'owner')",
+        )
+        .execute(&pool)
+        .await?;
+
+        up(&pool).await?;
+        let statuses: Vec<(String, String)> =
+            sqlx::query_as("SELECT name, status FROM circles ORDER BY name")
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(
+            statuses,
+            [
+                ("art".to_string(), "Recruiting".to_string()),
+                ("music".to_string(), "Active".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_clamp_legacy_circle_data_migration() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        while applied_versions(&pool).await?.contains(&13) {
+            down(&pool).await?;
+        }
+        sqlx::query(
+            "INSERT INTO members (id, name, grade, age) VALUES ('m1', 'young', 3, 10), ('m2', 'old', 1, 120), ('m3', 'member', 2, 20)",
+        )
+        .execute(&pool)
+        .await?;
+        let long_name = format!("  {}  ", "a".repeat(60));
+        sqlx::query(
+            "INSERT INTO circles (id, name, capacity, owner_id) VALUES ('c1', ?, 0, 'm1'), ('c2', '   ', 500, 'm1'), ('c3', 'art', 1, 'm1')",
+        )
+        .bind(long_name)
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO memberships (circle_id, member_id, role) VALUES ('c1', 'm1', 'owner'), ('c2', 'm1', 'owner'), ('c3', 'm1', 'owner'), ('c3', 'm2', 'member'), ('c3', 'm3', 'member')",
+        )
+        .execute(&pool)
+        .await?;
+
+        up(&pool).await?;
+        let circles: Vec<(String, i32)> =
+            sqlx::query_as("SELECT name, capacity FROM circles ORDER BY capacity")
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(
+            circles,
+            [
+                ("a".repeat(50), 1),
+                ("art".to_string(), 3),
+                ("circle c2".to_string(), 100),
+            ]
+        );
+        let ages: Vec<(String, i32)> =
+            sqlx::query_as("SELECT name, age FROM members ORDER BY name")
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(
+            ages,
+            [
+                ("member".to_string(), 20),
+                ("old".to_string(), 100),
+                ("young".to_string(), 15),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_legacy_ids_migration() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        while applied_versions(&pool).await?.contains(&14) {
+            down(&pool).await?;
+        }
+        // ids as they were generated before the switch to UUIDs
+        let owner_id = "aZ3kq9XbP0cD7eF2gH4iJ6kL8mN1oQ5rS7tU";
+        sqlx::query(
+            "INSERT INTO members (id, name, grade, age) VALUES (?, 'owner', 3, 21), ('m2', 'member', 2, 19)",
+        )
+        .bind(owner_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO circles (id, name, capacity, owner_id, successor_id) VALUES ('c1', 'music', 10, ?, 'm2')",
+        )
+        .bind(owner_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO memberships (circle_id, member_id, role) VALUES ('c1', ?, 'owner'), ('c1', 'm2', 'member')",
+        )
+        .bind(owner_id)
+        .execute(&pool)
+        .await?;
+
+        up(&pool).await?;
+        let circles = CircleRepository::new(pool.clone()).find_all().await?;
+        assert_eq!(circles.len(), 1);
+        let circle = &circles[0];
+        assert_eq!(circle.owner.name, "owner");
+        assert_eq!(circle.members.len(), 1);
+        assert_eq!(circle.successor_id, Some(circle.members[0].id.clone()));
+        let legacy: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM members WHERE id = ? OR id = 'm2'")
+                .bind(owner_id)
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(legacy, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lowercase_ids_migration() -> anyhow::Result<()> {
+        let (_container, pool) = setup().await;
+        while applied_versions(&pool).await?.contains(&15) {
+            down(&pool).await?;
+        }
+        let owner_id = "01890A5D-AC96-774B-BCCE-B302099A8057";
+        let circle_id = "01890A5D-AC96-774B-BCCE-B302099A8058";
+        sqlx::query("INSERT INTO members (id, name, grade, age) VALUES (?, 'owner', 3, 21)")
+            .bind(owner_id)
+            .execute(&pool)
+            .await?;
+        sqlx::query(
+            "INSERT INTO circles (id, name, capacity, owner_id) VALUES (?, 'music', 10, ?)",
+        )
+        .bind(circle_id)
+        .bind(owner_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query("INSERT INTO memberships (circle_id, member_id, role) VALUES (?, ?, 'owner')")
+            .bind(circle_id)
+            .bind(owner_id)
+            .execute(&pool)
+            .await?;
+
+        up(&pool).await?;
+        let circles = CircleRepository::new(pool.clone()).find_all().await?;
+        assert_eq!(circles.len(), 1);
+        assert_eq!(circles[0].id.to_string(), circle_id.to_lowercase());
+        assert_eq!(circles[0].owner.id.to_string(), owner_id.to_lowercase());
+        Ok(())
+    }
+}
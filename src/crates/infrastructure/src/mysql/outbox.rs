@@ -0,0 +1,402 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use domain::{
+    event::DomainEvent, interface::domain_event_publisher_interface::DomainEventPublisherInterface,
+};
+use sqlx::{mysql::MySqlConnection, MySql, QueryBuilder, Row};
+
+use crate::db_schema::event_data::EventData;
+
+/// Writes events to the outbox on the caller's connection, so they commit or roll back with the
+/// change that recorded them.
+pub(crate) async fn enqueue(
+    conn: &mut MySqlConnection,
+    events: &[DomainEvent],
+) -> Result<(), anyhow::Error> {
+    if events.is_empty() {
+        return Ok(());
+    }
+    let mut builder: QueryBuilder<MySql> =
+        QueryBuilder::new("INSERT INTO outbox (circle_id, event_type, payload) ");
+    let mut rows = Vec::with_capacity(events.len());
+    for event in events {
+        let circle_id = event.circle_id().to_string();
+        let event_data = EventData::from(event.clone());
+        let payload = serde_json::to_string(&event_data)?;
+        rows.push((circle_id, event_data.event_type(), payload));
+    }
+    builder.push_values(rows, |mut row, (circle_id, event_type, payload)| {
+        row.push_bind(circle_id)
+            .push_bind(event_type)
+            .push_bind(payload);
+    });
+    builder.build().execute(&mut *conn).await.map_err(|e| {
+        tracing::error!("Failed to insert outbox events: {:?}", e);
+        anyhow::Error::msg("Failed to insert outbox events")
+    })?;
+    Ok(())
+}
+
+/// Delivers events from the outbox to a sink, oldest first.
+#[derive(Clone)]
+pub struct OutboxDispatcher {
+    db: sqlx::MySqlPool,
+    sink: Arc<dyn DomainEventPublisherInterface + Send + Sync>,
+    batch_size: u32,
+    max_attempts: u32,
+}
+
+impl std::fmt::Debug for OutboxDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxDispatcher")
+            .field("db", &self.db)
+            .field("batch_size", &self.batch_size)
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OutboxDispatcher {
+    pub fn new(
+        db: sqlx::MySqlPool,
+        sink: Arc<dyn DomainEventPublisherInterface + Send + Sync>,
+        batch_size: u32,
+        max_attempts: u32,
+    ) -> Self {
+        Self {
+            db,
+            sink,
+            batch_size,
+            max_attempts,
+        }
+    }
+
+    /// Publishes the next batch of undelivered events and marks each one delivered.
+    /// The batch is claimed with `SKIP LOCKED`, so concurrent dispatchers never deliver the same
+    /// row twice. A failed delivery stops the batch so events keep their order, and is retried on
+    /// the next call; once a row has failed `max_attempts` times, or its payload can't be decoded
+    /// at all, it is dead-lettered and the rows behind it go on. Returns how many events were
+    /// delivered.
+    pub async fn dispatch(&self) -> Result<usize, anyhow::Error> {
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .context("Failed to begin outbox transaction")?;
+        let rows = sqlx::query(
+            "SELECT id, attempts, CAST(payload AS CHAR) AS payload FROM outbox WHERE delivered_at IS NULL AND dead_lettered_at IS NULL ORDER BY id LIMIT ? FOR UPDATE SKIP LOCKED",
+        )
+        .bind(self.batch_size)
+        .fetch_all(&mut *tx)
+        .await
+        .context("Failed to fetch outbox events")?;
+
+        let mut delivered = 0;
+        for row in rows {
+            let id: u64 = row.try_get("id")?;
+            let attempts: u32 = row.try_get::<u32, _>("attempts")? + 1;
+            let payload: String = row.try_get("payload")?;
+            let event = match serde_json::from_str::<EventData>(payload.as_str())
+                .map_err(anyhow::Error::from)
+                .and_then(DomainEvent::try_from)
+            {
+                Ok(event) => event,
+                Err(e) => {
+                    // a payload that can't be decoded won't decode on a retry either
+                    tracing::error!("Dead-lettering undecodable outbox event {id}: {e:?}");
+                    sqlx::query(
+                        "UPDATE outbox SET attempts = ?, last_error = ?, dead_lettered_at = CURRENT_TIMESTAMP(6) WHERE id = ?",
+                    )
+                    .bind(attempts)
+                    .bind(e.to_string())
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await
+                    .context("Failed to dead-letter outbox event")?;
+                    continue;
+                }
+            };
+            if let Err(e) = self.sink.publish(&[event]).await {
+                let dead_lettered = attempts >= self.max_attempts;
+                sqlx::query(
+                    "UPDATE outbox SET attempts = ?, last_error = ?, dead_lettered_at = IF(?, CURRENT_TIMESTAMP(6), NULL) WHERE id = ?",
+                )
+                .bind(attempts)
+                .bind(e.to_string())
+                .bind(dead_lettered)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .context("Failed to record outbox failure")?;
+                if dead_lettered {
+                    tracing::error!(
+                        "Dead-lettering outbox event {id} after {attempts} attempts: {e:?}"
+                    );
+                    continue;
+                }
+                tx.commit()
+                    .await
+                    .context("Failed to commit outbox transaction")?;
+                return Err(e.context(format!("Failed to deliver outbox event {id}")));
+            }
+            sqlx::query(
+                "UPDATE outbox SET attempts = ?, delivered_at = CURRENT_TIMESTAMP(6) WHERE id = ?",
+            )
+            .bind(attempts)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to mark outbox event delivered")?;
+            delivered += 1;
+        }
+        tx.commit()
+            .await
+            .context("Failed to commit outbox transaction")?;
+        Ok(delivered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                grade::Grade, major::Major, member_id::MemberId,
+            },
+        },
+        event::DomainEvent,
+        interface::{
+            circle_repository_interface::CircleRepositoryInterface,
+            domain_event_publisher_interface::MockDomainEventPublisherInterface,
+            member_repository_interface::MemberRepositoryInterface,
+        },
+    };
+
+    use super::OutboxDispatcher;
+    use crate::{
+        in_process::domain_event_publisher::DomainEventPublisher,
+        mysql::{
+            circle_repository::CircleRepository,
+            member_repository::MemberRepository,
+            test_utils::{self, create_members},
+        },
+    };
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "owner".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::Music,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(3)?,
+        )?)
+    }
+
+    async fn pending(pool: &sqlx::MySqlPool) -> anyhow::Result<i64> {
+        Ok(sqlx::query_scalar(
+            "SELECT COUNT(*) FROM outbox WHERE delivered_at IS NULL AND dead_lettered_at IS NULL",
+        )
+        .fetch_one(pool)
+        .await?)
+    }
+
+    #[tokio::test]
+    async fn test_dispatch() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        let member = Member::new(
+            MemberId::gen(),
+            "member".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Art,
+        );
+        MemberRepository::new(pool.clone()).create(&member).await?;
+        repository
+            .update(&circle.clone().add_member(member.clone())?)
+            .await?;
+        assert_eq!(pending(&pool).await?, 2);
+
+        let sink = DomainEventPublisher::new(16);
+        let mut receiver = sink.subscribe();
+        let dispatcher = OutboxDispatcher::new(pool.clone(), Arc::new(sink), 10, 3);
+        assert_eq!(dispatcher.dispatch().await?, 2);
+        assert!(matches!(
+            receiver.recv().await?,
+            DomainEvent::CircleCreated { circle_id, .. } if circle_id == circle.id
+        ));
+        assert_eq!(
+            receiver.recv().await?,
+            DomainEvent::MemberJoined {
+                circle_id: circle.id.clone(),
+                member_id: member.id,
+            }
+        );
+        assert_eq!(pending(&pool).await?, 0);
+        assert_eq!(dispatcher.dispatch().await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_retries_after_failure() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let mut failing_sink = MockDomainEventPublisherInterface::new();
+        failing_sink
+            .expect_publish()
+            .times(1)
+            .returning(|_| Err(anyhow::Error::msg("sink is down")));
+        let dispatcher = OutboxDispatcher::new(pool.clone(), Arc::new(failing_sink), 10, 3);
+        assert!(dispatcher.dispatch().await.is_err());
+        assert_eq!(pending(&pool).await?, 1);
+        let attempts: u32 = sqlx::query_scalar("SELECT attempts FROM outbox")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(attempts, 1);
+
+        let dispatcher =
+            OutboxDispatcher::new(pool.clone(), Arc::new(DomainEventPublisher::new(16)), 10, 3);
+        assert_eq!(dispatcher.dispatch().await?, 1);
+        assert_eq!(pending(&pool).await?, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_dead_letters_after_max_attempts() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        repository
+            .update(&circle.update(Some(CircleName::try_from("renamed")?), None)?)
+            .await?;
+
+        let mut failing_sink = MockDomainEventPublisherInterface::new();
+        let mut calls = 0;
+        failing_sink.expect_publish().times(3).returning(move |_| {
+            calls += 1;
+            // the first event keeps failing; the one behind it goes through once it is set aside
+            if calls <= 2 {
+                Err(anyhow::Error::msg("sink rejects this event"))
+            } else {
+                Ok(())
+            }
+        });
+        let dispatcher = OutboxDispatcher::new(pool.clone(), Arc::new(failing_sink), 10, 2);
+        assert!(dispatcher.dispatch().await.is_err());
+        assert_eq!(dispatcher.dispatch().await?, 1);
+        assert_eq!(pending(&pool).await?, 0);
+        let (attempts, last_error): (u32, String) = sqlx::query_as(
+            "SELECT attempts, last_error FROM outbox WHERE dead_lettered_at IS NOT NULL",
+        )
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(attempts, 2);
+        assert_eq!(last_error, "sink rejects this event");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_dead_letters_undecodable_payload() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup().await;
+        sqlx::query("INSERT INTO outbox (circle_id, event_type, payload) VALUES (?, ?, ?)")
+            .bind(CircleId::gen().to_string())
+            .bind("Unknown")
+            .bind("{}")
+            .execute(&pool)
+            .await?;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+
+        let sink = DomainEventPublisher::new(16);
+        let mut receiver = sink.subscribe();
+        let dispatcher = OutboxDispatcher::new(pool.clone(), Arc::new(sink), 10, 3);
+        assert_eq!(dispatcher.dispatch().await?, 1);
+        assert!(matches!(
+            receiver.recv().await?,
+            DomainEvent::CircleCreated { circle_id, .. } if circle_id == circle.id
+        ));
+        let dead_lettered: Vec<String> =
+            sqlx::query_scalar("SELECT event_type FROM outbox WHERE dead_lettered_at IS NOT NULL")
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(dead_lettered, vec!["Unknown"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_dispatchers_deliver_once() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup_with_max_connections(4).await;
+        let repository = CircleRepository::new(pool.clone());
+        let mut circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        for name in ["a", "b", "c", "d", "e"] {
+            circle = circle.update(Some(CircleName::try_from(name)?), None)?;
+            circle = repository.update(&circle).await?;
+        }
+
+        let sink = DomainEventPublisher::new(64);
+        let mut receiver = sink.subscribe();
+        let sink = Arc::new(sink);
+        let first = OutboxDispatcher::new(pool.clone(), sink.clone(), 2, 3);
+        let second = OutboxDispatcher::new(pool.clone(), sink, 2, 3);
+        let mut delivered = 0;
+        while pending(&pool).await? > 0 {
+            let (a, b) = tokio::join!(first.dispatch(), second.dispatch());
+            delivered += a? + b?;
+        }
+        assert_eq!(delivered, 6);
+        let mut received = 0;
+        while receiver.try_recv().is_ok() {
+            received += 1;
+        }
+        assert_eq!(received, 6);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rolled_back_write_leaves_no_event() -> anyhow::Result<()> {
+        let (_container, pool) = test_utils::setup().await;
+        let repository = CircleRepository::new(pool.clone());
+        let circle = build_circle()?;
+        create_members(&pool, &circle).await?;
+        repository.create(&circle).await?;
+        let stale = circle
+            .clone()
+            .update(Some(CircleName::try_from("renamed")?), None)?;
+        repository
+            .update(&circle.update(Some(CircleName::try_from("other")?), None)?)
+            .await?;
+
+        // the stale version conflicts, so its rename is rolled back along with its event
+        assert!(repository.update(&stale).await.is_err());
+        let types: Vec<String> = sqlx::query_scalar("SELECT event_type FROM outbox ORDER BY id")
+            .fetch_all(&pool)
+            .await?;
+        assert_eq!(types, vec!["CircleCreated", "CircleRenamed"]);
+        Ok(())
+    }
+}
@@ -1,50 +1,46 @@
 #![cfg(test)]
+use domain::{
+    aggregate::circle::Circle, interface::member_repository_interface::MemberRepositoryInterface,
+};
 use sqlx::{mysql::MySqlPoolOptions, MySqlPool};
 use testcontainers::{runners::AsyncRunner, ContainerAsync};
 use testcontainers_modules::mysql::Mysql;
 
+use crate::mysql::{member_repository::MemberRepository, migration::MIGRATOR};
+
 /// Starts a fresh, isolated MySQL container per call, so tests using this no longer need to
 /// coordinate with each other (no shared tables, no `#[serial]`/`#[file_serial]` needed).
 /// The returned container must be kept alive for as long as the pool is used — dropping it
 /// stops the container and breaks the connection.
 pub async fn setup() -> (ContainerAsync<Mysql>, MySqlPool) {
+    setup_with_max_connections(1).await
+}
+
+/// Like `setup`, with a pool large enough for tests that run queries concurrently.
+pub async fn setup_with_max_connections(
+    max_connections: u32,
+) -> (ContainerAsync<Mysql>, MySqlPool) {
     let container = Mysql::default().start().await.unwrap();
     let host = container.get_host().await.unwrap();
     let port = container.get_host_port_ipv4(3306).await.unwrap();
     let database_url = format!("mysql://root@{host}:{port}/test");
 
     let pool = MySqlPoolOptions::new()
-        .max_connections(1)
+        .max_connections(max_connections)
         .connect(&database_url)
         .await
         .unwrap();
 
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS circles (
-            id CHAR(36) NOT NULL PRIMARY KEY,
-            name VARCHAR(255) NOT NULL,
-            capacity INT NOT NULL,
-            owner_id CHAR(36) NOT NULL
-        );",
-    )
-    .execute(&pool)
-    .await
-    .unwrap();
-
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS members (
-            id CHAR(36) NOT NULL PRIMARY KEY,
-            name VARCHAR(255) NOT NULL,
-            grade INT NOT NULL,
-            circle_id CHAR(36),
-            age INT NOT NULL DEFAULT 20,
-            major VARCHAR(255) NOT NULL DEFAULT 'other',
-            FOREIGN KEY (circle_id) REFERENCES circles(id) ON DELETE CASCADE
-        );",
-    )
-    .execute(&pool)
-    .await
-    .unwrap();
+    MIGRATOR.run(&pool).await.unwrap();
 
     (container, pool)
 }
+
+/// Stores the circle's owner and members, which a circle can only reference once they exist.
+pub async fn create_members(pool: &MySqlPool, circle: &Circle) -> anyhow::Result<()> {
+    let member_repository = MemberRepository::new(pool.clone());
+    for member in std::iter::once(&circle.owner).chain(&circle.members) {
+        member_repository.create(member).await?;
+    }
+    Ok(())
+}
@@ -1,47 +1,261 @@
+use std::{sync::Arc, time::Duration};
+
 use axum::{
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router,
 };
+use tokio::sync::broadcast;
 
-use infrastructure::mysql::{
-    circle_duplicate_checker::CircleDuplicateChecker, circle_repository::CircleRepository,
+use domain::interface::{
+    circle_duplicate_checker_interface::CircleDuplicateCheckerInterface,
+    circle_repository_interface::CircleRepositoryInterface,
+    invitation_repository_interface::InvitationRepositoryInterface,
+    join_request_repository_interface::JoinRequestRepositoryInterface,
+    member_repository_interface::MemberRepositoryInterface,
+};
+use infrastructure::{
+    id_generator::IdGenerator, in_memory_db,
+    in_process::domain_event_publisher::DomainEventPublisher,
+    invitation_token::InvitationTokenSigner, mysql,
 };
 
 use crate::{
-    config::connect,
+    config::{
+        admin::admin_token, connect, invitation::invitation_token_signer, storage::StorageType,
+    },
     handler::{
-        handle_create_circle, handle_debug, handle_fetch_all, handle_fetch_circle,
-        handle_get_version, handle_update_circle,
+        handle_accept_invitation, handle_assign_role, handle_create_circle,
+        handle_create_invitation, handle_create_member, handle_debug, handle_decide_join_request,
+        handle_delete_circle, handle_disband_circle, handle_fetch_all, handle_fetch_circle,
+        handle_fetch_invitations, handle_fetch_join_requests, handle_fetch_member,
+        handle_fetch_member_circles, handle_get_version, handle_join_circle, handle_leave_circle,
+        handle_reactivate_circle, handle_revoke_invitation, handle_rollover_academic_year,
+        handle_submit_join_request, handle_transfer_ownership, handle_update_circle,
+        handle_update_member,
     },
 };
 
+/// Picks the repository implementations the handlers run against.
+pub(crate) trait Storage: Clone + Send + Sync + 'static {
+    type CircleRepository: CircleRepositoryInterface + Clone + Send + Sync + 'static;
+    type CircleDuplicateChecker: CircleDuplicateCheckerInterface + Clone + Send + Sync + 'static;
+    type MemberRepository: MemberRepositoryInterface + Clone + Send + Sync + 'static;
+    type JoinRequestRepository: JoinRequestRepositoryInterface + Clone + Send + Sync + 'static;
+    type InvitationRepository: InvitationRepositoryInterface + Clone + Send + Sync + 'static;
+}
+
+#[derive(Clone)]
+pub(crate) struct MySqlStorage;
+
+impl Storage for MySqlStorage {
+    type CircleRepository = mysql::circle_repository::CircleRepository;
+    type CircleDuplicateChecker = mysql::circle_duplicate_checker::CircleDuplicateChecker;
+    type MemberRepository = mysql::member_repository::MemberRepository;
+    type JoinRequestRepository = mysql::join_request_repository::JoinRequestRepository;
+    type InvitationRepository = mysql::invitation_repository::InvitationRepository;
+}
+
+#[derive(Clone)]
+pub(crate) struct InMemoryStorage;
+
+impl Storage for InMemoryStorage {
+    type CircleRepository = in_memory_db::circle_repository::CircleRepository;
+    type CircleDuplicateChecker = in_memory_db::circle_duplicate_checker::CircleDuplicateChecker;
+    type MemberRepository = in_memory_db::member_repository::MemberRepository;
+    type JoinRequestRepository = in_memory_db::join_request_repository::JoinRequestRepository;
+    type InvitationRepository = in_memory_db::invitation_repository::InvitationRepository;
+}
+
 #[derive(Clone)]
-pub(crate) struct AppState {
-    pub(crate) circle_repository: CircleRepository,
-    pub(crate) circle_duplicate_checker: CircleDuplicateChecker,
+pub(crate) struct AppState<S: Storage> {
+    pub(crate) circle_repository: S::CircleRepository,
+    pub(crate) circle_duplicate_checker: S::CircleDuplicateChecker,
+    pub(crate) member_repository: S::MemberRepository,
+    pub(crate) join_request_repository: S::JoinRequestRepository,
+    pub(crate) invitation_repository: S::InvitationRepository,
+    pub(crate) event_publisher: DomainEventPublisher,
+    pub(crate) id_generator: IdGenerator,
+    pub(crate) invitation_token_signer: InvitationTokenSigner,
+    /// Required as a bearer token by the `/admin` routes; `None` closes them.
+    pub(crate) admin_token: Option<Arc<str>>,
+}
+
+const EVENT_CAPACITY: usize = 1024;
+
+impl AppState<InMemoryStorage> {
+    pub(crate) fn in_memory(db: in_memory_db::db::Db) -> Self {
+        let event_publisher = DomainEventPublisher::new(EVENT_CAPACITY);
+        AppState {
+            circle_repository: in_memory_db::circle_repository::CircleRepository::new(db.clone())
+                .with_event_publisher(Arc::new(event_publisher.clone())),
+            circle_duplicate_checker:
+                in_memory_db::circle_duplicate_checker::CircleDuplicateChecker::new(db.clone()),
+            member_repository: in_memory_db::member_repository::MemberRepository::new(db.clone()),
+            join_request_repository:
+                in_memory_db::join_request_repository::JoinRequestRepository::new(db.clone()),
+            invitation_repository: in_memory_db::invitation_repository::InvitationRepository::new(
+                db,
+            ),
+            event_publisher,
+            id_generator: IdGenerator::new(),
+            invitation_token_signer: invitation_token_signer(),
+            admin_token: admin_token(),
+        }
+    }
+}
+
+impl AppState<MySqlStorage> {
+    pub(crate) fn mysql(pool: sqlx::MySqlPool) -> Self {
+        let event_publisher = DomainEventPublisher::new(EVENT_CAPACITY);
+        AppState {
+            // events go through the outbox instead, see `spawn_outbox_dispatcher`
+            circle_repository: mysql::circle_repository::CircleRepository::new(pool.clone()),
+            circle_duplicate_checker: mysql::circle_duplicate_checker::CircleDuplicateChecker::new(
+                pool.clone(),
+            ),
+            member_repository: mysql::member_repository::MemberRepository::new(pool.clone()),
+            join_request_repository: mysql::join_request_repository::JoinRequestRepository::new(
+                pool.clone(),
+            ),
+            invitation_repository: mysql::invitation_repository::InvitationRepository::new(pool),
+            event_publisher,
+            id_generator: IdGenerator::new(),
+            invitation_token_signer: invitation_token_signer(),
+            admin_token: admin_token(),
+        }
+    }
+}
+
+const OUTBOX_BATCH_SIZE: u32 = 100;
+const OUTBOX_MAX_ATTEMPTS: u32 = 5;
+const OUTBOX_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const OUTBOX_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Moves committed events from the MySQL outbox to the publisher, backing off while delivery fails.
+fn spawn_outbox_dispatcher(dispatcher: mysql::outbox::OutboxDispatcher) {
+    tokio::spawn(async move {
+        let mut backoff = OUTBOX_POLL_INTERVAL;
+        loop {
+            match dispatcher.dispatch().await {
+                // a full batch means more may be waiting, so go again straight away
+                Ok(delivered) if delivered == OUTBOX_BATCH_SIZE as usize => {
+                    backoff = OUTBOX_POLL_INTERVAL;
+                    continue;
+                }
+                Ok(_) => backoff = OUTBOX_POLL_INTERVAL,
+                Err(e) => {
+                    tracing::error!("Outbox dispatch failed, retrying in {:?}: {:?}", backoff, e);
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(OUTBOX_MAX_BACKOFF);
+                    continue;
+                }
+            }
+            tokio::time::sleep(OUTBOX_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Logs every domain event published by the repositories until the publisher goes away.
+fn spawn_event_logger(event_publisher: &DomainEventPublisher) {
+    let mut receiver = event_publisher.subscribe();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => tracing::info!("domain event: {:?}", event),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("domain event logger skipped {} events", skipped)
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
 }
 
-fn router() -> Router<AppState> {
+fn router<S: Storage>() -> Router<AppState<S>> {
     Router::new()
         .route("/version", get(handle_get_version))
-        .route("/circle/{id}", get(handle_fetch_circle))
-        .route("/circle", get(handle_fetch_all))
-        .route("/circle", post(handle_create_circle))
-        .route("/circle/{id}", put(handle_update_circle))
+        .route("/circle/{id}", get(handle_fetch_circle::<S>))
+        .route("/circle", get(handle_fetch_all::<S>))
+        .route("/circle", post(handle_create_circle::<S>))
+        .route("/circle/{id}", put(handle_update_circle::<S>))
+        .route("/circle/{id}", delete(handle_delete_circle::<S>))
+        .route("/circle/{id}/members", post(handle_join_circle::<S>))
+        .route(
+            "/circle/{id}/members/{member_id}",
+            delete(handle_leave_circle::<S>),
+        )
+        .route("/circle/{id}/owner", post(handle_transfer_ownership::<S>))
+        .route(
+            "/circle/{id}/members/{member_id}/role",
+            put(handle_assign_role::<S>),
+        )
+        .route("/circle/{id}/disband", post(handle_disband_circle::<S>))
+        .route(
+            "/circle/{id}/reactivate",
+            post(handle_reactivate_circle::<S>),
+        )
+        .route(
+            "/circle/{id}/join-requests",
+            post(handle_submit_join_request::<S>),
+        )
+        .route(
+            "/circle/{id}/join-requests",
+            get(handle_fetch_join_requests::<S>),
+        )
+        .route(
+            "/circle/{id}/join-requests/{join_request_id}",
+            post(handle_decide_join_request::<S>),
+        )
+        .route(
+            "/circle/{id}/invitations",
+            post(handle_create_invitation::<S>),
+        )
+        .route(
+            "/circle/{id}/invitations",
+            get(handle_fetch_invitations::<S>),
+        )
+        .route(
+            "/circle/{id}/invitations/{invitation_id}",
+            delete(handle_revoke_invitation::<S>),
+        )
+        .route("/invitations/accept", post(handle_accept_invitation::<S>))
+        .route("/member", post(handle_create_member::<S>))
+        .route("/member/{id}", get(handle_fetch_member::<S>))
+        .route("/member/{id}", put(handle_update_member::<S>))
+        .route(
+            "/member/{id}/circles",
+            get(handle_fetch_member_circles::<S>),
+        )
+        .route("/admin/rollover", post(handle_rollover_academic_year::<S>))
         .route("/debug", get(handle_debug))
 }
 
 pub async fn run() -> Result<(), ()> {
     tracing_subscriber::fmt().init();
 
-    let pool = connect::connect().await.expect("database should connect");
-    let state = AppState {
-        circle_repository: CircleRepository::new(pool.clone()),
-        circle_duplicate_checker: CircleDuplicateChecker::new(pool.clone()),
+    let app = match StorageType::from_env() {
+        StorageType::MySQL => {
+            let pool = connect::connect().await.expect("database should connect");
+            mysql::migration::up(&pool)
+                .await
+                .expect("migrations should apply");
+            let state = AppState::mysql(pool.clone());
+            spawn_event_logger(&state.event_publisher);
+            spawn_outbox_dispatcher(mysql::outbox::OutboxDispatcher::new(
+                pool,
+                Arc::new(state.event_publisher.clone()),
+                OUTBOX_BATCH_SIZE,
+                OUTBOX_MAX_ATTEMPTS,
+            ));
+            router().with_state(state)
+        }
+        StorageType::InMemory => {
+            let state = AppState::in_memory(in_memory_db::db::Db::new());
+            spawn_event_logger(&state.event_publisher);
+            router().with_state(state)
+        }
     };
 
-    let app = router().with_state(state);
-
     let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
         .await
         .unwrap();
@@ -52,35 +266,47 @@ pub async fn run() -> Result<(), ()> {
 
 #[cfg(test)]
 mod tests {
-    use axum::http::{header::CONTENT_TYPE, StatusCode};
+    use axum::http::{
+        header::{AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MATCH},
+        StatusCode,
+    };
     use domain::{
         aggregate::{
             circle::Circle,
             member::Member,
-            value_object::{circle_id::CircleId, grade::Grade, major::Major, member_id::MemberId},
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                circle_status::CircleStatus, grade::Grade, major::Major, member_id::MemberId,
+            },
         },
+        event::DomainEvent,
         interface::circle_repository_interface::CircleRepositoryInterface,
     };
+    use infrastructure::in_memory_db::db::Db;
     use std::str::FromStr;
     use tower::ServiceExt;
+    use usecase::decide_join_request::JoinRequestDecision;
 
+    use crate::error::ProblemDetails;
     use crate::handler::{
-        CreateCircleRequestBody, CreateCircleResponseBody, UpdateCircleRequestBody,
+        AcceptInvitationRequestBody, AcceptInvitationResponseBody, AssignRoleRequestBody,
+        CreateCircleRequestBody, CreateCircleResponseBody, CreateInvitationRequestBody,
+        CreateInvitationResponseBody, CreateMemberRequestBody, CreateMemberResponseBody,
+        DecideJoinRequestRequestBody, DecideJoinRequestResponseBody, DisbandCircleResponseBody,
+        FetchAllCircleResponseBody, FetchInvitationsResponseBody, FetchJoinRequestsResponseBody,
+        FetchMemberCirclesResponseBody, FetchMemberResponseBody, FetcheCircleResponseBody,
+        JoinCircleRequestBody, JoinCircleResponseBody, ReactivateCircleResponseBody,
+        RevokeInvitationResponseBody, RolloverAcademicYearRequestBody,
+        RolloverAcademicYearResponseBody, SubmitJoinRequestRequestBody,
+        SubmitJoinRequestResponseBody, TransferOwnershipRequestBody, TransferOwnershipResponseBody,
+        UpdateCircleRequestBody, UpdateMemberRequestBody,
     };
 
     use super::*;
 
-    // FIXME: ignore test because it requires a running database
     #[tokio::test]
-    #[ignore]
     async fn test_version() -> anyhow::Result<()> {
-        let pool = connect::connect_test()
-            .await
-            .expect("database should connect");
-        let state = AppState {
-            circle_repository: CircleRepository::new(pool.clone()),
-            circle_duplicate_checker: CircleDuplicateChecker::new(pool.clone()),
-        };
+        let state = AppState::in_memory(Db::new());
         let app = router().with_state(state);
         let response = app
             .oneshot(
@@ -101,16 +327,10 @@ mod tests {
     }
 
     #[tokio::test]
-    #[ignore]
     async fn test_create_circle() -> anyhow::Result<()> {
-        let pool = connect::connect_test()
-            .await
-            .expect("database should connect");
-        let state = AppState {
-            circle_repository: CircleRepository::new(pool.clone()),
-            circle_duplicate_checker: CircleDuplicateChecker::new(pool.clone()),
-        };
+        let state = AppState::in_memory(Db::new());
         let app = router().with_state(state.clone());
+        let owner_id = create_member(&app, "owner1", 3).await?;
         let response = app
             .oneshot(
                 axum::http::Request::builder()
@@ -121,10 +341,7 @@ mod tests {
                         &CreateCircleRequestBody {
                             circle_name: "circle_name1".to_string(),
                             capacity: 10,
-                            owner_name: "owner1".to_string(),
-                            owner_age: 21,
-                            owner_grade: 3,
-                            owner_major: "Music".to_string(),
+                            owner_id: owner_id.clone(),
                         },
                     )?))?,
             )
@@ -133,6 +350,7 @@ mod tests {
         let response_body = serde_json::from_slice::<'_, CreateCircleResponseBody>(
             &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
         )?;
+        assert_eq!(response_body.owner_id, owner_id);
 
         let created = state
             .circle_repository
@@ -140,33 +358,106 @@ mod tests {
             .await?;
         let circle = Circle::reconstruct(
             CircleId::from_str(&response_body.circle_id)?,
-            "circle_name1".to_string(),
+            CircleName::try_from("circle_name1")?,
             Member::reconstruct(
                 MemberId::from_str(&response_body.owner_id)?,
                 "owner1".to_string(),
-                21,
+                Age::try_from(21)?,
                 Grade::try_from(3)?,
                 Major::Music,
             ),
-            10,
+            Capacity::try_from(10)?,
             vec![],
+            vec![],
+            std::collections::BTreeMap::new(),
+            None,
+            CircleStatus::Recruiting,
+            1,
         );
         assert_eq!(created, circle);
         Ok(())
     }
 
     #[tokio::test]
-    #[ignore]
+    async fn test_create_circle_duplicate_name() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        build_circle(&app).await?;
+        let owner_id = create_member(&app, "Ringo Starr", 3).await?;
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/circle")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &CreateCircleRequestBody {
+                            circle_name: "Music club".to_string(),
+                            capacity: 5,
+                            owner_id,
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_circle_invalid_input() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        let owner_id = create_member(&app, "Ringo Starr", 3).await?;
+        let unknown_owner_id = MemberId::gen().to_string();
+
+        for (circle_name, capacity, owner_id, status) in [
+            (" ", 5, &owner_id, StatusCode::UNPROCESSABLE_ENTITY),
+            (
+                "Music club",
+                -1,
+                &owner_id,
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            ("Music club", 5, &unknown_owner_id, StatusCode::NOT_FOUND),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    axum::http::Request::builder()
+                        .method("POST")
+                        .uri("/circle")
+                        .header(CONTENT_TYPE, "application/json")
+                        .body(axum::body::Body::new(serde_json::to_string(
+                            &CreateCircleRequestBody {
+                                circle_name: circle_name.to_string(),
+                                capacity,
+                                owner_id: owner_id.clone(),
+                            },
+                        )?))?,
+                )
+                .await?;
+            assert_eq!(response.status(), status);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
     async fn test_fetch_circle() -> anyhow::Result<()> {
-        let pool = connect::connect_test()
-            .await
-            .expect("database should connect");
-        let state = AppState {
-            circle_repository: CircleRepository::new(pool.clone()),
-            circle_duplicate_checker: CircleDuplicateChecker::new(pool.clone()),
-        };
+        let state = AppState::in_memory(Db::new());
         let app = router().with_state(state);
-        let unexist_circle_id = 0;
+        let malformed_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri("/circle/0")
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(malformed_response.status(), StatusCode::BAD_REQUEST);
+
+        let unexist_circle_id = CircleId::gen();
         let response = app
             .clone()
             .oneshot(
@@ -176,13 +467,16 @@ mod tests {
                     .body(axum::body::Body::empty())?,
             )
             .await?;
-        assert_eq!(response.status(), StatusCode::OK);
-        let response_body = String::from_utf8(
-            axum::body::to_bytes(response.into_body(), usize::MAX)
-                .await?
-                .to_vec(),
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        let response_body = serde_json::from_slice::<ProblemDetails>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
         )?;
-        assert_eq!(response_body, "Circle not found");
+        assert_eq!(response_body.status, 404);
+        assert_eq!(response_body.detail, "Circle not found");
 
         let (circle_id, owner_id) = build_circle(&app).await?;
 
@@ -195,6 +489,7 @@ mod tests {
             )
             .await?;
         assert_eq!(fetched_response.status(), StatusCode::OK);
+        assert_eq!(fetched_response.headers().get(ETAG).unwrap(), "\"1\"");
         let fetched_response_body = String::from_utf8(
             axum::body::to_bytes(fetched_response.into_body(), usize::MAX)
                 .await?
@@ -203,31 +498,85 @@ mod tests {
         assert_eq!(
             fetched_response_body,
             format!(
-                "{{\"circle_id\":{},\"circle_name\":\"Music club\",\"capacity\":10,\"owner\":{{\"id\":{},\"name\":\"John Lennon\",\"age\":21,\"grade\":3,\"major\":\"Music\"}},\"members\":[]}}",
-                circle_id,owner_id
+                "{{\"circle_id\":\"{}\",\"circle_name\":\"Music club\",\"capacity\":10,\"owner\":{{\"id\":\"{}\",\"name\":\"John Lennon\",\"age\":21,\"grade\":3,\"major\":\"Music\",\"role\":\"owner\"}},\"members\":[],\"waitlist\":[],\"status\":\"Recruiting\"}}",
+                circle_id, owner_id
             )
         );
         Ok(())
     }
 
     #[tokio::test]
-    #[ignore]
+    async fn test_fetch_all_circle() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        let (first_id, _) = build_named_circle(&app, "Music club").await?;
+        let (second_id, _) = build_named_circle(&app, "Soccer club").await?;
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri("/circle?limit=1&sort=name")
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let first_page = serde_json::from_slice::<FetchAllCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(first_page.circles.len(), 1);
+        let next_cursor = first_page.next_cursor.expect("next page should exist");
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/circle?limit=1&sort=name&cursor={}", next_cursor))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let second_page = serde_json::from_slice::<FetchAllCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(second_page.circles.len(), 1);
+        assert_eq!(second_page.next_cursor, None);
+
+        let mut ids = vec![
+            first_page.circles[0].circle_id.clone(),
+            second_page.circles[0].circle_id.clone(),
+        ];
+        ids.sort();
+        let mut expected = vec![first_id, second_id];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri("/circle?sort=age")
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
     async fn test_update_circle() -> anyhow::Result<()> {
-        let pool = connect::connect_test()
-            .await
-            .expect("database should connect");
-        let state = AppState {
-            circle_repository: CircleRepository::new(pool.clone()),
-            circle_duplicate_checker: CircleDuplicateChecker::new(pool.clone()),
-        };
+        let state = AppState::in_memory(Db::new());
         let app = router().with_state(state.clone());
-        let (circle_id, _) = build_circle(&app).await?;
+        let (circle_id, owner_id) = build_circle(&app).await?;
         let update_response = app
             .oneshot(
                 axum::http::Request::builder()
                     .method("PUT")
                     .uri(format!("/circle/{}", circle_id))
                     .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
                     .body(axum::body::Body::new(serde_json::to_string(
                         &UpdateCircleRequestBody {
                             circle_name: Some("Football club".to_string()),
@@ -242,28 +591,1073 @@ mod tests {
             .circle_repository
             .find_by_id(&CircleId::from_str(&circle_id)?)
             .await?;
-        assert_eq!(updated_circle.name, "Football club");
-        assert_eq!(updated_circle.capacity, 20);
+        assert_eq!(updated_circle.name.as_str(), "Football club");
+        assert_eq!(i16::from(updated_circle.capacity), 20);
 
         Ok(())
     }
 
-    async fn build_circle(app: &Router) -> anyhow::Result<(String, String)> {
-        let create_response = app
+    #[tokio::test]
+    async fn test_update_circle_if_match() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let update =
+            |if_match: &'static str, capacity: i16| -> anyhow::Result<axum::http::Request<_>> {
+                Ok(axum::http::Request::builder()
+                    .method("PUT")
+                    .uri(format!("/circle/{}", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .header(IF_MATCH, if_match)
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &UpdateCircleRequestBody {
+                            circle_name: None,
+                            capacity: Some(capacity),
+                        },
+                    )?))?)
+            };
+
+        let response = app.clone().oneshot(update("\"1\"", 20)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(ETAG).unwrap(), "\"2\"");
+
+        // the second officer still holds the ETag from before the first update
+        let response = app.clone().oneshot(update("\"1\"", 20)?).await?;
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        // resending the current capacity writes nothing, so the version stays
+        let response = app.clone().oneshot(update("*", 20)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(ETAG).unwrap(), "\"2\"");
+
+        let response = app.oneshot(update("*", 30)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get(ETAG).unwrap(), "\"3\"");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_roles() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let member_id = join_circle(&app, &circle_id, &owner_id, 2).await?;
+        let assign = |acting_member_id: &str| -> anyhow::Result<axum::http::Request<_>> {
+            Ok(axum::http::Request::builder()
+                .method("PUT")
+                .uri(format!("/circle/{}/members/{}/role", circle_id, member_id))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", acting_member_id)
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &AssignRoleRequestBody {
+                        role: "treasurer".to_string(),
+                    },
+                )?))?)
+        };
+        let update = |acting_member_id: &str,
+                      body: UpdateCircleRequestBody|
+         -> anyhow::Result<axum::http::Request<_>> {
+            Ok(axum::http::Request::builder()
+                .method("PUT")
+                .uri(format!("/circle/{}", circle_id))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", acting_member_id)
+                .body(axum::body::Body::new(serde_json::to_string(&body)?))?)
+        };
+
+        // only the owner hands out roles
+        let response = app.clone().oneshot(assign(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = app.clone().oneshot(assign(&owner_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // the treasurer may change the capacity, but not rename the circle
+        let response = app
+            .clone()
+            .oneshot(update(
+                &member_id,
+                UpdateCircleRequestBody {
+                    circle_name: None,
+                    capacity: Some(20),
+                },
+            )?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app
+            .clone()
+            .oneshot(update(
+                &member_id,
+                UpdateCircleRequestBody {
+                    circle_name: Some("Football club".to_string()),
+                    capacity: None,
+                },
+            )?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response_body = serde_json::from_slice::<ProblemDetails>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.detail, "Not allowed to rename the circle");
+
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PUT")
+                    .uri(format!("/circle/{}", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &UpdateCircleRequestBody {
+                            circle_name: None,
+                            capacity: Some(20),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/circle/{}", circle_id))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        let response_body = serde_json::from_slice::<FetcheCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.members[0].role.as_deref(), Some("treasurer"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_circle() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+
+        let delete_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/circle/{}", circle_id))
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(delete_response.status(), StatusCode::NO_CONTENT);
+        assert!(state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await
+            .is_err());
+
+        let not_found_response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/circle/{}", circle_id))
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(not_found_response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_and_leave_circle() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let mut events = state.event_publisher.subscribe();
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        assert!(matches!(
+            events.recv().await?,
+            DomainEvent::CircleCreated { .. }
+        ));
+        let member_id = create_member(&app, "Paul McCartney", 1).await?;
+
+        // members go through a join request; only someone who may approve adds them directly
+        let self_join_response = app
             .clone()
             .oneshot(
                 axum::http::Request::builder()
                     .method("POST")
-                    .uri("/circle")
+                    .uri(format!("/circle/{}/members", circle_id))
                     .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", member_id.as_str())
                     .body(axum::body::Body::new(serde_json::to_string(
-                        &CreateCircleRequestBody {
-                            circle_name: "Music club".to_string(),
+                        &JoinCircleRequestBody {
+                            member_id: member_id.clone(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(self_join_response.status(), StatusCode::FORBIDDEN);
+
+        let join_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody {
+                            member_id: member_id.clone(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(join_response.status(), StatusCode::OK);
+        let join_response_body = serde_json::from_slice::<JoinCircleResponseBody>(
+            &axum::body::to_bytes(join_response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(join_response_body.member_id, member_id);
+
+        let joined_circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert!(joined_circle
+            .member(&MemberId::from_str(&join_response_body.member_id)?)
+            .is_some());
+
+        let rejoin_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody {
+                            member_id: member_id.clone(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(rejoin_response.status(), StatusCode::CONFLICT);
+
+        let leave_response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!(
+                        "/circle/{}/members/{}",
+                        circle_id, join_response_body.member_id
+                    ))
+                    .header("X-Member-Id", join_response_body.member_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(leave_response.status(), StatusCode::OK);
+
+        let left_circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert!(left_circle
+            .member(&MemberId::from_str(&join_response_body.member_id)?)
+            .is_none());
+
+        let member_id = MemberId::from_str(&join_response_body.member_id)?;
+        assert_eq!(
+            events.recv().await?,
+            DomainEvent::MemberJoined {
+                circle_id: CircleId::from_str(&circle_id)?,
+                member_id: member_id.clone(),
+            }
+        );
+        assert_eq!(
+            events.recv().await?,
+            DomainEvent::MemberLeft {
+                circle_id: CircleId::from_str(&circle_id)?,
+                member_id,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_full_circle_waitlists() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let update_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PUT")
+                    .uri(format!("/circle/{}", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &UpdateCircleRequestBody {
+                            circle_name: None,
+                            capacity: Some(2),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(update_response.status(), StatusCode::OK);
+        let seated_id = join_circle(&app, &circle_id, &owner_id, 1).await?;
+
+        let waiting_id = create_member(&app, "George Harrison", 2).await?;
+        let join_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody {
+                            member_id: waiting_id.clone(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(join_response.status(), StatusCode::OK);
+        let join_response_body = serde_json::from_slice::<JoinCircleResponseBody>(
+            &axum::body::to_bytes(join_response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(join_response_body.waitlist_position, Some(1));
+
+        let fetched_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/circle/{}", circle_id))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        let fetched_response_body = serde_json::from_slice::<FetcheCircleResponseBody>(
+            &axum::body::to_bytes(fetched_response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(fetched_response_body.waitlist.len(), 1);
+        assert_eq!(fetched_response_body.waitlist[0].position, 1);
+        assert_eq!(fetched_response_body.waitlist[0].member.id, waiting_id);
+
+        // the seat freed by leaving goes to the first in line
+        let leave_response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/circle/{}/members/{}", circle_id, seated_id))
+                    .header("X-Member-Id", seated_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(leave_response.status(), StatusCode::OK);
+
+        let circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert!(circle.member(&MemberId::from_str(&waiting_id)?).is_some());
+        assert!(circle.waitlist.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_fetch_and_update_member() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+
+        let invalid_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/member")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &CreateMemberRequestBody {
+                            name: "George Harrison".to_string(),
+                            age: -21,
+                            grade: 2,
+                            major: "Music".to_string(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(invalid_response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let member_id = create_member(&app, "George Harrison", 2).await?;
+        let update_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PUT")
+                    .uri(format!("/member/{}", member_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &UpdateMemberRequestBody {
+                            name: Some("George".to_string()),
+                            age: None,
+                            major: Some("Art".to_string()),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(update_response.status(), StatusCode::OK);
+
+        let fetched_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/member/{}", member_id))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(fetched_response.status(), StatusCode::OK);
+        let fetched = serde_json::from_slice::<FetchMemberResponseBody>(
+            &axum::body::to_bytes(fetched_response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(fetched.id, member_id);
+        assert_eq!(fetched.name, "George");
+        assert_eq!(fetched.age, 21);
+        assert_eq!(fetched.grade, 2);
+        assert_eq!(fetched.major, "Art");
+
+        let not_found_response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/member/{}", MemberId::gen()))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(not_found_response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_member_circles() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state);
+        let (music_id, owner_id) = build_circle(&app).await?;
+        let (art_id, art_owner_id) = build_named_circle(&app, "Art club").await?;
+        let join_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", art_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", art_owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody {
+                            member_id: owner_id.clone(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(join_response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("GET")
+                    .uri(format!("/member/{}/circles", owner_id))
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<FetchMemberCirclesResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        let mut roles: Vec<(String, String)> = response_body
+            .circles
+            .into_iter()
+            .map(|circle| (circle.circle_id, circle.role))
+            .collect();
+        roles.sort();
+        let mut expected = vec![
+            (music_id, "owner".to_string()),
+            (art_id, "member".to_string()),
+        ];
+        expected.sort();
+        assert_eq!(roles, expected);
+        Ok(())
+    }
+
+    const ADMIN_TOKEN: &str = "admin-secret";
+
+    fn admin_state() -> AppState<InMemoryStorage> {
+        AppState {
+            admin_token: Some(Arc::from(ADMIN_TOKEN)),
+            ..AppState::in_memory(Db::new())
+        }
+    }
+
+    fn rollover(
+        token: Option<&str>,
+        academic_year: i16,
+    ) -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+        let mut builder = axum::http::Request::builder()
+            .method("POST")
+            .uri("/admin/rollover")
+            .header(CONTENT_TYPE, "application/json");
+        if let Some(token) = token {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        Ok(builder.body(axum::body::Body::new(serde_json::to_string(
+            &RolloverAcademicYearRequestBody { academic_year },
+        )?))?)
+    }
+
+    #[tokio::test]
+    async fn test_rollover_academic_year() -> anyhow::Result<()> {
+        let state = admin_state();
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let loner_id = create_member(&app, "Ringo Starr", 1).await?;
+
+        let response = app
+            .clone()
+            .oneshot(rollover(Some(ADMIN_TOKEN), 2025)?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<RolloverAcademicYearResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        // nobody graduated, so no circle changed
+        assert!(response_body.circles.is_empty());
+        let promoted = response_body
+            .promoted
+            .iter()
+            .map(|member| (member.member_id.as_str(), member.to_grade))
+            .collect::<Vec<_>>();
+        assert!(promoted.contains(&(owner_id.as_str(), 4)));
+        // members outside any circle move up too
+        assert!(promoted.contains(&(loner_id.as_str(), 2)));
+        let loner = state
+            .member_repository
+            .find_by_id(&MemberId::from_str(&loner_id)?)
+            .await?;
+        assert_eq!(loner.grade, Grade::Second);
+
+        // a year is rolled over once, and an earlier one can't be rolled over after it
+        for academic_year in [2025, 2024] {
+            let response = app
+                .clone()
+                .oneshot(rollover(Some(ADMIN_TOKEN), academic_year)?)
+                .await?;
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+        }
+        let loner = state
+            .member_repository
+            .find_by_id(&MemberId::from_str(&loner_id)?)
+            .await?;
+        assert_eq!(loner.grade, Grade::Second);
+
+        // the owner graduates without a successor, so the circle goes dormant
+        let response = app.oneshot(rollover(Some(ADMIN_TOKEN), 2026)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<RolloverAcademicYearResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.circles.len(), 1);
+        assert_eq!(response_body.circles[0].circle_id, circle_id);
+        assert_eq!(response_body.circles[0].status, "Dormant");
+        let rolled_over = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert_eq!(rolled_over.owner.grade, Grade::Fourth);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover_requires_admin_token() -> anyhow::Result<()> {
+        let app = router().with_state(admin_state());
+        for token in [None, Some("wrong"), Some("admin-secre")] {
+            let response = app.clone().oneshot(rollover(token, 2025)?).await?;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // without a configured token the route stays closed
+        let app = router().with_state(AppState {
+            admin_token: None,
+            ..AppState::in_memory(Db::new())
+        });
+        let response = app.oneshot(rollover(Some(ADMIN_TOKEN), 2025)?).await?;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let member_id = join_circle(&app, &circle_id, &owner_id, 3).await?;
+        let transfer = |acting_member_id: &str| -> anyhow::Result<axum::http::Request<_>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("/circle/{}/owner", circle_id))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", acting_member_id)
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &TransferOwnershipRequestBody {
+                        member_id: member_id.clone(),
+                        at_rollover: false,
+                    },
+                )?))?)
+        };
+
+        // only the owner hands the circle over
+        let response = app.clone().oneshot(transfer(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = app.oneshot(transfer(&owner_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<TransferOwnershipResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.owner_id, member_id);
+
+        let circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert_eq!(circle.owner.id, MemberId::from_str(&member_id)?);
+        assert!(circle.member(&MemberId::from_str(&owner_id)?).is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disband_and_reactivate_circle() -> anyhow::Result<()> {
+        let app = router().with_state(AppState::in_memory(Db::new()));
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        join_circle(&app, &circle_id, &owner_id, 1).await?;
+        join_circle(&app, &circle_id, &owner_id, 2).await?;
+
+        let circles_with_status = |status: &str| {
+            axum::http::Request::builder()
+                .method("GET")
+                .uri(format!("/circle?status={status}"))
+                .body(axum::body::Body::empty())
+        };
+        let response = app.clone().oneshot(circles_with_status("Active")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<FetchAllCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.circles.len(), 1);
+        assert_eq!(response_body.circles[0].status, "Active");
+        let response = app.clone().oneshot(circles_with_status("Closed")?).await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let disband = || {
+            axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("/circle/{}/disband", circle_id))
+                .header("X-Member-Id", owner_id.as_str())
+                .body(axum::body::Body::empty())
+        };
+        let response = app.clone().oneshot(disband()?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<DisbandCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.status, "Disbanded");
+        let response = app.clone().oneshot(disband()?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        // a disbanded circle takes no new members
+        let member_id = create_member(&app, "Ringo Starr", 1).await?;
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody { member_id },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/reactivate", circle_id))
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<ReactivateCircleResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.status, "Active");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_request() -> anyhow::Result<()> {
+        let state = AppState::in_memory(Db::new());
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let member_id = create_member(&app, "Ringo Starr", 1).await?;
+
+        let submit = |member_id: &str| -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("/circle/{}/join-requests", circle_id))
+                .header(CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &SubmitJoinRequestRequestBody {
+                        member_id: member_id.to_string(),
+                    },
+                )?))?)
+        };
+        let response = app.clone().oneshot(submit(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<SubmitJoinRequestResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.status, "Pending");
+        let join_request_id = response_body.join_request_id;
+        // only one pending request per member
+        let response = app.clone().oneshot(submit(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let list = |acting_member_id: &str| -> anyhow::Result<axum::http::Request<_>> {
+            Ok(axum::http::Request::builder()
+                .method("GET")
+                .uri(format!(
+                    "/circle/{}/join-requests?status=Pending",
+                    circle_id
+                ))
+                .header("X-Member-Id", acting_member_id)
+                .body(axum::body::Body::empty())?)
+        };
+        // the requester isn't a member yet, so they can't see the circle's requests
+        let response = app.clone().oneshot(list(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = app.clone().oneshot(list(&owner_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<FetchJoinRequestsResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.join_requests.len(), 1);
+        assert_eq!(
+            response_body.join_requests[0].join_request_id,
+            join_request_id
+        );
+        assert_eq!(response_body.join_requests[0].member_id, member_id);
+
+        let decide = |decision: &str| -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri(format!(
+                    "/circle/{}/join-requests/{}",
+                    circle_id, join_request_id
+                ))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", owner_id.as_str())
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &serde_json::json!({ "decision": decision }),
+                )?))?)
+        };
+        let response = app.clone().oneshot(decide("maybe")?).await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app.clone().oneshot(decide("approve")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<DecideJoinRequestResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.status, "Approved");
+        let circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert!(circle.member(&MemberId::from_str(&member_id)?).is_some());
+        // a request is decided once
+        let response = app.clone().oneshot(decide("reject")?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        // 4th graders can ask, but approving them still goes through the circle's rules
+        let senior_id = create_member(&app, "George Harrison", 4).await?;
+        let response = app.clone().oneshot(submit(&senior_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<SubmitJoinRequestResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!(
+                        "/circle/{}/join-requests/{}",
+                        circle_id, response_body.join_request_id
+                    ))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &DecideJoinRequestRequestBody {
+                            decision: JoinRequestDecision::Approve,
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invitation() -> anyhow::Result<()> {
+        let app = router().with_state(AppState::in_memory(Db::new()));
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let member_id = create_member(&app, "Ringo Starr", 1).await?;
+
+        let invite = |member_id: &str| -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("/circle/{}/invitations", circle_id))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", owner_id.as_str())
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &CreateInvitationRequestBody {
+                        member_id: member_id.to_string(),
+                        valid_days: Some(3),
+                    },
+                )?))?)
+        };
+        let response = app.clone().oneshot(invite(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let invitation = serde_json::from_slice::<CreateInvitationResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+
+        let list = |acting_member_id: &str| -> anyhow::Result<axum::http::Request<_>> {
+            Ok(axum::http::Request::builder()
+                .method("GET")
+                .uri(format!("/circle/{}/invitations", circle_id))
+                .header("X-Member-Id", acting_member_id)
+                .body(axum::body::Body::empty())?)
+        };
+        // not even the invited member gets to list the circle's invitations
+        let response = app.clone().oneshot(list(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = app.clone().oneshot(list(&owner_id)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<FetchInvitationsResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.invitations.len(), 1);
+        assert_eq!(
+            response_body.invitations[0].invitation_id,
+            invitation.invitation_id
+        );
+        assert_eq!(response_body.invitations[0].status, "Pending");
+        assert_eq!(
+            response_body.invitations[0].expires_at,
+            invitation.expires_at
+        );
+
+        let accept = |token: &str| -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri("/invitations/accept")
+                .header(CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &AcceptInvitationRequestBody {
+                        token: token.to_string(),
+                    },
+                )?))?)
+        };
+        let response = app
+            .clone()
+            .oneshot(accept(&format!("{}x", invitation.token))?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app.clone().oneshot(accept(&invitation.token)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<AcceptInvitationResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.circle_id, circle_id);
+        assert_eq!(response_body.member_id, member_id);
+        // single use
+        let response = app.clone().oneshot(accept(&invitation.token)?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let response = app.clone().oneshot(invite(&member_id)?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        // a revoked invitation can't be accepted
+        let other_id = create_member(&app, "George Harrison", 2).await?;
+        let response = app.clone().oneshot(invite(&other_id)?).await?;
+        let invitation = serde_json::from_slice::<CreateInvitationResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("DELETE")
+                    .uri(format!(
+                        "/circle/{}/invitations/{}",
+                        circle_id, invitation.invitation_id
+                    ))
+                    .header("X-Member-Id", owner_id.as_str())
+                    .body(axum::body::Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<RevokeInvitationResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.status, "Revoked");
+        let response = app.oneshot(accept(&invitation.token)?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover_hands_over_to_successor() -> anyhow::Result<()> {
+        let state = admin_state();
+        let app = router().with_state(state.clone());
+        let (circle_id, owner_id) = build_circle(&app).await?;
+        let member_id = join_circle(&app, &circle_id, &owner_id, 1).await?;
+        let nominate = || -> anyhow::Result<axum::http::Request<axum::body::Body>> {
+            Ok(axum::http::Request::builder()
+                .method("POST")
+                .uri(format!("/circle/{}/owner", circle_id))
+                .header(CONTENT_TYPE, "application/json")
+                .header("X-Member-Id", owner_id.as_str())
+                .body(axum::body::Body::new(serde_json::to_string(
+                    &TransferOwnershipRequestBody {
+                        member_id: member_id.clone(),
+                        at_rollover: true,
+                    },
+                )?))?)
+        };
+
+        // a 1st grader is too young to take over
+        let response = app.clone().oneshot(nominate()?).await?;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // owner 3rd -> 4th, member 1st -> 2nd
+        let response = app
+            .clone()
+            .oneshot(rollover(Some(ADMIN_TOKEN), 2025)?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = app.clone().oneshot(nominate()?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // the owner graduates and the nominated successor takes over
+        let response = app.oneshot(rollover(Some(ADMIN_TOKEN), 2026)?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response_body = serde_json::from_slice::<RolloverAcademicYearResponseBody>(
+            &axum::body::to_bytes(response.into_body(), usize::MAX).await?,
+        )?;
+        assert_eq!(response_body.circles[0].graduated[0].member_id, owner_id);
+        assert_eq!(
+            response_body.circles[0].new_owner_id.as_deref(),
+            Some(member_id.as_str())
+        );
+
+        let circle = state
+            .circle_repository
+            .find_by_id(&CircleId::from_str(&circle_id)?)
+            .await?;
+        assert_eq!(circle.owner.id, MemberId::from_str(&member_id)?);
+        assert_eq!(circle.owner.grade, Grade::Third);
+        assert!(circle.members.is_empty());
+        Ok(())
+    }
+
+    async fn join_circle(
+        app: &Router,
+        circle_id: &str,
+        owner_id: &str,
+        grade: i16,
+    ) -> anyhow::Result<String> {
+        let member_id = create_member(app, "Paul McCartney", grade).await?;
+        let join_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri(format!("/circle/{}/members", circle_id))
+                    .header(CONTENT_TYPE, "application/json")
+                    .header("X-Member-Id", owner_id)
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &JoinCircleRequestBody { member_id },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(join_response.status(), StatusCode::OK);
+        let join_response_body = serde_json::from_slice::<JoinCircleResponseBody>(
+            &axum::body::to_bytes(join_response.into_body(), usize::MAX).await?,
+        )?;
+        Ok(join_response_body.member_id)
+    }
+
+    async fn create_member(app: &Router, name: &str, grade: i16) -> anyhow::Result<String> {
+        let create_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/member")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &CreateMemberRequestBody {
+                            name: name.to_string(),
+                            age: 21,
+                            grade,
+                            major: "Music".to_string(),
+                        },
+                    )?))?,
+            )
+            .await?;
+        assert_eq!(create_response.status(), StatusCode::OK);
+        let create_response_body = serde_json::from_slice::<CreateMemberResponseBody>(
+            &axum::body::to_bytes(create_response.into_body(), usize::MAX).await?,
+        )?;
+        Ok(create_response_body.member_id)
+    }
+
+    async fn build_circle(app: &Router) -> anyhow::Result<(String, String)> {
+        build_named_circle(app, "Music club").await
+    }
+
+    async fn build_named_circle(
+        app: &Router,
+        circle_name: &str,
+    ) -> anyhow::Result<(String, String)> {
+        let owner_id = create_member(app, "John Lennon", 3).await?;
+        let create_response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/circle")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::new(serde_json::to_string(
+                        &CreateCircleRequestBody {
+                            circle_name: circle_name.to_string(),
                             capacity: 10,
-                            owner_name: "John Lennon".to_string(),
-                            owner_age: 21,
-                            owner_grade: 3,
-                            owner_major: "Music".to_string(),
+                            owner_id,
                         },
                     )?))?,
             )
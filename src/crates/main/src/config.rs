@@ -1 +1,4 @@
+pub mod admin;
 pub mod connect;
+pub mod invitation;
+pub mod storage;
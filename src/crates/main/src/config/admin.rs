@@ -0,0 +1,12 @@
+use std::{env, sync::Arc};
+
+/// The bearer token `/admin` routes require, from `ADMIN_TOKEN`. Without one they stay closed.
+pub(crate) fn admin_token() -> Option<Arc<str>> {
+    match env::var("ADMIN_TOKEN") {
+        Ok(token) if !token.is_empty() => Some(Arc::from(token)),
+        _ => {
+            tracing::warn!("ADMIN_TOKEN is not set, admin routes are disabled");
+            None
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use infrastructure::invitation_token::InvitationTokenSigner;
+use std::env;
+
+/// Signs invitation tokens with `INVITATION_SECRET`, so they stay valid across restarts.
+pub(crate) fn invitation_token_signer() -> InvitationTokenSigner {
+    match env::var("INVITATION_SECRET") {
+        Ok(secret) if !secret.is_empty() => InvitationTokenSigner::new(secret),
+        _ => {
+            tracing::warn!("INVITATION_SECRET is not set, invitations won't survive a restart");
+            InvitationTokenSigner::random()
+        }
+    }
+}
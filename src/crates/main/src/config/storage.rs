@@ -0,0 +1,18 @@
+use std::env;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StorageType {
+    MySQL,
+    InMemory,
+}
+
+impl StorageType {
+    pub(crate) fn from_env() -> Self {
+        let storage_raw = env::var("STORAGE").unwrap_or_else(|_| "mysql".to_string());
+        match storage_raw.as_str() {
+            "mysql" => StorageType::MySQL,
+            "memory" => StorageType::InMemory,
+            other => panic!("unknown STORAGE: {other} (expected \"memory\" or \"mysql\")"),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use axum::{
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use domain::error::DomainError;
+use usecase::error::UsecaseError;
+
+/// Renders a `UsecaseError` as an RFC 7807 problem document.
+#[derive(Debug)]
+pub(crate) struct ApiError(UsecaseError);
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+}
+
+impl ApiError {
+    fn status(&self) -> StatusCode {
+        match &self.0 {
+            UsecaseError::Domain(error) => match error {
+                DomainError::InvalidId(_) | DomainError::InvalidInvitationToken => {
+                    StatusCode::BAD_REQUEST
+                }
+                DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
+                DomainError::NotFound(_) | DomainError::NotMember => StatusCode::NOT_FOUND,
+                DomainError::Duplicate(_)
+                | DomainError::VersionConflict(_)
+                | DomainError::CapacityBelowMemberCount(_)
+                | DomainError::AlreadyMember
+                | DomainError::AlreadyWaitlisted
+                | DomainError::CircleDisbanded
+                | DomainError::NotReactivatable(_)
+                | DomainError::JoinRequestNotPending(_)
+                | DomainError::InvitationNotPending(_)
+                | DomainError::AlreadyRolledOver(_) => StatusCode::CONFLICT,
+                DomainError::InvitationExpired => StatusCode::GONE,
+                DomainError::InvalidOwnerGrade
+                | DomainError::InvalidSuccessorGrade
+                | DomainError::InvalidCapacity(_)
+                | DomainError::InvalidGrade(_)
+                | DomainError::InvalidAge(_)
+                | DomainError::InvalidAcademicYear(_)
+                | DomainError::InvalidCircleStatus(_)
+                | DomainError::InvalidJoinRequestStatus(_)
+                | DomainError::InvalidInvitationStatus(_)
+                | DomainError::InvalidRole(_)
+                | DomainError::OwnerRoleNotAssignable
+                | DomainError::EmptyCircleName
+                | DomainError::CircleNameTooLong
+                | DomainError::FourthGradeCannotJoin
+                | DomainError::OwnerCannotBeRemoved => StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            UsecaseError::InvalidInput(_) => StatusCode::BAD_REQUEST,
+            UsecaseError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            UsecaseError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            UsecaseError::Unexpected(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<UsecaseError> for ApiError {
+    fn from(error: UsecaseError) -> Self {
+        ApiError(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        let detail = match &self.0 {
+            UsecaseError::Domain(error) => error.to_string(),
+            UsecaseError::InvalidInput(message)
+            | UsecaseError::PreconditionFailed(message)
+            | UsecaseError::Unauthorized(message) => message.clone(),
+            UsecaseError::Unexpected(error) => {
+                tracing::error!("unexpected error: {:?}", error);
+                "Internal server error".to_string()
+            }
+        };
+        let problem = ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail,
+        };
+        (
+            status,
+            [(CONTENT_TYPE, "application/problem+json")],
+            Json(problem),
+        )
+            .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::aggregate::value_object::{academic_year::AcademicYear, permission::Permission};
+
+    use super::*;
+
+    async fn render(error: UsecaseError) -> anyhow::Result<(StatusCode, ProblemDetails)> {
+        let response = ApiError::from(error).into_response();
+        let status = response.status();
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body)?))
+    }
+
+    #[tokio::test]
+    async fn test_status_mapping() -> anyhow::Result<()> {
+        for (error, status) in [
+            (DomainError::InvalidId("Circle"), StatusCode::BAD_REQUEST),
+            (DomainError::NotFound("Circle"), StatusCode::NOT_FOUND),
+            (DomainError::Duplicate("Circle name"), StatusCode::CONFLICT),
+            (DomainError::AlreadyWaitlisted, StatusCode::CONFLICT),
+            (DomainError::VersionConflict("Circle"), StatusCode::CONFLICT),
+            (DomainError::AlreadyMember, StatusCode::CONFLICT),
+            (DomainError::CircleDisbanded, StatusCode::CONFLICT),
+            (DomainError::InvitationExpired, StatusCode::GONE),
+            (DomainError::InvalidInvitationToken, StatusCode::BAD_REQUEST),
+            (
+                DomainError::Forbidden(Permission::Rename),
+                StatusCode::FORBIDDEN,
+            ),
+            (
+                DomainError::CapacityBelowMemberCount(3),
+                StatusCode::CONFLICT,
+            ),
+            (DomainError::InvalidAge(7), StatusCode::UNPROCESSABLE_ENTITY),
+            (
+                DomainError::AlreadyRolledOver(AcademicYear::try_from(2026)?),
+                StatusCode::CONFLICT,
+            ),
+            (
+                DomainError::InvalidAcademicYear(26),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                DomainError::EmptyCircleName,
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+            (
+                DomainError::InvalidOwnerGrade,
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ),
+        ] {
+            let detail = error.to_string();
+            let (actual, problem) = render(UsecaseError::Domain(error)).await?;
+            assert_eq!(actual, status);
+            assert_eq!(problem.status, status.as_u16());
+            assert_eq!(problem.detail, detail);
+        }
+
+        let (status, problem) = render(UsecaseError::InvalidInput(
+            "limit must be positive".to_string(),
+        ))
+        .await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(problem.detail, "limit must be positive");
+
+        let (status, _) = render(UsecaseError::PreconditionFailed(
+            "Circle version is 2, not 1".to_string(),
+        ))
+        .await?;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+        let (status, _) = render(UsecaseError::Unauthorized(
+            "Admin token is missing or wrong".to_string(),
+        ))
+        .await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unexpected_error_hides_detail() -> anyhow::Result<()> {
+        let (status, problem) = render(UsecaseError::Unexpected(anyhow::Error::msg(
+            "Failed to fetch circle and members",
+        )))
+        .await?;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(problem.title, "Internal Server Error");
+        assert_eq!(problem.detail, "Internal server error");
+        Ok(())
+    }
+}
@@ -1,18 +1,107 @@
 use axum::{
-    extract::{Json, Path, State},
-    http::StatusCode,
+    extract::{rejection::JsonRejection, Json, Path, Query, State},
+    http::{
+        header::{HeaderName, AUTHORIZATION, ETAG, IF_MATCH},
+        HeaderMap, StatusCode,
+    },
     response::IntoResponse,
 };
 use serde::Deserialize;
 use std::env;
 use usecase::{
+    accept_invitation::{AcceptInvitationInput, AcceptInvitationOutput, AcceptInvitationUsecase},
+    assign_role::{AssignRoleInput, AssignRoleOutput, AssignRoleUsecase},
     create_circle::{CreateCircleInput, CreateCircleOutput, CreateCircleUsecase},
-    fetch_all_circle::FetchAllCircleUsecase,
-    fetch_circle::{FetchCircleInput, FetchCircleOutput, FetchCircleUsecase, MemberOutput},
+    create_invitation::{CreateInvitationInput, CreateInvitationOutput, CreateInvitationUsecase},
+    create_member::{CreateMemberInput, CreateMemberOutput, CreateMemberUsecase},
+    decide_join_request::{
+        DecideJoinRequestInput, DecideJoinRequestOutput, DecideJoinRequestUsecase,
+        JoinRequestDecision,
+    },
+    delete_circle::{DeleteCircleInput, DeleteCircleUsecase},
+    disband_circle::{DisbandCircleInput, DisbandCircleOutput, DisbandCircleUsecase},
+    error::UsecaseError,
+    fetch_all_circle::{
+        CircleSummaryOutput, FetchAllCircleInput, FetchAllCircleOutput, FetchAllCircleUsecase,
+    },
+    fetch_circle::{
+        FetchCircleInput, FetchCircleOutput, FetchCircleUsecase, MemberOutput, WaitlistEntryOutput,
+    },
+    fetch_invitations::{
+        FetchInvitationsInput, FetchInvitationsOutput, FetchInvitationsUsecase, InvitationOutput,
+    },
+    fetch_join_requests::{
+        FetchJoinRequestsInput, FetchJoinRequestsOutput, FetchJoinRequestsUsecase,
+        JoinRequestOutput,
+    },
+    fetch_member::{FetchMemberInput, FetchMemberOutput, FetchMemberUsecase},
+    fetch_member_circles::{
+        FetchMemberCirclesInput, FetchMemberCirclesOutput, FetchMemberCirclesUsecase,
+        MemberCircleOutput,
+    },
+    join_circle::{JoinCircleInput, JoinCircleOutput, JoinCircleUsecase},
+    leave_circle::{LeaveCircleInput, LeaveCircleOutput, LeaveCircleUsecase},
+    reactivate_circle::{ReactivateCircleInput, ReactivateCircleOutput, ReactivateCircleUsecase},
+    revoke_invitation::{RevokeInvitationInput, RevokeInvitationOutput, RevokeInvitationUsecase},
+    rollover_academic_year::{
+        CircleRolloverOutput, PromotedMemberOutput, RolloverAcademicYearInput,
+        RolloverAcademicYearOutput, RolloverAcademicYearUsecase,
+    },
+    submit_join_request::{
+        SubmitJoinRequestInput, SubmitJoinRequestOutput, SubmitJoinRequestUsecase,
+    },
+    transfer_ownership::{
+        TransferOwnershipInput, TransferOwnershipOutput, TransferOwnershipUsecase,
+    },
     update_circle::{UpdateCircleInput, UpdateCircleOutPut, UpdateCircleUsecase},
+    update_member::{UpdateMemberInput, UpdateMemberOutput, UpdateMemberUsecase},
 };
 
-use crate::app::AppState;
+use crate::{
+    app::{AppState, Storage},
+    error::ApiError,
+};
+
+/// Names the member making the request; use cases check it against the circle's roles.
+const X_MEMBER_ID: HeaderName = HeaderName::from_static("x-member-id");
+
+fn acting_member_id(headers: &HeaderMap) -> Result<String, ApiError> {
+    headers
+        .get(X_MEMBER_ID)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+        .ok_or_else(|| {
+            ApiError::from(UsecaseError::InvalidInput(
+                "X-Member-Id header is required".to_string(),
+            ))
+        })
+}
+
+/// Checks the `Authorization: Bearer` header against the configured admin token.
+fn require_admin<S: Storage>(state: &AppState<S>, headers: &HeaderMap) -> Result<(), ApiError> {
+    let unauthorized = || {
+        ApiError::from(UsecaseError::Unauthorized(
+            "Admin token is missing or wrong".to_string(),
+        ))
+    };
+    let expected = state.admin_token.as_deref().ok_or_else(unauthorized)?;
+    let given = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(unauthorized)?;
+    // compare every byte so the time taken doesn't reveal how much of the token matched
+    let matches = given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0;
+    if !matches {
+        return Err(unauthorized());
+    }
+    Ok(())
+}
 
 pub(crate) async fn handle_get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
@@ -22,10 +111,7 @@ pub(crate) async fn handle_get_version() -> String {
 pub struct CreateCircleRequestBody {
     pub circle_name: String,
     pub capacity: i16,
-    pub owner_name: String,
-    pub owner_age: i16,
-    pub owner_grade: i16,
-    pub owner_major: String,
+    pub owner_id: String,
 }
 
 impl std::convert::From<CreateCircleRequestBody> for CreateCircleInput {
@@ -33,19 +119,13 @@ impl std::convert::From<CreateCircleRequestBody> for CreateCircleInput {
         CreateCircleRequestBody {
             circle_name,
             capacity,
-            owner_name,
-            owner_age,
-            owner_grade,
-            owner_major,
+            owner_id,
         }: CreateCircleRequestBody,
     ) -> Self {
         CreateCircleInput {
             circle_name,
             capacity,
-            owner_name,
-            owner_age,
-            owner_grade,
-            owner_major,
+            owner_id,
         }
     }
 }
@@ -70,19 +150,23 @@ impl std::convert::From<CreateCircleOutput> for CreateCircleResponseBody {
     }
 }
 
-pub(crate) async fn handle_create_circle(
-    State(state): State<AppState>,
+pub(crate) async fn handle_create_circle<S: Storage>(
+    State(state): State<AppState<S>>,
     Json(body): Json<CreateCircleRequestBody>,
-) -> Result<Json<CreateCircleResponseBody>, String> {
+) -> Result<Json<CreateCircleResponseBody>, ApiError> {
     let circle_circle_input = CreateCircleInput::from(body);
-    let mut usecase =
-        CreateCircleUsecase::new(state.circle_repository, state.circle_duplicate_checker);
+    let mut usecase = CreateCircleUsecase::new(
+        state.circle_repository,
+        state.circle_duplicate_checker,
+        state.member_repository,
+        state.id_generator,
+    );
     usecase
         .execute(circle_circle_input)
         .await
         .map(CreateCircleResponseBody::from)
         .map(Json)
-        .map_err(|e| e.to_string())
+        .map_err(ApiError::from)
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,6 +181,8 @@ pub struct FetcheCircleResponseBody {
     pub capacity: i16,
     pub owner: MemberOutput,
     pub members: Vec<MemberOutput>,
+    pub waitlist: Vec<WaitlistEntryOutput>,
+    pub status: String,
 }
 
 impl std::convert::From<FetchCircleOutput> for FetcheCircleResponseBody {
@@ -107,6 +193,9 @@ impl std::convert::From<FetchCircleOutput> for FetcheCircleResponseBody {
             capacity,
             owner,
             members,
+            waitlist,
+            status,
+            ..
         }: FetchCircleOutput,
     ) -> Self {
         FetcheCircleResponseBody {
@@ -115,29 +204,97 @@ impl std::convert::From<FetchCircleOutput> for FetcheCircleResponseBody {
             capacity,
             owner,
             members,
+            waitlist,
+            status,
         }
     }
 }
 
-pub(crate) async fn handle_fetch_circle(
-    State(state): State<AppState>,
+pub(crate) async fn handle_fetch_circle<S: Storage>(
+    State(state): State<AppState<S>>,
     Path(param): Path<FetchCircleInputParam>,
-) -> Result<Json<FetcheCircleResponseBody>, String> {
+) -> Result<impl IntoResponse, ApiError> {
     let fetch_circle_input = FetchCircleInput::new(param.id);
     let usecase = FetchCircleUsecase::new(state.circle_repository);
     usecase
         .execute(fetch_circle_input)
         .await
-        .map(FetcheCircleResponseBody::from)
-        .map(Json)
-        .map_err(|e| e.to_string())
+        .map(|output| {
+            (
+                [(ETAG, etag(output.version))],
+                Json(FetcheCircleResponseBody::from(output)),
+            )
+        })
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchAllCircleQueryParam {
+    limit: Option<usize>,
+    cursor: Option<String>,
+    name_prefix: Option<String>,
+    owner_major: Option<String>,
+    has_free_seats: Option<bool>,
+    status: Option<String>,
+    sort: Option<String>,
+}
+
+impl std::convert::From<FetchAllCircleQueryParam> for FetchAllCircleInput {
+    fn from(
+        FetchAllCircleQueryParam {
+            limit,
+            cursor,
+            name_prefix,
+            owner_major,
+            has_free_seats,
+            status,
+            sort,
+        }: FetchAllCircleQueryParam,
+    ) -> Self {
+        FetchAllCircleInput {
+            limit,
+            cursor,
+            name_prefix,
+            owner_major,
+            has_free_seats,
+            status,
+            sort,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchAllCircleResponseBody {
+    pub circles: Vec<CircleSummaryOutput>,
+    pub next_cursor: Option<String>,
 }
 
-pub(crate) async fn handle_fetch_all(State(state): State<AppState>) -> impl IntoResponse {
+impl std::convert::From<FetchAllCircleOutput> for FetchAllCircleResponseBody {
+    fn from(
+        FetchAllCircleOutput {
+            circles,
+            next_cursor,
+        }: FetchAllCircleOutput,
+    ) -> Self {
+        FetchAllCircleResponseBody {
+            circles,
+            next_cursor,
+        }
+    }
+}
+
+pub(crate) async fn handle_fetch_all<S: Storage>(
+    State(state): State<AppState<S>>,
+    Query(param): Query<FetchAllCircleQueryParam>,
+) -> Result<Json<FetchAllCircleResponseBody>, ApiError> {
+    let fetch_all_circle_input = FetchAllCircleInput::from(param);
     let usecase = FetchAllCircleUsecase::new(state.circle_repository);
-    let circles = usecase.execute().await;
-    tracing::info!("circles: {:?}", circles);
-    (StatusCode::OK).into_response()
+    usecase
+        .execute(fetch_all_circle_input)
+        .await
+        .map(FetchAllCircleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
 }
 
 #[derive(Debug, Deserialize)]
@@ -152,8 +309,19 @@ pub struct UpdateCircleRequestBody {
 }
 
 impl UpdateCircleRequestBody {
-    pub fn convert_to_input(self, id: String) -> UpdateCircleInput {
-        UpdateCircleInput::new(id, self.circle_name, self.capacity)
+    pub fn convert_to_input(
+        self,
+        id: String,
+        acting_member_id: String,
+        expected_version: Option<u32>,
+    ) -> UpdateCircleInput {
+        UpdateCircleInput::new(
+            id,
+            acting_member_id,
+            self.circle_name,
+            self.capacity,
+            expected_version,
+        )
     }
 }
 
@@ -163,25 +331,962 @@ pub struct UpdateCircleResponseBody {
 }
 
 impl std::convert::From<UpdateCircleOutPut> for UpdateCircleResponseBody {
-    fn from(UpdateCircleOutPut { circle_id }: UpdateCircleOutPut) -> Self {
+    fn from(UpdateCircleOutPut { circle_id, .. }: UpdateCircleOutPut) -> Self {
         UpdateCircleResponseBody { circle_id }
     }
 }
 
-pub(crate) async fn handle_update_circle(
-    State(state): State<AppState>,
+pub(crate) async fn handle_update_circle<S: Storage>(
+    State(state): State<AppState<S>>,
     Path(path): Path<UpdateCircleInputParam>,
+    headers: HeaderMap,
     Json(body): Json<UpdateCircleRequestBody>,
-) -> Result<Json<UpdateCircleResponseBody>, String> {
-    let update_circle_input = body.convert_to_input(path.id.to_string());
+) -> Result<impl IntoResponse, ApiError> {
+    let expected_version = parse_if_match(&headers)?;
+    let update_circle_input = body.convert_to_input(
+        path.id.to_string(),
+        acting_member_id(&headers)?,
+        expected_version,
+    );
     let mut usecase = UpdateCircleUsecase::new(state.circle_repository);
 
     usecase
         .execute(update_circle_input)
         .await
-        .map(UpdateCircleResponseBody::from)
+        .map(|output| {
+            (
+                [(ETAG, etag(output.version))],
+                Json(UpdateCircleResponseBody::from(output)),
+            )
+        })
+        .map_err(ApiError::from)
+}
+
+fn etag(version: u32) -> String {
+    format!("\"{version}\"")
+}
+
+// `*` and a missing header both mean "update whatever is there"
+fn parse_if_match(headers: &HeaderMap) -> Result<Option<u32>, ApiError> {
+    let Some(value) = headers.get(IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value.to_str().unwrap_or_default().trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(|version| version.parse::<u32>().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            ApiError::from(UsecaseError::PreconditionFailed(format!(
+                "If-Match {value} does not match the current ETag"
+            )))
+        })
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteCircleInputParam {
+    id: String,
+}
+
+pub(crate) async fn handle_delete_circle<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<DeleteCircleInputParam>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ApiError> {
+    let delete_circle_input = DeleteCircleInput::new(path.id, acting_member_id(&headers)?);
+    let mut usecase = DeleteCircleUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(delete_circle_input)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JoinCircleInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct JoinCircleRequestBody {
+    pub member_id: String,
+}
+
+impl JoinCircleRequestBody {
+    pub fn convert_to_input(self, circle_id: String, acting_member_id: String) -> JoinCircleInput {
+        JoinCircleInput {
+            circle_id,
+            member_id: self.member_id,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct JoinCircleResponseBody {
+    pub circle_id: String,
+    pub member_id: String,
+    pub waitlist_position: Option<usize>,
+}
+
+impl std::convert::From<JoinCircleOutput> for JoinCircleResponseBody {
+    fn from(
+        JoinCircleOutput {
+            circle_id,
+            member_id,
+            waitlist_position,
+        }: JoinCircleOutput,
+    ) -> Self {
+        JoinCircleResponseBody {
+            circle_id,
+            member_id,
+            waitlist_position,
+        }
+    }
+}
+
+pub(crate) async fn handle_join_circle<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<JoinCircleInputParam>,
+    headers: HeaderMap,
+    Json(body): Json<JoinCircleRequestBody>,
+) -> Result<Json<JoinCircleResponseBody>, ApiError> {
+    let join_circle_input = body.convert_to_input(path.id, acting_member_id(&headers)?);
+    let mut usecase = JoinCircleUsecase::new(state.circle_repository, state.member_repository);
+
+    usecase
+        .execute(join_circle_input)
+        .await
+        .map(JoinCircleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeaveCircleInputParam {
+    id: String,
+    member_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct LeaveCircleResponseBody {
+    pub circle_id: String,
+}
+
+impl std::convert::From<LeaveCircleOutput> for LeaveCircleResponseBody {
+    fn from(LeaveCircleOutput { circle_id }: LeaveCircleOutput) -> Self {
+        LeaveCircleResponseBody { circle_id }
+    }
+}
+
+pub(crate) async fn handle_leave_circle<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<LeaveCircleInputParam>,
+    headers: HeaderMap,
+) -> Result<Json<LeaveCircleResponseBody>, ApiError> {
+    let leave_circle_input =
+        LeaveCircleInput::new(path.id, path.member_id, acting_member_id(&headers)?);
+    let mut usecase = LeaveCircleUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(leave_circle_input)
+        .await
+        .map(LeaveCircleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferOwnershipInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TransferOwnershipRequestBody {
+    pub member_id: String,
+    #[serde(default)]
+    pub at_rollover: bool,
+}
+
+impl TransferOwnershipRequestBody {
+    pub fn convert_to_input(
+        self,
+        circle_id: String,
+        acting_member_id: String,
+    ) -> TransferOwnershipInput {
+        TransferOwnershipInput::new(
+            circle_id,
+            self.member_id,
+            self.at_rollover,
+            acting_member_id,
+        )
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TransferOwnershipResponseBody {
+    pub circle_id: String,
+    pub owner_id: String,
+    pub successor_id: Option<String>,
+}
+
+impl std::convert::From<TransferOwnershipOutput> for TransferOwnershipResponseBody {
+    fn from(
+        TransferOwnershipOutput {
+            circle_id,
+            owner_id,
+            successor_id,
+        }: TransferOwnershipOutput,
+    ) -> Self {
+        TransferOwnershipResponseBody {
+            circle_id,
+            owner_id,
+            successor_id,
+        }
+    }
+}
+
+pub(crate) async fn handle_transfer_ownership<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<TransferOwnershipInputParam>,
+    headers: HeaderMap,
+    Json(body): Json<TransferOwnershipRequestBody>,
+) -> Result<Json<TransferOwnershipResponseBody>, ApiError> {
+    let transfer_ownership_input = body.convert_to_input(path.id, acting_member_id(&headers)?);
+    let mut usecase = TransferOwnershipUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(transfer_ownership_input)
+        .await
+        .map(TransferOwnershipResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignRoleInputParam {
+    id: String,
+    member_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AssignRoleRequestBody {
+    pub role: String,
+}
+
+impl AssignRoleRequestBody {
+    pub fn convert_to_input(
+        self,
+        path: AssignRoleInputParam,
+        acting_member_id: String,
+    ) -> AssignRoleInput {
+        AssignRoleInput::new(path.id, path.member_id, self.role, acting_member_id)
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AssignRoleResponseBody {
+    pub circle_id: String,
+    pub member_id: String,
+    pub role: String,
+}
+
+impl std::convert::From<AssignRoleOutput> for AssignRoleResponseBody {
+    fn from(
+        AssignRoleOutput {
+            circle_id,
+            member_id,
+            role,
+        }: AssignRoleOutput,
+    ) -> Self {
+        AssignRoleResponseBody {
+            circle_id,
+            member_id,
+            role,
+        }
+    }
+}
+
+pub(crate) async fn handle_assign_role<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<AssignRoleInputParam>,
+    headers: HeaderMap,
+    Json(body): Json<AssignRoleRequestBody>,
+) -> Result<Json<AssignRoleResponseBody>, ApiError> {
+    let assign_role_input = body.convert_to_input(path, acting_member_id(&headers)?);
+    let mut usecase = AssignRoleUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(assign_role_input)
+        .await
+        .map(AssignRoleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DisbandCircleInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DisbandCircleResponseBody {
+    pub circle_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<DisbandCircleOutput> for DisbandCircleResponseBody {
+    fn from(DisbandCircleOutput { circle_id, status }: DisbandCircleOutput) -> Self {
+        DisbandCircleResponseBody { circle_id, status }
+    }
+}
+
+pub(crate) async fn handle_disband_circle<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<DisbandCircleInputParam>,
+    headers: HeaderMap,
+) -> Result<Json<DisbandCircleResponseBody>, ApiError> {
+    let disband_circle_input = DisbandCircleInput::new(path.id, acting_member_id(&headers)?);
+    let mut usecase = DisbandCircleUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(disband_circle_input)
+        .await
+        .map(DisbandCircleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReactivateCircleInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReactivateCircleResponseBody {
+    pub circle_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<ReactivateCircleOutput> for ReactivateCircleResponseBody {
+    fn from(ReactivateCircleOutput { circle_id, status }: ReactivateCircleOutput) -> Self {
+        ReactivateCircleResponseBody { circle_id, status }
+    }
+}
+
+pub(crate) async fn handle_reactivate_circle<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<ReactivateCircleInputParam>,
+    headers: HeaderMap,
+) -> Result<Json<ReactivateCircleResponseBody>, ApiError> {
+    let reactivate_circle_input = ReactivateCircleInput::new(path.id, acting_member_id(&headers)?);
+    let mut usecase = ReactivateCircleUsecase::new(state.circle_repository);
+
+    usecase
+        .execute(reactivate_circle_input)
+        .await
+        .map(ReactivateCircleResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitJoinRequestInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SubmitJoinRequestRequestBody {
+    pub member_id: String,
+}
+
+impl SubmitJoinRequestRequestBody {
+    pub fn convert_to_input(self, circle_id: String) -> SubmitJoinRequestInput {
+        SubmitJoinRequestInput {
+            circle_id,
+            member_id: self.member_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SubmitJoinRequestResponseBody {
+    pub join_request_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<SubmitJoinRequestOutput> for SubmitJoinRequestResponseBody {
+    fn from(
+        SubmitJoinRequestOutput {
+            join_request_id,
+            status,
+        }: SubmitJoinRequestOutput,
+    ) -> Self {
+        SubmitJoinRequestResponseBody {
+            join_request_id,
+            status,
+        }
+    }
+}
+
+pub(crate) async fn handle_submit_join_request<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<SubmitJoinRequestInputParam>,
+    Json(body): Json<SubmitJoinRequestRequestBody>,
+) -> Result<Json<SubmitJoinRequestResponseBody>, ApiError> {
+    let submit_join_request_input = body.convert_to_input(path.id);
+    let mut usecase = SubmitJoinRequestUsecase::new(
+        state.circle_repository,
+        state.member_repository,
+        state.join_request_repository,
+        state.id_generator,
+    );
+
+    usecase
+        .execute(submit_join_request_input)
+        .await
+        .map(SubmitJoinRequestResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchJoinRequestsInputParam {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchJoinRequestsQueryParam {
+    pub status: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchJoinRequestsResponseBody {
+    pub join_requests: Vec<JoinRequestOutput>,
+}
+
+impl std::convert::From<FetchJoinRequestsOutput> for FetchJoinRequestsResponseBody {
+    fn from(FetchJoinRequestsOutput { join_requests }: FetchJoinRequestsOutput) -> Self {
+        FetchJoinRequestsResponseBody { join_requests }
+    }
+}
+
+pub(crate) async fn handle_fetch_join_requests<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<FetchJoinRequestsInputParam>,
+    Query(param): Query<FetchJoinRequestsQueryParam>,
+    headers: HeaderMap,
+) -> Result<Json<FetchJoinRequestsResponseBody>, ApiError> {
+    let fetch_join_requests_input = FetchJoinRequestsInput {
+        circle_id: path.id,
+        status: param.status,
+        acting_member_id: acting_member_id(&headers)?,
+    };
+    let usecase =
+        FetchJoinRequestsUsecase::new(state.circle_repository, state.join_request_repository);
+
+    usecase
+        .execute(fetch_join_requests_input)
+        .await
+        .map(FetchJoinRequestsResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideJoinRequestInputParam {
+    id: String,
+    join_request_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DecideJoinRequestRequestBody {
+    pub decision: JoinRequestDecision,
+}
+
+impl DecideJoinRequestRequestBody {
+    pub fn convert_to_input(
+        self,
+        path: DecideJoinRequestInputParam,
+        acting_member_id: String,
+    ) -> DecideJoinRequestInput {
+        DecideJoinRequestInput {
+            circle_id: path.id,
+            join_request_id: path.join_request_id,
+            decision: self.decision,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DecideJoinRequestResponseBody {
+    pub join_request_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<DecideJoinRequestOutput> for DecideJoinRequestResponseBody {
+    fn from(
+        DecideJoinRequestOutput {
+            join_request_id,
+            status,
+        }: DecideJoinRequestOutput,
+    ) -> Self {
+        DecideJoinRequestResponseBody {
+            join_request_id,
+            status,
+        }
+    }
+}
+
+pub(crate) async fn handle_decide_join_request<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<DecideJoinRequestInputParam>,
+    headers: HeaderMap,
+    body: Result<Json<DecideJoinRequestRequestBody>, JsonRejection>,
+) -> Result<Json<DecideJoinRequestResponseBody>, ApiError> {
+    // an unknown decision is a bad request rather than axum's 422
+    let Json(body) = body.map_err(|rejection| UsecaseError::InvalidInput(rejection.body_text()))?;
+    let decide_join_request_input = body.convert_to_input(path, acting_member_id(&headers)?);
+    let mut usecase = DecideJoinRequestUsecase::new(
+        state.circle_repository,
+        state.member_repository,
+        state.join_request_repository,
+    );
+
+    usecase
+        .execute(decide_join_request_input)
+        .await
+        .map(DecideJoinRequestResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateInvitationInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateInvitationRequestBody {
+    pub member_id: String,
+    pub valid_days: Option<u16>,
+}
+
+impl CreateInvitationRequestBody {
+    pub fn convert_to_input(
+        self,
+        circle_id: String,
+        acting_member_id: String,
+    ) -> CreateInvitationInput {
+        CreateInvitationInput {
+            circle_id,
+            member_id: self.member_id,
+            valid_days: self.valid_days,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateInvitationResponseBody {
+    pub invitation_id: String,
+    pub token: String,
+    pub expires_at: u64,
+}
+
+impl std::convert::From<CreateInvitationOutput> for CreateInvitationResponseBody {
+    fn from(
+        CreateInvitationOutput {
+            invitation_id,
+            token,
+            expires_at,
+        }: CreateInvitationOutput,
+    ) -> Self {
+        CreateInvitationResponseBody {
+            invitation_id,
+            token,
+            expires_at,
+        }
+    }
+}
+
+pub(crate) async fn handle_create_invitation<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<CreateInvitationInputParam>,
+    headers: HeaderMap,
+    Json(body): Json<CreateInvitationRequestBody>,
+) -> Result<Json<CreateInvitationResponseBody>, ApiError> {
+    let create_invitation_input = body.convert_to_input(path.id, acting_member_id(&headers)?);
+    let mut usecase = CreateInvitationUsecase::new(
+        state.circle_repository,
+        state.member_repository,
+        state.invitation_repository,
+        state.id_generator,
+        state.invitation_token_signer,
+    );
+
+    usecase
+        .execute(create_invitation_input)
+        .await
+        .map(CreateInvitationResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchInvitationsInputParam {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchInvitationsQueryParam {
+    pub status: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchInvitationsResponseBody {
+    pub invitations: Vec<InvitationOutput>,
+}
+
+impl std::convert::From<FetchInvitationsOutput> for FetchInvitationsResponseBody {
+    fn from(FetchInvitationsOutput { invitations }: FetchInvitationsOutput) -> Self {
+        FetchInvitationsResponseBody { invitations }
+    }
+}
+
+pub(crate) async fn handle_fetch_invitations<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<FetchInvitationsInputParam>,
+    Query(param): Query<FetchInvitationsQueryParam>,
+    headers: HeaderMap,
+) -> Result<Json<FetchInvitationsResponseBody>, ApiError> {
+    let fetch_invitations_input = FetchInvitationsInput {
+        circle_id: path.id,
+        status: param.status,
+        acting_member_id: acting_member_id(&headers)?,
+    };
+    let usecase =
+        FetchInvitationsUsecase::new(state.circle_repository, state.invitation_repository);
+
+    usecase
+        .execute(fetch_invitations_input)
+        .await
+        .map(FetchInvitationsResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeInvitationInputParam {
+    id: String,
+    invitation_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RevokeInvitationResponseBody {
+    pub invitation_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<RevokeInvitationOutput> for RevokeInvitationResponseBody {
+    fn from(
+        RevokeInvitationOutput {
+            invitation_id,
+            status,
+        }: RevokeInvitationOutput,
+    ) -> Self {
+        RevokeInvitationResponseBody {
+            invitation_id,
+            status,
+        }
+    }
+}
+
+pub(crate) async fn handle_revoke_invitation<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<RevokeInvitationInputParam>,
+    headers: HeaderMap,
+) -> Result<Json<RevokeInvitationResponseBody>, ApiError> {
+    let revoke_invitation_input =
+        RevokeInvitationInput::new(path.id, path.invitation_id, acting_member_id(&headers)?);
+    let mut usecase =
+        RevokeInvitationUsecase::new(state.circle_repository, state.invitation_repository);
+
+    usecase
+        .execute(revoke_invitation_input)
+        .await
+        .map(RevokeInvitationResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AcceptInvitationRequestBody {
+    pub token: String,
+}
+
+impl std::convert::From<AcceptInvitationRequestBody> for AcceptInvitationInput {
+    fn from(AcceptInvitationRequestBody { token }: AcceptInvitationRequestBody) -> Self {
+        AcceptInvitationInput { token }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AcceptInvitationResponseBody {
+    pub circle_id: String,
+    pub member_id: String,
+    pub waitlist_position: Option<usize>,
+}
+
+impl std::convert::From<AcceptInvitationOutput> for AcceptInvitationResponseBody {
+    fn from(
+        AcceptInvitationOutput {
+            circle_id,
+            member_id,
+            waitlist_position,
+        }: AcceptInvitationOutput,
+    ) -> Self {
+        AcceptInvitationResponseBody {
+            circle_id,
+            member_id,
+            waitlist_position,
+        }
+    }
+}
+
+pub(crate) async fn handle_accept_invitation<S: Storage>(
+    State(state): State<AppState<S>>,
+    Json(body): Json<AcceptInvitationRequestBody>,
+) -> Result<Json<AcceptInvitationResponseBody>, ApiError> {
+    let accept_invitation_input = AcceptInvitationInput::from(body);
+    let mut usecase = AcceptInvitationUsecase::new(
+        state.circle_repository,
+        state.member_repository,
+        state.invitation_repository,
+        state.invitation_token_signer,
+    );
+
+    usecase
+        .execute(accept_invitation_input)
+        .await
+        .map(AcceptInvitationResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateMemberRequestBody {
+    pub name: String,
+    pub age: i16,
+    pub grade: i16,
+    pub major: String,
+}
+
+impl std::convert::From<CreateMemberRequestBody> for CreateMemberInput {
+    fn from(
+        CreateMemberRequestBody {
+            name,
+            age,
+            grade,
+            major,
+        }: CreateMemberRequestBody,
+    ) -> Self {
+        CreateMemberInput {
+            name,
+            age,
+            grade,
+            major,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateMemberResponseBody {
+    pub member_id: String,
+}
+
+impl std::convert::From<CreateMemberOutput> for CreateMemberResponseBody {
+    fn from(CreateMemberOutput { member_id }: CreateMemberOutput) -> Self {
+        CreateMemberResponseBody { member_id }
+    }
+}
+
+pub(crate) async fn handle_create_member<S: Storage>(
+    State(state): State<AppState<S>>,
+    Json(body): Json<CreateMemberRequestBody>,
+) -> Result<Json<CreateMemberResponseBody>, ApiError> {
+    let create_member_input = CreateMemberInput::from(body);
+    let mut usecase = CreateMemberUsecase::new(state.member_repository, state.id_generator);
+    usecase
+        .execute(create_member_input)
+        .await
+        .map(CreateMemberResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FetchMemberInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchMemberResponseBody {
+    pub id: String,
+    pub name: String,
+    pub age: i16,
+    pub grade: i16,
+    pub major: String,
+}
+
+impl std::convert::From<FetchMemberOutput> for FetchMemberResponseBody {
+    fn from(
+        FetchMemberOutput {
+            id,
+            name,
+            age,
+            grade,
+            major,
+        }: FetchMemberOutput,
+    ) -> Self {
+        FetchMemberResponseBody {
+            id,
+            name,
+            age,
+            grade,
+            major,
+        }
+    }
+}
+
+pub(crate) async fn handle_fetch_member<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(param): Path<FetchMemberInputParam>,
+) -> Result<Json<FetchMemberResponseBody>, ApiError> {
+    let fetch_member_input = FetchMemberInput::new(param.id);
+    let usecase = FetchMemberUsecase::new(state.member_repository);
+    usecase
+        .execute(fetch_member_input)
+        .await
+        .map(FetchMemberResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct FetchMemberCirclesResponseBody {
+    pub circles: Vec<MemberCircleOutput>,
+}
+
+impl std::convert::From<FetchMemberCirclesOutput> for FetchMemberCirclesResponseBody {
+    fn from(FetchMemberCirclesOutput { circles }: FetchMemberCirclesOutput) -> Self {
+        FetchMemberCirclesResponseBody { circles }
+    }
+}
+
+pub(crate) async fn handle_fetch_member_circles<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(param): Path<FetchMemberInputParam>,
+) -> Result<Json<FetchMemberCirclesResponseBody>, ApiError> {
+    let fetch_member_circles_input = FetchMemberCirclesInput::new(param.id);
+    let usecase = FetchMemberCirclesUsecase::new(state.circle_repository, state.member_repository);
+    usecase
+        .execute(fetch_member_circles_input)
+        .await
+        .map(FetchMemberCirclesResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMemberInputParam {
+    id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateMemberRequestBody {
+    pub name: Option<String>,
+    pub age: Option<i16>,
+    pub major: Option<String>,
+}
+
+impl UpdateMemberRequestBody {
+    pub fn convert_to_input(self, id: String) -> UpdateMemberInput {
+        UpdateMemberInput::new(id, self.name, self.age, self.major)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct UpdateMemberResponseBody {
+    pub member_id: String,
+}
+
+impl std::convert::From<UpdateMemberOutput> for UpdateMemberResponseBody {
+    fn from(UpdateMemberOutput { member_id }: UpdateMemberOutput) -> Self {
+        UpdateMemberResponseBody { member_id }
+    }
+}
+
+pub(crate) async fn handle_update_member<S: Storage>(
+    State(state): State<AppState<S>>,
+    Path(path): Path<UpdateMemberInputParam>,
+    Json(body): Json<UpdateMemberRequestBody>,
+) -> Result<Json<UpdateMemberResponseBody>, ApiError> {
+    let update_member_input = body.convert_to_input(path.id);
+    let mut usecase = UpdateMemberUsecase::new(state.member_repository);
+    usecase
+        .execute(update_member_input)
+        .await
+        .map(UpdateMemberResponseBody::from)
+        .map(Json)
+        .map_err(ApiError::from)
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RolloverAcademicYearRequestBody {
+    pub academic_year: i16,
+}
+
+impl std::convert::From<RolloverAcademicYearRequestBody> for RolloverAcademicYearInput {
+    fn from(
+        RolloverAcademicYearRequestBody { academic_year }: RolloverAcademicYearRequestBody,
+    ) -> Self {
+        RolloverAcademicYearInput::new(academic_year)
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RolloverAcademicYearResponseBody {
+    pub promoted: Vec<PromotedMemberOutput>,
+    pub circles: Vec<CircleRolloverOutput>,
+}
+
+impl std::convert::From<RolloverAcademicYearOutput> for RolloverAcademicYearResponseBody {
+    fn from(RolloverAcademicYearOutput { promoted, circles }: RolloverAcademicYearOutput) -> Self {
+        RolloverAcademicYearResponseBody { promoted, circles }
+    }
+}
+
+pub(crate) async fn handle_rollover_academic_year<S: Storage>(
+    State(state): State<AppState<S>>,
+    headers: HeaderMap,
+    Json(body): Json<RolloverAcademicYearRequestBody>,
+) -> Result<Json<RolloverAcademicYearResponseBody>, ApiError> {
+    require_admin(&state, &headers)?;
+    let mut usecase =
+        RolloverAcademicYearUsecase::new(state.circle_repository, state.member_repository);
+    usecase
+        .execute(RolloverAcademicYearInput::from(body))
+        .await
+        .map(RolloverAcademicYearResponseBody::from)
         .map(Json)
-        .map_err(|e| e.to_string())
+        .map_err(ApiError::from)
 }
 
 #[tracing::instrument(name = "handle_debug", skip())]
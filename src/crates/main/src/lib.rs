@@ -1,3 +1,5 @@
 pub mod app;
 pub(crate) mod config;
+pub(crate) mod error;
 pub(crate) mod handler;
+pub mod migrate;
@@ -0,0 +1,26 @@
+use infrastructure::mysql::migration;
+
+use crate::config::connect;
+
+/// Entry point of `main migrate <up|down|status>`.
+pub async fn run(command: Option<&str>) -> anyhow::Result<()> {
+    let pool = connect::connect().await?;
+    match command {
+        Some("up") => {
+            migration::up(&pool).await?;
+            println!("Applied all pending migrations");
+        }
+        Some("down") => match migration::down(&pool).await? {
+            Some(version) => println!("Reverted migration {version}"),
+            None => println!("No migration to revert"),
+        },
+        Some("status") => {
+            for status in migration::status(&pool).await? {
+                let state = if status.applied { "applied" } else { "pending" };
+                println!("{:>4} {:<8} {}", status.version, state, status.description);
+            }
+        }
+        _ => anyhow::bail!("usage: main migrate <up|down|status>"),
+    }
+    Ok(())
+}
@@ -0,0 +1,369 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use domain::interface::{
+    circle_repository_interface::CircleRepositoryInterface,
+    invitation_repository_interface::InvitationRepositoryInterface,
+    invitation_token_interface::InvitationTokenInterface,
+    member_repository_interface::MemberRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptInvitationInput {
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct AcceptInvitationOutput {
+    pub circle_id: String,
+    pub member_id: String,
+    /// Set when the circle was full and the member was put on its waitlist instead.
+    pub waitlist_position: Option<usize>,
+}
+
+pub struct AcceptInvitationUsecase<T, M, I, K>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    I: InvitationRepositoryInterface,
+    K: InvitationTokenInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+    invitation_repository: I,
+    invitation_token: K,
+}
+
+impl<T, M, I, K> AcceptInvitationUsecase<T, M, I, K>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    I: InvitationRepositoryInterface,
+    K: InvitationTokenInterface,
+{
+    pub fn new(
+        circle_repository: T,
+        member_repository: M,
+        invitation_repository: I,
+        invitation_token: K,
+    ) -> Self {
+        AcceptInvitationUsecase {
+            circle_repository,
+            member_repository,
+            invitation_repository,
+            invitation_token,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        accept_invitation_input: AcceptInvitationInput,
+    ) -> Result<AcceptInvitationOutput, UsecaseError> {
+        let invitation_id = self
+            .invitation_token
+            .verify(accept_invitation_input.token.as_str())?;
+        let invitation = self
+            .invitation_repository
+            .find_by_id(&invitation_id)
+            .await?;
+
+        let invitation = invitation.accept(SystemTime::now())?;
+        let member = self
+            .member_repository
+            .find_by_id(&invitation.member_id)
+            .await?;
+        self.circle_repository
+            .find_by_id(&invitation.circle_id)
+            .await?
+            .add_member(member.clone())?;
+        // one transaction: a revoke or a second accept that got here first fails this before
+        // anyone is seated, and a seat that can't be taken leaves the invitation usable
+        let circle = self
+            .circle_repository
+            .add_member_by_invitation(&invitation, &member)
+            .await?;
+        Ok(AcceptInvitationOutput {
+            waitlist_position: circle.waitlist_position(&member.id),
+            circle_id: String::from(circle.id),
+            member_id: String::from(member.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            invitation::Invitation,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                grade::Grade, invitation_id::InvitationId, invitation_status::InvitationStatus,
+                major::Major, member_id::MemberId,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            invitation_repository_interface::MockInvitationRepositoryInterface,
+            invitation_token_interface::MockInvitationTokenInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn build_member() -> anyhow::Result<Member> {
+        Ok(Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        ))
+    }
+
+    fn mock_circle_repository(circle: &Circle) -> MockCircleRepositoryInterface {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        circle_repository
+    }
+
+    fn mock_member_repository(member: &Member) -> MockMemberRepositoryInterface {
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let member_clone = member.clone();
+        member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(member_clone.clone()));
+        member_repository
+    }
+
+    fn mock_invitation_token(invitation_id: &InvitationId) -> MockInvitationTokenInterface {
+        let mut invitation_token = MockInvitationTokenInterface::new();
+        let invitation_id = invitation_id.clone();
+        invitation_token
+            .expect_verify()
+            .withf(|token| token == "token")
+            .returning(move |_| Ok(invitation_id.clone()));
+        invitation_token
+    }
+
+    // the invitation is only ever stored together with the seat, through the circle repository
+    fn mock_invitation_repository(invitation: &Invitation) -> MockInvitationRepositoryInterface {
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        let invitation_clone = invitation.clone();
+        invitation_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(invitation_clone.clone()));
+        invitation_repository.expect_update().times(0);
+        invitation_repository
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member()?;
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            member.id.clone(),
+            SystemTime::now(),
+            Duration::from_secs(60),
+        )?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        let (circle_clone, invitation_id) = (circle.clone(), invitation.id.clone());
+        circle_repository
+            .expect_add_member_by_invitation()
+            .withf(move |invitation, member| {
+                invitation.id == invitation_id
+                    && invitation.status == InvitationStatus::Accepted
+                    && member.name == "mike"
+            })
+            .times(1)
+            .returning(move |_, member| Ok(circle_clone.clone().add_member(member.clone())?));
+
+        let mut usecase = AcceptInvitationUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_invitation_repository(&invitation),
+            mock_invitation_token(&invitation.id),
+        );
+        let output = usecase
+            .execute(AcceptInvitationInput {
+                token: "token".to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            AcceptInvitationOutput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+                waitlist_position: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_usecase_lost_race() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member()?;
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            member.id.clone(),
+            SystemTime::now(),
+            Duration::from_secs(60),
+        )?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        // the invitation was accepted or revoked between our read and our write
+        circle_repository
+            .expect_add_member_by_invitation()
+            .times(1)
+            .returning(|_, _| {
+                Err(DomainError::InvitationNotPending(InvitationStatus::Revoked).into())
+            });
+
+        let mut usecase = AcceptInvitationUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_invitation_repository(&invitation),
+            mock_invitation_token(&invitation.id),
+        );
+        let result = usecase
+            .execute(AcceptInvitationInput {
+                token: "token".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvitationNotPending(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_usecase_add_member_fails() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member()?;
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            member.id.clone(),
+            SystemTime::now(),
+            Duration::from_secs(60),
+        )?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        // the circle was disbanded between our read and our write; the error comes out of the
+        // same transaction that would have consumed the invitation, so nothing else is written
+        circle_repository
+            .expect_add_member_by_invitation()
+            .times(1)
+            .returning(|_, _| Err(DomainError::CircleDisbanded.into()));
+
+        let mut usecase = AcceptInvitationUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_invitation_repository(&invitation),
+            mock_invitation_token(&invitation.id),
+        );
+        let result = usecase
+            .execute(AcceptInvitationInput {
+                token: "token".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::CircleDisbanded)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_usecase_expired() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            MemberId::gen(),
+            SystemTime::now() - Duration::from_secs(120),
+            Duration::from_secs(60),
+        )?;
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        circle_repository.expect_add_member_by_invitation().times(0);
+
+        let mut usecase = AcceptInvitationUsecase::new(
+            circle_repository,
+            MockMemberRepositoryInterface::new(),
+            mock_invitation_repository(&invitation),
+            mock_invitation_token(&invitation.id),
+        );
+        let result = usecase
+            .execute(AcceptInvitationInput {
+                token: "token".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvitationExpired)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_accept_invitation_usecase_invalid_token() -> anyhow::Result<()> {
+        let mut invitation_token = MockInvitationTokenInterface::new();
+        invitation_token
+            .expect_verify()
+            .returning(|_| Err(DomainError::InvalidInvitationToken));
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        invitation_repository.expect_find_by_id().times(0);
+
+        let mut usecase = AcceptInvitationUsecase::new(
+            MockCircleRepositoryInterface::new(),
+            MockMemberRepositoryInterface::new(),
+            invitation_repository,
+            invitation_token,
+        );
+        let result = usecase
+            .execute(AcceptInvitationInput {
+                token: "forged".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvalidInvitationToken)
+        ));
+        Ok(())
+    }
+}
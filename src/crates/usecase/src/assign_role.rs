@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{
+        circle_id::CircleId, member_id::MemberId, permission::Permission, role::Role,
+    },
+    interface::circle_repository_interface::CircleRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct AssignRoleInput {
+    pub circle_id: String,
+    pub member_id: String,
+    /// "vice_owner", "treasurer" or "member"
+    pub role: String,
+    pub acting_member_id: String,
+}
+
+impl AssignRoleInput {
+    pub fn new(
+        circle_id: String,
+        member_id: String,
+        role: String,
+        acting_member_id: String,
+    ) -> Self {
+        AssignRoleInput {
+            circle_id,
+            member_id,
+            role,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct AssignRoleOutput {
+    pub circle_id: String,
+    pub member_id: String,
+    pub role: String,
+}
+
+pub struct AssignRoleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    circle_repository: T,
+}
+
+impl<T> AssignRoleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    pub fn new(circle_repository: T) -> Self {
+        AssignRoleUsecase { circle_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        assign_role_input: AssignRoleInput,
+    ) -> Result<AssignRoleOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(assign_role_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(assign_role_input.member_id.as_str())?;
+        let acting_member_id = MemberId::from_str(assign_role_input.acting_member_id.as_str())?;
+        let role = Role::try_from(assign_role_input.role.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::AssignRole)?;
+
+        let circle = circle.assign_role(&member_id, role)?;
+        self.circle_repository.update(&circle).await?;
+        Ok(AssignRoleOutput {
+            role: circle
+                .role(&member_id)
+                .map(String::from)
+                .unwrap_or_default(),
+            circle_id: String::from(circle.id),
+            member_id: String::from(member_id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::circle_repository_interface::MockCircleRepositoryInterface,
+    };
+
+    use super::*;
+
+    fn build_circle(member: &Member) -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?)
+    }
+
+    fn build_member() -> anyhow::Result<Member> {
+        Ok(Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(20)?,
+            Grade::Second,
+            Major::Economics,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_assign_role_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let member = build_member()?;
+        let circle = build_circle(&member)?;
+        let circle_clone = circle.clone();
+        let member_id = member.id.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .withf(move |circle| circle.role(&member_id) == Some(Role::Treasurer))
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+
+        let mut usecase = AssignRoleUsecase::new(mocked_circle_repository);
+        let input = AssignRoleInput::new(
+            circle.id.to_string(),
+            member.id.to_string(),
+            "treasurer".to_string(),
+            circle.owner.id.to_string(),
+        );
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(
+            output,
+            AssignRoleOutput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+                role: "treasurer".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_assign_role_usecase_errors() -> anyhow::Result<()> {
+        let member = build_member()?;
+        let circle = build_circle(&member)?.assign_role(&member.id, Role::ViceOwner)?;
+        for (role, acting_member_id, error) in [
+            (
+                "vice_owner",
+                member.id.clone(),
+                DomainError::Forbidden(Permission::AssignRole),
+            ),
+            (
+                "owner",
+                circle.owner.id.clone(),
+                DomainError::OwnerRoleNotAssignable,
+            ),
+            (
+                "captain",
+                circle.owner.id.clone(),
+                DomainError::InvalidRole("captain".to_string()),
+            ),
+        ] {
+            let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+            let circle_clone = circle.clone();
+            mocked_circle_repository
+                .expect_find_by_id()
+                .returning(move |_| Ok(circle_clone.clone()));
+            mocked_circle_repository.expect_update().times(0);
+
+            let mut usecase = AssignRoleUsecase::new(mocked_circle_repository);
+            let input = AssignRoleInput::new(
+                circle.id.to_string(),
+                member.id.to_string(),
+                role.to_string(),
+                acting_member_id.to_string(),
+            );
+            let result = usecase.execute(input).await;
+
+            assert!(matches!(
+                result.unwrap_err(),
+                UsecaseError::Domain(e) if e == error
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,26 +1,27 @@
-use anyhow::Result;
+use std::str::FromStr;
+
 use serde::Deserialize;
 
 use domain::{
     aggregate::{
         circle::Circle,
-        member::Member,
-        value_object::{grade::Grade, major::Major},
+        value_object::{capacity::Capacity, circle_name::CircleName, member_id::MemberId},
     },
     interface::{
         circle_duplicate_checker_interface::CircleDuplicateCheckerInterface,
         circle_repository_interface::CircleRepositoryInterface,
+        id_generator_interface::IdGeneratorInterface,
+        member_repository_interface::MemberRepositoryInterface,
     },
 };
 
+use crate::error::UsecaseError;
+
 #[derive(Debug, Deserialize)]
 pub struct CreateCircleInput {
     pub circle_name: String,
     pub capacity: i16,
-    pub owner_name: String,
-    pub owner_age: i16,
-    pub owner_grade: i16,
-    pub owner_major: String,
+    pub owner_id: String,
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq)]
@@ -29,93 +30,140 @@ pub struct CreateCircleOutput {
     pub owner_id: String,
 }
 
-pub struct CreateCircleUsecase<T, U>
+pub struct CreateCircleUsecase<T, U, M, G>
 where
     T: CircleRepositoryInterface,
     U: CircleDuplicateCheckerInterface,
+    M: MemberRepositoryInterface,
+    G: IdGeneratorInterface,
 {
     circle_repository: T,
     circle_duplicate_checker: U,
+    member_repository: M,
+    id_generator: G,
 }
 
-impl<T, U> CreateCircleUsecase<T, U>
+impl<T, U, M, G> CreateCircleUsecase<T, U, M, G>
 where
     T: CircleRepositoryInterface,
     U: CircleDuplicateCheckerInterface,
+    M: MemberRepositoryInterface,
+    G: IdGeneratorInterface,
 {
-    pub fn new(circle_repository: T, circle_duplicate_checker: U) -> Self {
+    pub fn new(
+        circle_repository: T,
+        circle_duplicate_checker: U,
+        member_repository: M,
+        id_generator: G,
+    ) -> Self {
         CreateCircleUsecase {
             circle_repository,
             circle_duplicate_checker,
+            member_repository,
+            id_generator,
         }
     }
 
     pub async fn execute(
         &mut self,
         create_circle_input: CreateCircleInput,
-    ) -> Result<CreateCircleOutput> {
-        let grade = Grade::try_from(create_circle_input.owner_grade)?;
-        let major = Major::from(create_circle_input.owner_major.as_str());
-        let owner = Member::new(
-            create_circle_input.owner_name,
-            create_circle_input.owner_age,
-            grade,
-            major,
-        );
-        let circle = Circle::create(
-            create_circle_input.circle_name,
-            owner.clone(),
-            create_circle_input.capacity,
-        )?;
+    ) -> Result<CreateCircleOutput, UsecaseError> {
+        let owner_id = MemberId::from_str(create_circle_input.owner_id.as_str())?;
+        let circle_name = CircleName::try_from(create_circle_input.circle_name)?;
+        let capacity = Capacity::try_from(create_circle_input.capacity)?;
+        let owner = self.member_repository.find_by_id(&owner_id).await?;
+        let circle = Circle::create(self.id_generator.circle_id(), circle_name, owner, capacity)?;
         self.circle_duplicate_checker
             .check_circle_duplicate(&circle)
             .await?;
-        self.circle_repository
-            .create(&circle)
-            .await
-            .map(|_| CreateCircleOutput {
-                circle_id: String::from(circle.id),
-                owner_id: String::from(owner.id),
-            })
+        self.circle_repository.create(&circle).await?;
+        Ok(CreateCircleOutput {
+            circle_id: String::from(circle.id),
+            owner_id: String::from(owner_id),
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anyhow::anyhow;
+    use domain::aggregate::{
+        member::Member,
+        value_object::{age::Age, circle_id::CircleId, grade::Grade, major::Major},
+    };
+    use domain::error::DomainError;
     use domain::interface::{
         circle_duplicate_checker_interface::MockCircleDuplicateCheckerInterface,
         circle_repository_interface::MockCircleRepositoryInterface,
+        id_generator_interface::MockIdGeneratorInterface,
+        member_repository_interface::MockMemberRepositoryInterface,
     };
 
+    fn mock_id_generator(circle_id: CircleId) -> MockIdGeneratorInterface {
+        let mut id_generator = MockIdGeneratorInterface::new();
+        id_generator
+            .expect_circle_id()
+            .return_once(move || circle_id);
+        id_generator
+    }
+
+    fn mock_member_repository(
+        grade: Grade,
+    ) -> anyhow::Result<(MockMemberRepositoryInterface, Member)> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(21)?,
+            grade,
+            Major::ComputerScience,
+        );
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let owner_clone = owner.clone();
+        member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(owner_clone.clone()));
+        Ok((member_repository, owner))
+    }
+
     #[tokio::test]
     async fn test_create_circle_usecase_successful() -> anyhow::Result<()> {
         let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
         let mut mocked_circle_duplicate_checker = MockCircleDuplicateCheckerInterface::new();
+        let (member_repository, owner) = mock_member_repository(Grade::Third)?;
 
         let input = CreateCircleInput {
             circle_name: "music".to_string(),
             capacity: 10,
-            owner_name: "mike".to_string(),
-            owner_age: 21,
-            owner_grade: 3,
-            owner_major: "ComputerScience".to_string(),
+            owner_id: owner.id.to_string(),
         };
 
         mocked_circle_duplicate_checker
             .expect_check_circle_duplicate()
             .times(1)
             .return_once(|_| Ok(()));
+        let expected_owner = owner.clone();
         mocked_circle_repository
             .expect_create()
             .times(1)
+            .withf(move |circle| circle.owner == expected_owner)
             .return_once(|_| Ok(()));
 
-        let mut usecase =
-            CreateCircleUsecase::new(mocked_circle_repository, mocked_circle_duplicate_checker);
-        let _result = usecase.execute(input).await?;
+        let circle_id = CircleId::gen();
+        let mut usecase = CreateCircleUsecase::new(
+            mocked_circle_repository,
+            mocked_circle_duplicate_checker,
+            member_repository,
+            mock_id_generator(circle_id.clone()),
+        );
+        let output = usecase.execute(input).await?;
 
+        assert_eq!(
+            output,
+            CreateCircleOutput {
+                circle_id: circle_id.to_string(),
+                owner_id: owner.id.to_string(),
+            }
+        );
         anyhow::Ok(())
     }
 
@@ -123,32 +171,67 @@ mod tests {
     async fn test_create_circle_usecase_duplicate_error() -> anyhow::Result<()> {
         let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
         let mut mocked_circle_duplicate_checker = MockCircleDuplicateCheckerInterface::new();
+        let (member_repository, owner) = mock_member_repository(Grade::Third)?;
 
         let input = CreateCircleInput {
             circle_name: "music".to_string(),
             capacity: 10,
-            owner_name: "mike".to_string(),
-            owner_age: 21,
-            owner_grade: 3,
-            owner_major: "ComputerScience".to_string(),
+            owner_id: owner.id.to_string(),
         };
 
         mocked_circle_duplicate_checker
             .expect_check_circle_duplicate()
             .times(1)
-            .return_once(|_| Err(anyhow!("Circle name already exists")));
+            .return_once(|_| Err(DomainError::Duplicate("Circle name").into()));
         mocked_circle_repository.expect_create().times(0);
 
-        let mut usecase =
-            CreateCircleUsecase::new(mocked_circle_repository, mocked_circle_duplicate_checker);
+        let mut usecase = CreateCircleUsecase::new(
+            mocked_circle_repository,
+            mocked_circle_duplicate_checker,
+            member_repository,
+            mock_id_generator(CircleId::gen()),
+        );
         let result = usecase.execute(input).await;
 
-        assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            "Circle name already exists"
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error,
+            UsecaseError::Domain(DomainError::Duplicate("Circle name"))
+        ));
+        assert_eq!(error.to_string(), "Circle name already exists");
+
+        anyhow::Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_circle_usecase_invalid_owner() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let mut mocked_circle_duplicate_checker = MockCircleDuplicateCheckerInterface::new();
+        mocked_circle_duplicate_checker
+            .expect_check_circle_duplicate()
+            .times(0);
+        mocked_circle_repository.expect_create().times(0);
+        let (member_repository, owner) = mock_member_repository(Grade::Second)?;
+
+        let mut usecase = CreateCircleUsecase::new(
+            mocked_circle_repository,
+            mocked_circle_duplicate_checker,
+            member_repository,
+            mock_id_generator(CircleId::gen()),
         );
 
+        for (owner_id, error) in [
+            ("1".to_string(), DomainError::InvalidId("Member")),
+            (owner.id.to_string(), DomainError::InvalidOwnerGrade),
+        ] {
+            let input = CreateCircleInput {
+                circle_name: "music".to_string(),
+                capacity: 10,
+                owner_id,
+            };
+            let result = usecase.execute(input).await;
+            assert!(matches!(result, Err(UsecaseError::Domain(e)) if e == error));
+        }
         anyhow::Ok(())
     }
 }
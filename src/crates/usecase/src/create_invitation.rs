@@ -0,0 +1,333 @@
+use std::{
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::{
+        invitation::Invitation,
+        value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    },
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        id_generator_interface::IdGeneratorInterface,
+        invitation_repository_interface::InvitationRepositoryInterface,
+        invitation_token_interface::InvitationTokenInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateInvitationInput {
+    pub circle_id: String,
+    pub member_id: String,
+    pub valid_days: Option<u16>,
+    /// Inviting needs `Permission::ApproveJoin`.
+    pub acting_member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct CreateInvitationOutput {
+    pub invitation_id: String,
+    /// Only handed out here; the invitee accepts the invitation with it.
+    pub token: String,
+    /// unix seconds
+    pub expires_at: u64,
+}
+
+pub struct CreateInvitationUsecase<T, M, I, G, K>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    I: InvitationRepositoryInterface,
+    G: IdGeneratorInterface,
+    K: InvitationTokenInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+    invitation_repository: I,
+    id_generator: G,
+    invitation_token: K,
+}
+
+impl<T, M, I, G, K> CreateInvitationUsecase<T, M, I, G, K>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    I: InvitationRepositoryInterface,
+    G: IdGeneratorInterface,
+    K: InvitationTokenInterface,
+{
+    const DEFAULT_VALID_DAYS: u16 = 7;
+    const MAX_VALID_DAYS: u16 = 30;
+
+    pub fn new(
+        circle_repository: T,
+        member_repository: M,
+        invitation_repository: I,
+        id_generator: G,
+        invitation_token: K,
+    ) -> Self {
+        CreateInvitationUsecase {
+            circle_repository,
+            member_repository,
+            invitation_repository,
+            id_generator,
+            invitation_token,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        create_invitation_input: CreateInvitationInput,
+    ) -> Result<CreateInvitationOutput, UsecaseError> {
+        let valid_days = create_invitation_input
+            .valid_days
+            .unwrap_or(Self::DEFAULT_VALID_DAYS);
+        if valid_days == 0 || valid_days > Self::MAX_VALID_DAYS {
+            return Err(UsecaseError::InvalidInput(format!(
+                "valid_days must be between 1 and {}",
+                Self::MAX_VALID_DAYS
+            )));
+        }
+        let circle_id = CircleId::from_str(create_invitation_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(create_invitation_input.member_id.as_str())?;
+        let acting_member_id =
+            MemberId::from_str(create_invitation_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+        let member = self.member_repository.find_by_id(&member_id).await?;
+
+        let invitation = Invitation::issue(
+            self.id_generator.invitation_id(),
+            &circle,
+            member.id,
+            SystemTime::now(),
+            Duration::from_secs(u64::from(valid_days) * 24 * 60 * 60),
+        )?;
+        self.invitation_repository.create(&invitation).await?;
+        Ok(CreateInvitationOutput {
+            token: self.invitation_token.sign(&invitation.id),
+            expires_at: invitation
+                .expires_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            invitation_id: String::from(invitation.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                invitation_id::InvitationId, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            id_generator_interface::MockIdGeneratorInterface,
+            invitation_repository_interface::MockInvitationRepositoryInterface,
+            invitation_token_interface::MockInvitationTokenInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn mock_repositories(
+        circle: &Circle,
+        member: &Member,
+    ) -> (MockCircleRepositoryInterface, MockMemberRepositoryInterface) {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let member_clone = member.clone();
+        member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(member_clone.clone()));
+        (circle_repository, member_repository)
+    }
+
+    fn mock_id_generator(invitation_id: InvitationId) -> MockIdGeneratorInterface {
+        let mut id_generator = MockIdGeneratorInterface::new();
+        id_generator
+            .expect_invitation_id()
+            .returning(move || invitation_id.clone());
+        id_generator
+    }
+
+    #[tokio::test]
+    async fn test_create_invitation_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let (circle_repository, member_repository) = mock_repositories(&circle, &member);
+        let invitation_id = InvitationId::gen();
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        let (circle_id, member_id) = (circle.id.clone(), member.id.clone());
+        invitation_repository
+            .expect_create()
+            .withf(move |invitation| {
+                invitation.circle_id == circle_id && invitation.member_id == member_id
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut invitation_token = MockInvitationTokenInterface::new();
+        let id = invitation_id.clone();
+        invitation_token
+            .expect_sign()
+            .withf(move |invitation_id| *invitation_id == id)
+            .times(1)
+            .returning(|_| "token".to_string());
+
+        let mut usecase = CreateInvitationUsecase::new(
+            circle_repository,
+            member_repository,
+            invitation_repository,
+            mock_id_generator(invitation_id.clone()),
+            invitation_token,
+        );
+        let before = SystemTime::now();
+        let output = usecase
+            .execute(CreateInvitationInput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+                valid_days: Some(1),
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(output.invitation_id, invitation_id.to_string());
+        assert_eq!(output.token, "token");
+        let tomorrow = (before + Duration::from_secs(24 * 60 * 60))
+            .duration_since(UNIX_EPOCH)?
+            .as_secs();
+        assert!((tomorrow..=tomorrow + 1).contains(&output.expires_at));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_invitation_usecase_already_member() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let (circle_repository, member_repository) = mock_repositories(&circle, &circle.owner);
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        invitation_repository.expect_create().times(0);
+
+        let mut usecase = CreateInvitationUsecase::new(
+            circle_repository,
+            member_repository,
+            invitation_repository,
+            mock_id_generator(InvitationId::gen()),
+            MockInvitationTokenInterface::new(),
+        );
+        let result = usecase
+            .execute(CreateInvitationInput {
+                circle_id: circle.id.to_string(),
+                member_id: circle.owner.id.to_string(),
+                valid_days: None,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::AlreadyMember)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_invitation_usecase_checks_role() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let (circle_repository, member_repository) = mock_repositories(&circle, &member);
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        invitation_repository.expect_create().times(0);
+
+        let mut usecase = CreateInvitationUsecase::new(
+            circle_repository,
+            member_repository,
+            invitation_repository,
+            mock_id_generator(InvitationId::gen()),
+            MockInvitationTokenInterface::new(),
+        );
+        let result = usecase
+            .execute(CreateInvitationInput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+                valid_days: None,
+                acting_member_id: member.id.to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_invitation_usecase_invalid_valid_days() -> anyhow::Result<()> {
+        for valid_days in [0, 31] {
+            let mut usecase = CreateInvitationUsecase::new(
+                MockCircleRepositoryInterface::new(),
+                MockMemberRepositoryInterface::new(),
+                MockInvitationRepositoryInterface::new(),
+                MockIdGeneratorInterface::new(),
+                MockInvitationTokenInterface::new(),
+            );
+            let result = usecase
+                .execute(CreateInvitationInput {
+                    circle_id: CircleId::gen().to_string(),
+                    member_id: MemberId::gen().to_string(),
+                    valid_days: Some(valid_days),
+                    acting_member_id: MemberId::gen().to_string(),
+                })
+                .await;
+
+            assert!(matches!(result.unwrap_err(), UsecaseError::InvalidInput(_)));
+        }
+        Ok(())
+    }
+}
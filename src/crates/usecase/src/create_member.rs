@@ -0,0 +1,131 @@
+use serde::Deserialize;
+
+use domain::{
+    aggregate::{
+        member::Member,
+        value_object::{age::Age, grade::Grade, major::Major},
+    },
+    interface::{
+        id_generator_interface::IdGeneratorInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateMemberInput {
+    pub name: String,
+    pub age: i16,
+    pub grade: i16,
+    pub major: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct CreateMemberOutput {
+    pub member_id: String,
+}
+
+pub struct CreateMemberUsecase<T, G>
+where
+    T: MemberRepositoryInterface,
+    G: IdGeneratorInterface,
+{
+    member_repository: T,
+    id_generator: G,
+}
+
+impl<T, G> CreateMemberUsecase<T, G>
+where
+    T: MemberRepositoryInterface,
+    G: IdGeneratorInterface,
+{
+    pub fn new(member_repository: T, id_generator: G) -> Self {
+        CreateMemberUsecase {
+            member_repository,
+            id_generator,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        create_member_input: CreateMemberInput,
+    ) -> Result<CreateMemberOutput, UsecaseError> {
+        let member = Member::new(
+            self.id_generator.member_id(),
+            create_member_input.name,
+            Age::try_from(create_member_input.age)?,
+            Grade::try_from(create_member_input.grade)?,
+            Major::from(create_member_input.major.as_str()),
+        );
+        self.member_repository.create(&member).await?;
+        Ok(CreateMemberOutput {
+            member_id: String::from(member.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::aggregate::value_object::member_id::MemberId;
+    use domain::error::DomainError;
+    use domain::interface::{
+        id_generator_interface::MockIdGeneratorInterface,
+        member_repository_interface::MockMemberRepositoryInterface,
+    };
+
+    #[tokio::test]
+    async fn test_create_member_usecase_successful() -> anyhow::Result<()> {
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        let member_id = MemberId::gen();
+        let mut id_generator = MockIdGeneratorInterface::new();
+        let generated_id = member_id.clone();
+        id_generator
+            .expect_member_id()
+            .return_once(move || generated_id);
+        let expected_id = member_id.clone();
+        mocked_member_repository
+            .expect_create()
+            .times(1)
+            .withf(move |member| {
+                member.id == expected_id && member.name == "john" && member.grade == Grade::First
+            })
+            .returning(|_| Ok(()));
+
+        let mut usecase = CreateMemberUsecase::new(mocked_member_repository, id_generator);
+        let input = CreateMemberInput {
+            name: "john".to_string(),
+            age: 19,
+            grade: 1,
+            major: "Art".to_string(),
+        };
+        let output = usecase.execute(input).await?;
+        assert_eq!(output.member_id, member_id.to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_member_usecase_invalid_input() -> anyhow::Result<()> {
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        mocked_member_repository.expect_create().times(0);
+        let mut id_generator = MockIdGeneratorInterface::new();
+        id_generator.expect_member_id().returning(MemberId::gen);
+        let mut usecase = CreateMemberUsecase::new(mocked_member_repository, id_generator);
+
+        for (age, grade, error) in [
+            (10, 1, DomainError::InvalidAge(10)),
+            (19, 5, DomainError::InvalidGrade(5)),
+        ] {
+            let input = CreateMemberInput {
+                name: "john".to_string(),
+                age,
+                grade,
+                major: "Art".to_string(),
+            };
+            let result = usecase.execute(input).await;
+            assert!(matches!(result, Err(UsecaseError::Domain(e)) if e == error));
+        }
+        Ok(())
+    }
+}
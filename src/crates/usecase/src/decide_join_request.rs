@@ -0,0 +1,408 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    aggregate::value_object::{
+        circle_id::CircleId, join_request_id::JoinRequestId, member_id::MemberId,
+        permission::Permission,
+    },
+    error::DomainError,
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        join_request_repository_interface::JoinRequestRepositoryInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JoinRequestDecision {
+    Approve,
+    Reject,
+    Withdraw,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DecideJoinRequestInput {
+    pub circle_id: String,
+    pub join_request_id: String,
+    pub decision: JoinRequestDecision,
+    /// Approving and rejecting need `Permission::ApproveJoin`; so does withdrawing someone
+    /// else's request.
+    pub acting_member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct DecideJoinRequestOutput {
+    pub join_request_id: String,
+    pub status: String,
+}
+
+pub struct DecideJoinRequestUsecase<T, M, J>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+    join_request_repository: J,
+}
+
+impl<T, M, J> DecideJoinRequestUsecase<T, M, J>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+{
+    pub fn new(circle_repository: T, member_repository: M, join_request_repository: J) -> Self {
+        DecideJoinRequestUsecase {
+            circle_repository,
+            member_repository,
+            join_request_repository,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        decide_join_request_input: DecideJoinRequestInput,
+    ) -> Result<DecideJoinRequestOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(decide_join_request_input.circle_id.as_str())?;
+        let join_request_id =
+            JoinRequestId::from_str(decide_join_request_input.join_request_id.as_str())?;
+        let acting_member_id =
+            MemberId::from_str(decide_join_request_input.acting_member_id.as_str())?;
+        let join_request = self
+            .join_request_repository
+            .find_by_id(&join_request_id)
+            .await?;
+        if join_request.circle_id != circle_id {
+            return Err(DomainError::NotFound("Join request").into());
+        }
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+
+        let join_request = match decide_join_request_input.decision {
+            JoinRequestDecision::Approve => {
+                circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+                let join_request = join_request.approve()?;
+                let member = self
+                    .member_repository
+                    .find_by_id(&join_request.member_id)
+                    .await?;
+                circle.clone().add_member(member.clone())?;
+                // one transaction: only the approval that wins the pending request seats the
+                // member, and a seat that can't be taken leaves the request pending
+                self.circle_repository
+                    .add_member_by_join_request(&join_request, &member)
+                    .await?;
+                join_request
+            }
+            JoinRequestDecision::Reject => {
+                circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+                let join_request = join_request.reject()?;
+                self.join_request_repository.update(&join_request).await?;
+                join_request
+            }
+            JoinRequestDecision::Withdraw => {
+                if acting_member_id != join_request.member_id {
+                    circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+                }
+                let join_request = join_request.withdraw()?;
+                self.join_request_repository.update(&join_request).await?;
+                join_request
+            }
+        };
+        Ok(DecideJoinRequestOutput {
+            join_request_id: String::from(join_request.id),
+            status: String::from(join_request.status),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            join_request::JoinRequest,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                join_request_status::JoinRequestStatus, major::Major, member_id::MemberId,
+            },
+        },
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            join_request_repository_interface::MockJoinRequestRepositoryInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn build_member(grade: Grade) -> anyhow::Result<Member> {
+        Ok(Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            grade,
+            Major::Economics,
+        ))
+    }
+
+    fn mock_join_request_repository(
+        join_request: &JoinRequest,
+        updates: usize,
+    ) -> MockJoinRequestRepositoryInterface {
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        let join_request_clone = join_request.clone();
+        join_request_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(join_request_clone.clone()));
+        join_request_repository
+            .expect_update()
+            .times(updates)
+            .returning(|_| Ok(()));
+        join_request_repository
+    }
+
+    fn mock_circle_repository(circle: &Circle) -> MockCircleRepositoryInterface {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        circle_repository
+    }
+
+    fn mock_member_repository(member: &Member) -> MockMemberRepositoryInterface {
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let member_clone = member.clone();
+        member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(member_clone.clone()));
+        member_repository
+    }
+
+    #[tokio::test]
+    async fn test_decide_join_request_usecase_approve() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member(Grade::First)?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        let (circle_clone, join_request_id) = (circle.clone(), join_request.id.clone());
+        circle_repository
+            .expect_add_member_by_join_request()
+            .withf(move |join_request, member| {
+                join_request.id == join_request_id
+                    && join_request.status == JoinRequestStatus::Approved
+                    && member.name == "mike"
+            })
+            .times(1)
+            .returning(move |_, member| Ok(circle_clone.clone().add_member(member.clone())?));
+
+        let mut usecase = DecideJoinRequestUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 0),
+        );
+        let output = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Approve,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            DecideJoinRequestOutput {
+                join_request_id: join_request.id.to_string(),
+                status: "Approved".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decide_join_request_usecase_approve_rejects_4th_grade() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member(Grade::Fourth)?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        circle_repository
+            .expect_add_member_by_join_request()
+            .times(0);
+
+        let mut usecase = DecideJoinRequestUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 0),
+        );
+        let result = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Approve,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::FourthGradeCannotJoin)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decide_join_request_usecase_approve_lost_race() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member(Grade::First)?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        // someone else decided the request between our read and our write
+        circle_repository
+            .expect_add_member_by_join_request()
+            .times(1)
+            .returning(|_, _| {
+                Err(DomainError::JoinRequestNotPending(JoinRequestStatus::Rejected).into())
+            });
+
+        let mut usecase = DecideJoinRequestUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 0),
+        );
+        let result = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Approve,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::JoinRequestNotPending(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decide_join_request_usecase_reject() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member(Grade::First)?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+        let mut circle_repository = mock_circle_repository(&circle);
+        circle_repository
+            .expect_add_member_by_join_request()
+            .times(0);
+
+        let mut usecase = DecideJoinRequestUsecase::new(
+            circle_repository,
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 1),
+        );
+        let output = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Reject,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await?;
+        assert_eq!(output.status, "Rejected");
+
+        // a request is only found through its own circle
+        let mut usecase = DecideJoinRequestUsecase::new(
+            MockCircleRepositoryInterface::new(),
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 0),
+        );
+        let result = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: CircleId::gen().to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Reject,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::NotFound("Join request"))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_decide_join_request_usecase_checks_role() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = build_member(Grade::First)?;
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, member.id.clone())?;
+
+        // deciding takes the right to approve joins, which a stranger doesn't have
+        for decision in [
+            JoinRequestDecision::Approve,
+            JoinRequestDecision::Reject,
+            JoinRequestDecision::Withdraw,
+        ] {
+            let mut usecase = DecideJoinRequestUsecase::new(
+                mock_circle_repository(&circle),
+                mock_member_repository(&member),
+                mock_join_request_repository(&join_request, 0),
+            );
+            let result = usecase
+                .execute(DecideJoinRequestInput {
+                    circle_id: circle.id.to_string(),
+                    join_request_id: join_request.id.to_string(),
+                    decision,
+                    acting_member_id: MemberId::gen().to_string(),
+                })
+                .await;
+            assert!(matches!(
+                result.unwrap_err(),
+                UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+            ));
+        }
+
+        // while the requester can always take their own request back
+        let mut usecase = DecideJoinRequestUsecase::new(
+            mock_circle_repository(&circle),
+            mock_member_repository(&member),
+            mock_join_request_repository(&join_request, 1),
+        );
+        let output = usecase
+            .execute(DecideJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                join_request_id: join_request.id.to_string(),
+                decision: JoinRequestDecision::Withdraw,
+                acting_member_id: member.id.to_string(),
+            })
+            .await?;
+        assert_eq!(output.status, "Withdrawn");
+        Ok(())
+    }
+}
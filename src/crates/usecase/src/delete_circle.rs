@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    interface::circle_repository_interface::CircleRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteCircleInput {
+    pub id: String,
+    pub acting_member_id: String,
+}
+
+impl DeleteCircleInput {
+    pub fn new(id: String, acting_member_id: String) -> Self {
+        DeleteCircleInput {
+            id,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct DeleteCircleOutput {
+    pub circle_id: String,
+}
+
+pub struct DeleteCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    circle_repository: T,
+}
+
+impl<T> DeleteCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    pub fn new(circle_repository: T) -> Self {
+        DeleteCircleUsecase { circle_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        delete_circle_input: DeleteCircleInput,
+    ) -> Result<DeleteCircleOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(delete_circle_input.id.as_str())?;
+        let acting_member_id = MemberId::from_str(delete_circle_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ManageCircle)?;
+        self.circle_repository.delete(&circle).await?;
+        Ok(DeleteCircleOutput {
+            circle_id: String::from(circle.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                member_id::MemberId, role::Role,
+            },
+        },
+        error::DomainError,
+        interface::circle_repository_interface::MockCircleRepositoryInterface,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_circle_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let circle_id = circle.id.clone();
+        mocked_circle_repository
+            .expect_delete()
+            .withf(move |circle| circle.id == circle_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut usecase = DeleteCircleUsecase::new(mocked_circle_repository);
+        let output = usecase
+            .execute(DeleteCircleInput::new(
+                circle.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await?;
+
+        assert_eq!(output.circle_id, circle.id.to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_circle_usecase_not_found() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_| Err(DomainError::NotFound("Circle").into()));
+        mocked_circle_repository.expect_delete().times(0);
+
+        let mut usecase = DeleteCircleUsecase::new(mocked_circle_repository);
+        let result = usecase
+            .execute(DeleteCircleInput::new(
+                CircleId::gen().to_string(),
+                MemberId::gen().to_string(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::NotFound("Circle"))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_circle_usecase_checks_role() -> anyhow::Result<()> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?;
+
+        for circle in [
+            circle.clone(),
+            circle.assign_role(&member.id, Role::Treasurer)?,
+        ] {
+            let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+            let circle_clone = circle.clone();
+            mocked_circle_repository
+                .expect_find_by_id()
+                .returning(move |_| Ok(circle_clone.clone()));
+            mocked_circle_repository.expect_delete().times(0);
+
+            let mut usecase = DeleteCircleUsecase::new(mocked_circle_repository);
+            let result = usecase
+                .execute(DeleteCircleInput::new(
+                    circle.id.to_string(),
+                    member.id.to_string(),
+                ))
+                .await;
+
+            assert!(matches!(
+                result.unwrap_err(),
+                UsecaseError::Domain(DomainError::Forbidden(Permission::ManageCircle))
+            ));
+        }
+        Ok(())
+    }
+}
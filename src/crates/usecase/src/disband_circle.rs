@@ -0,0 +1,196 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    interface::circle_repository_interface::CircleRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct DisbandCircleInput {
+    pub circle_id: String,
+    pub acting_member_id: String,
+}
+
+impl DisbandCircleInput {
+    pub fn new(circle_id: String, acting_member_id: String) -> Self {
+        DisbandCircleInput {
+            circle_id,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct DisbandCircleOutput {
+    pub circle_id: String,
+    pub status: String,
+}
+
+pub struct DisbandCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    circle_repository: T,
+}
+
+impl<T> DisbandCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    pub fn new(circle_repository: T) -> Self {
+        DisbandCircleUsecase { circle_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        disband_circle_input: DisbandCircleInput,
+    ) -> Result<DisbandCircleOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(disband_circle_input.circle_id.as_str())?;
+        let acting_member_id = MemberId::from_str(disband_circle_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ManageCircle)?;
+
+        let circle = circle.disband()?;
+        self.circle_repository.update(&circle).await?;
+        Ok(DisbandCircleOutput {
+            circle_id: String::from(circle.id),
+            status: String::from(circle.status),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, circle_status::CircleStatus,
+                grade::Grade, major::Major, member_id::MemberId, role::Role,
+            },
+        },
+        error::DomainError,
+        interface::circle_repository_interface::MockCircleRepositoryInterface,
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_disband_circle_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let circle = build_circle()?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .withf(|circle| circle.status == CircleStatus::Disbanded)
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+
+        let mut usecase = DisbandCircleUsecase::new(mocked_circle_repository);
+        let output = usecase
+            .execute(DisbandCircleInput::new(
+                circle.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await?;
+
+        assert_eq!(
+            output,
+            DisbandCircleOutput {
+                circle_id: circle.id.to_string(),
+                status: "Disbanded".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disband_circle_usecase_already_disbanded() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let circle = build_circle()?.disband()?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository.expect_update().times(0);
+
+        let mut usecase = DisbandCircleUsecase::new(mocked_circle_repository);
+        let result = usecase
+            .execute(DisbandCircleInput::new(
+                circle.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::CircleDisbanded)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_disband_circle_usecase_checks_role() -> anyhow::Result<()> {
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = build_circle()?.add_member(member.clone())?;
+
+        // only the owner may disband the circle, whatever other role a member has
+        for circle in [
+            circle.clone(),
+            circle.assign_role(&member.id, Role::Treasurer)?,
+        ] {
+            let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+            let circle_clone = circle.clone();
+            mocked_circle_repository
+                .expect_find_by_id()
+                .returning(move |_| Ok(circle_clone.clone()));
+            mocked_circle_repository.expect_update().times(0);
+
+            let mut usecase = DisbandCircleUsecase::new(mocked_circle_repository);
+            let result = usecase
+                .execute(DisbandCircleInput::new(
+                    circle.id.to_string(),
+                    member.id.to_string(),
+                ))
+                .await;
+
+            assert!(matches!(
+                result.unwrap_err(),
+                UsecaseError::Domain(DomainError::Forbidden(Permission::ManageCircle))
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use domain::error::DomainError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UsecaseError {
+    #[error(transparent)]
+    Domain(#[from] DomainError),
+    #[error("{0}")]
+    InvalidInput(String),
+    #[error("{0}")]
+    PreconditionFailed(String),
+    #[error("{0}")]
+    Unauthorized(String),
+    #[error(transparent)]
+    Unexpected(anyhow::Error),
+}
+
+// repositories report domain failures (e.g. a missing circle) through `anyhow::Error`
+impl From<anyhow::Error> for UsecaseError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<DomainError>() {
+            Ok(error) => UsecaseError::Domain(error),
+            Err(error) => UsecaseError::Unexpected(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_anyhow_error() {
+        let error = UsecaseError::from(anyhow::Error::from(DomainError::NotFound("Circle")));
+        assert!(matches!(
+            error,
+            UsecaseError::Domain(DomainError::NotFound("Circle"))
+        ));
+
+        let error = UsecaseError::from(anyhow::Error::msg("connection refused"));
+        assert!(matches!(error, UsecaseError::Unexpected(_)));
+        assert_eq!(error.to_string(), "connection refused");
+    }
+}
@@ -1,22 +1,58 @@
-use anyhow::{Error, Ok, Result};
-use serde::Deserialize;
+use std::str::FromStr;
 
-use domain::interface::circle_repository_interface::CircleRepositoryInterface;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+use domain::{
+    aggregate::{
+        circle::Circle,
+        value_object::{circle_id::CircleId, circle_status::CircleStatus, major::Major},
+    },
+    interface::circle_repository_interface::{CircleQuery, CircleRepositoryInterface, CircleSort},
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Default, Deserialize)]
 pub struct FetchAllCircleInput {
-    pub id: i16,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+    pub name_prefix: Option<String>,
+    pub owner_major: Option<String>,
+    pub has_free_seats: Option<bool>,
+    pub status: Option<String>,
+    pub sort: Option<String>,
 }
 
-impl FetchAllCircleInput {
-    pub fn new(id: i16) -> Self {
-        FetchAllCircleInput { id }
-    }
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct FetchAllCircleOutput {
+    pub circles: Vec<CircleSummaryOutput>,
+    pub next_cursor: Option<String>,
 }
 
-// TODO: Define the output struct
-#[derive(Debug, Deserialize, PartialEq, Eq)]
-pub struct FetchAllCircleOutput {}
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CircleSummaryOutput {
+    pub circle_id: String,
+    pub circle_name: String,
+    pub capacity: i16,
+    pub owner_name: String,
+    pub owner_major: String,
+    pub member_count: usize,
+    pub status: String,
+}
+
+impl std::convert::From<Circle> for CircleSummaryOutput {
+    fn from(circle: Circle) -> Self {
+        CircleSummaryOutput {
+            member_count: circle.member_count(),
+            circle_id: circle.id.into(),
+            circle_name: circle.name.into(),
+            capacity: circle.capacity.into(),
+            owner_name: circle.owner.name,
+            owner_major: String::from(circle.owner.major),
+            status: circle.status.into(),
+        }
+    }
+}
 
 pub struct FetchAllCircleUsecase<T>
 where
@@ -29,13 +65,67 @@ impl<T> FetchAllCircleUsecase<T>
 where
     T: CircleRepositoryInterface,
 {
+    const DEFAULT_LIMIT: usize = 20;
+    const MAX_LIMIT: usize = 100;
+
     pub fn new(circle_repository: T) -> Self {
         FetchAllCircleUsecase { circle_repository }
     }
 
-    pub async fn execute(&self) -> Result<FetchAllCircleOutput, Error> {
-        self.circle_repository.find_all().await?;
-        Ok(FetchAllCircleOutput {})
+    pub async fn execute(
+        &self,
+        fetch_all_circle_input: FetchAllCircleInput,
+    ) -> Result<FetchAllCircleOutput, UsecaseError> {
+        let limit = fetch_all_circle_input.limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if limit == 0 || limit > Self::MAX_LIMIT {
+            return Err(UsecaseError::InvalidInput(format!(
+                "limit must be between 1 and {}",
+                Self::MAX_LIMIT
+            )));
+        }
+        let sort = match fetch_all_circle_input.sort.as_deref() {
+            None | Some("name") => CircleSort::Name,
+            Some("capacity") => CircleSort::Capacity,
+            Some(other) => {
+                return Err(UsecaseError::InvalidInput(format!(
+                    "unknown sort: {other} (expected \"name\" or \"capacity\")"
+                )))
+            }
+        };
+        let status = fetch_all_circle_input
+            .status
+            .map(|status| CircleStatus::try_from(status.as_str()))
+            .transpose()
+            .map_err(|e| UsecaseError::InvalidInput(e.to_string()))?;
+        let cursor = fetch_all_circle_input
+            .cursor
+            .map(|cursor| CircleId::from_str(cursor.as_str()))
+            .transpose()?;
+
+        // fetch one extra circle to find out whether there is a next page
+        let query = CircleQuery {
+            name_prefix: fetch_all_circle_input.name_prefix,
+            owner_major: fetch_all_circle_input
+                .owner_major
+                .map(|major| Major::from(major.as_str())),
+            has_free_seats: fetch_all_circle_input.has_free_seats,
+            status,
+            sort,
+            cursor,
+            limit: limit + 1,
+        };
+        let mut circles = self.circle_repository.find_by_query(&query).await?;
+
+        let next_cursor = if circles.len() > limit {
+            circles.truncate(limit);
+            circles.last().map(|circle| circle.id.to_string())
+        } else {
+            None
+        };
+        Ok(FetchAllCircleOutput {
+            circles: circles.into_iter().map(CircleSummaryOutput::from).collect(),
+            next_cursor,
+        })
     }
 }
 
@@ -43,34 +133,120 @@ where
 mod tests {
     use domain::{
         aggregate::{
-            circle::Circle,
             member::Member,
-            value_object::{grade::Grade, major::Major},
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                member_id::MemberId,
+            },
         },
         interface::circle_repository_interface::MockCircleRepositoryInterface,
     };
 
     use super::*;
 
-    #[tokio::test]
-    async fn test_fetch_all_circle_usecase() -> anyhow::Result<()> {
-        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+    fn build_circle(name: &str) -> anyhow::Result<Circle> {
         let owner = Member::new(
+            MemberId::gen(),
             "john".to_string(),
-            21,
+            Age::try_from(21)?,
             Grade::try_from(3)?,
             Major::from("ComputerScience"),
         );
-        let circle = Circle::create("music".to_string(), owner.clone(), 10)?;
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from(name)?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_circle_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let circles = vec![build_circle("music")?, build_circle("soccer")?];
+        let returned = circles.clone();
 
         mocked_circle_repository
-            .expect_find_all()
-            .returning(move || Ok(vec![circle.clone()]));
+            .expect_find_by_query()
+            .withf(|query| {
+                query.limit == 3
+                    && query.sort == CircleSort::Capacity
+                    && query.name_prefix.as_deref() == Some("m")
+                    && query.owner_major == Some(Major::ComputerScience)
+                    && query.status == Some(CircleStatus::Recruiting)
+            })
+            .times(1)
+            .returning(move |_| anyhow::Ok(returned.clone()));
 
         let usecase = FetchAllCircleUsecase::new(mocked_circle_repository);
-        let output = usecase.execute().await.unwrap();
+        let output = usecase
+            .execute(FetchAllCircleInput {
+                limit: Some(2),
+                name_prefix: Some("m".to_string()),
+                owner_major: Some("ComputerScience".to_string()),
+                status: Some("Recruiting".to_string()),
+                sort: Some("capacity".to_string()),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            FetchAllCircleOutput {
+                circles: circles.into_iter().map(CircleSummaryOutput::from).collect(),
+                next_cursor: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_circle_usecase_next_cursor() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let circles = vec![build_circle("art")?, build_circle("music")?];
+        let first_id = circles[0].id.to_string();
+
+        mocked_circle_repository
+            .expect_find_by_query()
+            .times(1)
+            .returning(move |_| anyhow::Ok(circles.clone()));
+
+        let usecase = FetchAllCircleUsecase::new(mocked_circle_repository);
+        let output = usecase
+            .execute(FetchAllCircleInput {
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await?;
+
+        assert_eq!(output.circles.len(), 1);
+        assert_eq!(output.next_cursor, Some(first_id));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_circle_usecase_invalid_sort() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        mocked_circle_repository.expect_find_by_query().times(0);
+
+        let usecase = FetchAllCircleUsecase::new(mocked_circle_repository);
+        let result = usecase
+            .execute(FetchAllCircleInput {
+                sort: Some("age".to_string()),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(matches!(result, Err(UsecaseError::InvalidInput(_))));
+
+        let result = usecase
+            .execute(FetchAllCircleInput {
+                status: Some("Closed".to_string()),
+                ..Default::default()
+            })
+            .await;
 
-        assert_eq!(output, FetchAllCircleOutput {});
+        assert!(matches!(result, Err(UsecaseError::InvalidInput(_))));
         Ok(())
     }
 }
@@ -1,13 +1,18 @@
 use std::str::FromStr;
 
-use anyhow::{Error, Result};
 use serde::{Deserialize, Serialize};
 
 use domain::{
-    aggregate::{circle::Circle, value_object::circle_id::CircleId},
+    aggregate::{
+        circle::Circle,
+        member::Member,
+        value_object::{circle_id::CircleId, role::Role},
+    },
     interface::circle_repository_interface::CircleRepositoryInterface,
 };
 
+use crate::error::UsecaseError;
+
 #[derive(Debug, Deserialize)]
 pub struct FetchCircleInput {
     pub id: String,
@@ -26,6 +31,9 @@ pub struct FetchCircleOutput {
     pub capacity: i16,
     pub owner: MemberOutput,
     pub members: Vec<MemberOutput>,
+    pub waitlist: Vec<WaitlistEntryOutput>,
+    pub status: String,
+    pub version: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -35,7 +43,32 @@ pub struct MemberOutput {
     pub age: i16,
     pub grade: i16,
     pub major: String,
+    /// The member's role in the circle; not set for someone on the waitlist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WaitlistEntryOutput {
+    /// 1 is next in line for a free seat.
+    pub position: usize,
+    #[serde(flatten)]
+    pub member: MemberOutput,
+}
+
+impl std::convert::From<&Member> for MemberOutput {
+    fn from(member: &Member) -> Self {
+        MemberOutput {
+            id: member.id.clone().into(),
+            name: member.name.clone(),
+            age: member.age.into(),
+            grade: i16::from(member.grade),
+            major: String::from(member.major),
+            role: None,
+        }
+    }
 }
+
 pub struct FetchCircleUsecase<T>
 where
     T: CircleRepositoryInterface,
@@ -54,45 +87,54 @@ where
     pub async fn execute(
         &self,
         fetch_circle_input: FetchCircleInput,
-    ) -> Result<FetchCircleOutput, Error> {
+    ) -> Result<FetchCircleOutput, UsecaseError> {
         let circle_id = CircleId::from_str(fetch_circle_input.id.as_str())?;
-        self.circle_repository
-            .find_by_id(&circle_id)
-            .await
-            .map(|circle: Circle| FetchCircleOutput {
-                circle_id: circle.id.into(),
-                circle_name: circle.name,
-                capacity: circle.capacity as i16,
-                owner: MemberOutput {
-                    id: String::from(circle.owner.id),
-                    name: circle.owner.name,
-                    age: circle.owner.age,
-                    grade: i16::from(circle.owner.grade),
-                    major: String::from(circle.owner.major),
-                },
-                members: circle
-                    .members
-                    .iter()
-                    .map(|member| MemberOutput {
-                        id: member.id.clone().into(),
-                        name: member.name.clone(),
-                        age: member.age,
-                        grade: i16::from(member.grade),
-                        major: String::from(member.major),
-                    })
-                    .collect(),
+        let circle: Circle = self.circle_repository.find_by_id(&circle_id).await?;
+        let members = circle
+            .members
+            .iter()
+            .map(|member| MemberOutput {
+                role: circle.role(&member.id).map(String::from),
+                ..MemberOutput::from(member)
             })
+            .collect();
+        Ok(FetchCircleOutput {
+            circle_id: circle.id.into(),
+            circle_name: circle.name.into(),
+            capacity: circle.capacity.into(),
+            owner: MemberOutput {
+                role: Some(Role::Owner.into()),
+                ..MemberOutput::from(&circle.owner)
+            },
+            members,
+            waitlist: circle
+                .waitlist
+                .iter()
+                .zip(1..)
+                .map(|(member, position)| WaitlistEntryOutput {
+                    position,
+                    member: MemberOutput::from(member),
+                })
+                .collect(),
+            status: circle.status.into(),
+            version: circle.version,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::collections::BTreeMap;
+
     use domain::{
         aggregate::{
             circle::Circle,
             member::Member,
-            value_object::{grade::Grade, major::Major},
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                member_id::MemberId,
+            },
         },
         interface::circle_repository_interface::MockCircleRepositoryInterface,
     };
@@ -102,26 +144,45 @@ mod tests {
     #[tokio::test]
     async fn test_fetch_circle_usecase() -> anyhow::Result<()> {
         let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
-        let circle_id = CircleId::from_str("123")?;
+        let circle_id = CircleId::gen();
         let owner = Member::new(
+            MemberId::gen(),
             "john".to_string(),
-            21,
+            Age::try_from(21)?,
             Grade::try_from(3)?,
             Major::from("ComputerScience"),
         );
         let members = vec![Member::new(
+            MemberId::gen(),
             "mike".to_string(),
-            19,
+            Age::try_from(19)?,
             Grade::try_from(1).unwrap(),
             Major::from("Economics"),
         )];
-        let circle = Circle::create("music".to_string(), owner.clone(), 10)?;
+        let waitlist = vec![Member::new(
+            MemberId::gen(),
+            "bob".to_string(),
+            Age::try_from(20)?,
+            Grade::try_from(2)?,
+            Major::from("Law"),
+        )];
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?;
         let circle = Circle::reconstruct(
             circle_id.clone(),
             circle.name,
             circle.owner,
             circle.capacity,
             members.clone(),
+            waitlist.clone(),
+            BTreeMap::from([(members[0].id.clone(), Role::Treasurer)]),
+            None,
+            circle.status,
+            circle.version,
         );
 
         mocked_circle_repository
@@ -141,12 +202,19 @@ mod tests {
         assert_eq!(output.owner.age, 21);
         assert_eq!(output.owner.grade, 3);
         assert_eq!(output.owner.major, "ComputerScience");
+        assert_eq!(output.owner.role.as_deref(), Some("owner"));
         assert_eq!(output.members.len(), 1);
         assert_eq!(output.members[0].id, members[0].id.clone().to_string());
         assert_eq!(output.members[0].name, "mike");
         assert_eq!(output.members[0].age, 19);
         assert_eq!(output.members[0].grade, 1);
         assert_eq!(output.members[0].major, "Economics");
+        assert_eq!(output.members[0].role.as_deref(), Some("treasurer"));
+        assert_eq!(output.waitlist.len(), 1);
+        assert_eq!(output.waitlist[0].position, 1);
+        assert_eq!(output.waitlist[0].member.id, waitlist[0].id.to_string());
+        assert_eq!(output.waitlist[0].member.role, None);
+        assert_eq!(output.status, "Recruiting");
         Ok(())
     }
 }
@@ -0,0 +1,241 @@
+use std::{str::FromStr, time::UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    aggregate::{
+        invitation::Invitation,
+        value_object::{
+            circle_id::CircleId, invitation_status::InvitationStatus, member_id::MemberId,
+            permission::Permission,
+        },
+    },
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        invitation_repository_interface::InvitationRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct FetchInvitationsInput {
+    pub circle_id: String,
+    pub status: Option<String>,
+    /// Listing needs `Permission::ApproveJoin`, like deciding on them.
+    pub acting_member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct FetchInvitationsOutput {
+    pub invitations: Vec<InvitationOutput>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct InvitationOutput {
+    pub invitation_id: String,
+    pub member_id: String,
+    pub status: String,
+    /// unix seconds
+    pub expires_at: u64,
+}
+
+impl std::convert::From<Invitation> for InvitationOutput {
+    fn from(invitation: Invitation) -> Self {
+        InvitationOutput {
+            expires_at: invitation
+                .expires_at
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            invitation_id: invitation.id.into(),
+            member_id: invitation.member_id.into(),
+            status: invitation.status.into(),
+        }
+    }
+}
+
+pub struct FetchInvitationsUsecase<T, I>
+where
+    T: CircleRepositoryInterface,
+    I: InvitationRepositoryInterface,
+{
+    circle_repository: T,
+    invitation_repository: I,
+}
+
+impl<T, I> FetchInvitationsUsecase<T, I>
+where
+    T: CircleRepositoryInterface,
+    I: InvitationRepositoryInterface,
+{
+    pub fn new(circle_repository: T, invitation_repository: I) -> Self {
+        FetchInvitationsUsecase {
+            circle_repository,
+            invitation_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        fetch_invitations_input: FetchInvitationsInput,
+    ) -> Result<FetchInvitationsOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(fetch_invitations_input.circle_id.as_str())?;
+        let status = fetch_invitations_input
+            .status
+            .map(|status| InvitationStatus::try_from(status.as_str()))
+            .transpose()
+            .map_err(|e| UsecaseError::InvalidInput(e.to_string()))?;
+        let acting_member_id =
+            MemberId::from_str(fetch_invitations_input.acting_member_id.as_str())?;
+        // an unknown circle is a 404, not an empty list
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+
+        let invitations = self
+            .invitation_repository
+            .find_by_circle_id(&circle.id, status)
+            .await?;
+        Ok(FetchInvitationsOutput {
+            invitations: invitations
+                .into_iter()
+                .map(InvitationOutput::from)
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                invitation_id::InvitationId, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            invitation_repository_interface::MockInvitationRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_invitations_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let invitation = Invitation::issue(
+            InvitationId::gen(),
+            &circle,
+            MemberId::gen(),
+            now,
+            Duration::from_secs(60),
+        )?;
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        let (circle_id, invitation_clone) = (circle.id.clone(), invitation.clone());
+        invitation_repository
+            .expect_find_by_circle_id()
+            .withf(move |id, status| *id == circle_id && status.is_none())
+            .times(1)
+            .returning(move |_, _| Ok(vec![invitation_clone.clone()]));
+
+        let usecase = FetchInvitationsUsecase::new(circle_repository, invitation_repository);
+        let output = usecase
+            .execute(FetchInvitationsInput {
+                circle_id: circle.id.to_string(),
+                status: None,
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            FetchInvitationsOutput {
+                invitations: vec![InvitationOutput {
+                    invitation_id: invitation.id.to_string(),
+                    member_id: invitation.member_id.to_string(),
+                    status: "Pending".to_string(),
+                    expires_at: 1_700_000_060,
+                }],
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_invitations_usecase_invalid_status() -> anyhow::Result<()> {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        circle_repository.expect_find_by_id().times(0);
+
+        let usecase = FetchInvitationsUsecase::new(
+            circle_repository,
+            MockInvitationRepositoryInterface::new(),
+        );
+        let result = usecase
+            .execute(FetchInvitationsInput {
+                circle_id: CircleId::gen().to_string(),
+                status: Some("Expired".to_string()),
+                acting_member_id: MemberId::gen().to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UsecaseError::InvalidInput(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_invitations_usecase_checks_role() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        invitation_repository.expect_find_by_circle_id().times(0);
+
+        let usecase = FetchInvitationsUsecase::new(circle_repository, invitation_repository);
+        let result = usecase
+            .execute(FetchInvitationsInput {
+                circle_id: circle.id.to_string(),
+                status: None,
+                acting_member_id: MemberId::gen().to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    aggregate::{
+        join_request::JoinRequest,
+        value_object::{
+            circle_id::CircleId, join_request_status::JoinRequestStatus, member_id::MemberId,
+            permission::Permission,
+        },
+    },
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        join_request_repository_interface::JoinRequestRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct FetchJoinRequestsInput {
+    pub circle_id: String,
+    pub status: Option<String>,
+    /// Listing needs `Permission::ApproveJoin`, like deciding on them.
+    pub acting_member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct FetchJoinRequestsOutput {
+    pub join_requests: Vec<JoinRequestOutput>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct JoinRequestOutput {
+    pub join_request_id: String,
+    pub member_id: String,
+    pub status: String,
+}
+
+impl std::convert::From<JoinRequest> for JoinRequestOutput {
+    fn from(join_request: JoinRequest) -> Self {
+        JoinRequestOutput {
+            join_request_id: join_request.id.into(),
+            member_id: join_request.member_id.into(),
+            status: join_request.status.into(),
+        }
+    }
+}
+
+pub struct FetchJoinRequestsUsecase<T, J>
+where
+    T: CircleRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+{
+    circle_repository: T,
+    join_request_repository: J,
+}
+
+impl<T, J> FetchJoinRequestsUsecase<T, J>
+where
+    T: CircleRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+{
+    pub fn new(circle_repository: T, join_request_repository: J) -> Self {
+        FetchJoinRequestsUsecase {
+            circle_repository,
+            join_request_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        fetch_join_requests_input: FetchJoinRequestsInput,
+    ) -> Result<FetchJoinRequestsOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(fetch_join_requests_input.circle_id.as_str())?;
+        let status = fetch_join_requests_input
+            .status
+            .map(|status| JoinRequestStatus::try_from(status.as_str()))
+            .transpose()
+            .map_err(|e| UsecaseError::InvalidInput(e.to_string()))?;
+        let acting_member_id =
+            MemberId::from_str(fetch_join_requests_input.acting_member_id.as_str())?;
+        // an unknown circle is a 404, not an empty list
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+
+        let join_requests = self
+            .join_request_repository
+            .find_by_circle_id(&circle.id, status)
+            .await?;
+        Ok(FetchJoinRequestsOutput {
+            join_requests: join_requests
+                .into_iter()
+                .map(JoinRequestOutput::from)
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                join_request_id::JoinRequestId, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            join_request_repository_interface::MockJoinRequestRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_join_requests_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let join_request = JoinRequest::submit(JoinRequestId::gen(), &circle, MemberId::gen())?;
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        let (circle_id, join_request_clone) = (circle.id.clone(), join_request.clone());
+        join_request_repository
+            .expect_find_by_circle_id()
+            .withf(move |id, status| {
+                *id == circle_id && *status == Some(JoinRequestStatus::Pending)
+            })
+            .times(1)
+            .returning(move |_, _| Ok(vec![join_request_clone.clone()]));
+
+        let usecase = FetchJoinRequestsUsecase::new(circle_repository, join_request_repository);
+        let output = usecase
+            .execute(FetchJoinRequestsInput {
+                circle_id: circle.id.to_string(),
+                status: Some("Pending".to_string()),
+                acting_member_id: circle.owner.id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            FetchJoinRequestsOutput {
+                join_requests: vec![JoinRequestOutput {
+                    join_request_id: join_request.id.to_string(),
+                    member_id: join_request.member_id.to_string(),
+                    status: "Pending".to_string(),
+                }],
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_join_requests_usecase_invalid_status() -> anyhow::Result<()> {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        circle_repository.expect_find_by_id().times(0);
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        join_request_repository.expect_find_by_circle_id().times(0);
+
+        let usecase = FetchJoinRequestsUsecase::new(circle_repository, join_request_repository);
+        let result = usecase
+            .execute(FetchJoinRequestsInput {
+                circle_id: CircleId::gen().to_string(),
+                status: Some("pending".to_string()),
+                acting_member_id: MemberId::gen().to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), UsecaseError::InvalidInput(_)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_join_requests_usecase_checks_role() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        join_request_repository.expect_find_by_circle_id().times(0);
+
+        let usecase = FetchJoinRequestsUsecase::new(circle_repository, join_request_repository);
+        let result = usecase
+            .execute(FetchJoinRequestsInput {
+                circle_id: circle.id.to_string(),
+                status: None,
+                acting_member_id: MemberId::gen().to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::member_id::MemberId,
+    interface::member_repository_interface::MemberRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct FetchMemberInput {
+    pub id: String,
+}
+
+impl FetchMemberInput {
+    pub fn new(id: String) -> Self {
+        FetchMemberInput { id }
+    }
+}
+
+#[derive(Debug)]
+pub struct FetchMemberOutput {
+    pub id: String,
+    pub name: String,
+    pub age: i16,
+    pub grade: i16,
+    pub major: String,
+}
+
+pub struct FetchMemberUsecase<T>
+where
+    T: MemberRepositoryInterface,
+{
+    member_repository: T,
+}
+
+impl<T> FetchMemberUsecase<T>
+where
+    T: MemberRepositoryInterface,
+{
+    pub fn new(member_repository: T) -> Self {
+        FetchMemberUsecase { member_repository }
+    }
+
+    pub async fn execute(
+        &self,
+        fetch_member_input: FetchMemberInput,
+    ) -> Result<FetchMemberOutput, UsecaseError> {
+        let member_id = MemberId::from_str(fetch_member_input.id.as_str())?;
+        let member = self.member_repository.find_by_id(&member_id).await?;
+        Ok(FetchMemberOutput {
+            id: member.id.into(),
+            name: member.name,
+            age: member.age.into(),
+            grade: member.grade.into(),
+            major: member.major.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            member::Member,
+            value_object::{age::Age, grade::Grade, major::Major},
+        },
+        interface::member_repository_interface::MockMemberRepositoryInterface,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_member_usecase() -> anyhow::Result<()> {
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let member_clone = member.clone();
+        mocked_member_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(member_clone.clone()));
+
+        let usecase = FetchMemberUsecase::new(mocked_member_repository);
+        let output = usecase
+            .execute(FetchMemberInput::new(member.id.to_string()))
+            .await?;
+
+        assert_eq!(output.id, member.id.to_string());
+        assert_eq!(output.name, "mike");
+        assert_eq!(output.age, 19);
+        assert_eq!(output.grade, 1);
+        assert_eq!(output.major, String::from(Major::Economics));
+        Ok(())
+    }
+}
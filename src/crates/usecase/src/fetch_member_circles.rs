@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    aggregate::value_object::member_id::MemberId,
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct FetchMemberCirclesInput {
+    pub member_id: String,
+}
+
+impl FetchMemberCirclesInput {
+    pub fn new(member_id: String) -> Self {
+        FetchMemberCirclesInput { member_id }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct FetchMemberCirclesOutput {
+    pub circles: Vec<MemberCircleOutput>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct MemberCircleOutput {
+    pub circle_id: String,
+    pub circle_name: String,
+    /// `owner` or `member`
+    pub role: String,
+}
+
+pub struct FetchMemberCirclesUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+}
+
+impl<T, M> FetchMemberCirclesUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    pub fn new(circle_repository: T, member_repository: M) -> Self {
+        FetchMemberCirclesUsecase {
+            circle_repository,
+            member_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        fetch_member_circles_input: FetchMemberCirclesInput,
+    ) -> Result<FetchMemberCirclesOutput, UsecaseError> {
+        let member_id = MemberId::from_str(fetch_member_circles_input.member_id.as_str())?;
+        // an unknown member is a 404 rather than an empty list
+        self.member_repository.find_by_id(&member_id).await?;
+        let circles = self.circle_repository.find_by_member_id(&member_id).await?;
+        Ok(FetchMemberCirclesOutput {
+            circles: circles
+                .into_iter()
+                .map(|circle| MemberCircleOutput {
+                    role: if circle.owner.id == member_id {
+                        "owner"
+                    } else {
+                        "member"
+                    }
+                    .to_string(),
+                    circle_id: circle.id.into(),
+                    circle_name: circle.name.into(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                grade::Grade, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_member_circles_usecase() -> anyhow::Result<()> {
+        let owner = |name: &str| -> anyhow::Result<Member> {
+            Ok(Member::new(
+                MemberId::gen(),
+                name.to_string(),
+                Age::try_from(21)?,
+                Grade::Third,
+                Major::Music,
+            ))
+        };
+        let john = owner("john")?;
+        let music = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            john.clone(),
+            Capacity::try_from(10)?,
+        )?;
+        let art = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("art")?,
+            owner("george")?,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(john.clone())?;
+
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let john_clone = john.clone();
+        member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(john_clone.clone()));
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circles = vec![music.clone(), art.clone()];
+        circle_repository
+            .expect_find_by_member_id()
+            .times(1)
+            .returning(move |_| Ok(circles.clone()));
+
+        let usecase = FetchMemberCirclesUsecase::new(circle_repository, member_repository);
+        let output = usecase
+            .execute(FetchMemberCirclesInput::new(john.id.to_string()))
+            .await?;
+        assert_eq!(
+            output.circles,
+            vec![
+                MemberCircleOutput {
+                    circle_id: music.id.to_string(),
+                    circle_name: "music".to_string(),
+                    role: "owner".to_string(),
+                },
+                MemberCircleOutput {
+                    circle_id: art.id.to_string(),
+                    circle_name: "art".to_string(),
+                    role: "member".to_string(),
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_member_circles_usecase_unknown_member() -> anyhow::Result<()> {
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        member_repository
+            .expect_find_by_id()
+            .returning(|_| Err(DomainError::NotFound("Member").into()));
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        circle_repository.expect_find_by_member_id().times(0);
+
+        let usecase = FetchMemberCirclesUsecase::new(circle_repository, member_repository);
+        let result = usecase
+            .execute(FetchMemberCirclesInput::new(MemberId::gen().to_string()))
+            .await;
+        assert!(matches!(
+            result,
+            Err(UsecaseError::Domain(DomainError::NotFound("Member")))
+        ));
+        Ok(())
+    }
+}
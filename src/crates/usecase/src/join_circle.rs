@@ -0,0 +1,266 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct JoinCircleInput {
+    pub circle_id: String,
+    pub member_id: String,
+    pub acting_member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct JoinCircleOutput {
+    pub circle_id: String,
+    pub member_id: String,
+    /// Set when the circle was full and the member was put on its waitlist instead.
+    pub waitlist_position: Option<usize>,
+}
+
+pub struct JoinCircleUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+}
+
+impl<T, M> JoinCircleUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    pub fn new(circle_repository: T, member_repository: M) -> Self {
+        JoinCircleUsecase {
+            circle_repository,
+            member_repository,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        join_circle_input: JoinCircleInput,
+    ) -> Result<JoinCircleOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(join_circle_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(join_circle_input.member_id.as_str())?;
+        let acting_member_id = MemberId::from_str(join_circle_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+        let member = self.member_repository.find_by_id(&member_id).await?;
+
+        let circle = self
+            .circle_repository
+            .add_member(&circle_id, &member)
+            .await?;
+        Ok(JoinCircleOutput {
+            waitlist_position: circle.waitlist_position(&member_id),
+            circle_id: String::from(circle.id),
+            member_id: String::from(member_id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn mock_circle_repository(circle: &Circle) -> MockCircleRepositoryInterface {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        circle_repository
+    }
+
+    fn mock_member_repository(
+        grade: Grade,
+    ) -> anyhow::Result<(MockMemberRepositoryInterface, Member)> {
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            grade,
+            Major::Economics,
+        );
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let member_clone = member.clone();
+        member_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(member_clone.clone()));
+        Ok((member_repository, member))
+    }
+
+    #[tokio::test]
+    async fn test_join_circle_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut mocked_circle_repository = mock_circle_repository(&circle);
+        let circle_clone = circle.clone();
+        let circle_id = circle.id.clone();
+        mocked_circle_repository
+            .expect_add_member()
+            .withf(move |id, member| *id == circle_id && member.name == "mike")
+            .times(1)
+            .returning(move |_, member| Ok(circle_clone.clone().add_member(member.clone())?));
+        let (member_repository, member) = mock_member_repository(Grade::First)?;
+
+        let mut usecase = JoinCircleUsecase::new(mocked_circle_repository, member_repository);
+        let input = JoinCircleInput {
+            circle_id: circle.id.to_string(),
+            member_id: member.id.to_string(),
+            acting_member_id: circle.owner.id.to_string(),
+        };
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(
+            output,
+            JoinCircleOutput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+                waitlist_position: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_circle_usecase_full_circle() -> anyhow::Result<()> {
+        let circle = build_circle()?.update(None, Some(Capacity::try_from(1)?))?;
+        let mut mocked_circle_repository = mock_circle_repository(&circle);
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_add_member()
+            .times(1)
+            .returning(move |_, member| Ok(circle_clone.clone().add_member(member.clone())?));
+        let (member_repository, member) = mock_member_repository(Grade::First)?;
+
+        let mut usecase = JoinCircleUsecase::new(mocked_circle_repository, member_repository);
+        let input = JoinCircleInput {
+            circle_id: circle.id.to_string(),
+            member_id: member.id.to_string(),
+            acting_member_id: circle.owner.id.to_string(),
+        };
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(output.waitlist_position, Some(1));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_circle_usecase_rejects_4th_grade() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut mocked_circle_repository = mock_circle_repository(&circle);
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_add_member()
+            .times(1)
+            .returning(move |_, member| Ok(circle_clone.clone().add_member(member.clone())?));
+        let (member_repository, member) = mock_member_repository(Grade::Fourth)?;
+
+        let mut usecase = JoinCircleUsecase::new(mocked_circle_repository, member_repository);
+        let input = JoinCircleInput {
+            circle_id: circle.id.to_string(),
+            member_id: member.id.to_string(),
+            acting_member_id: circle.owner.id.to_string(),
+        };
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::FourthGradeCannotJoin)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_circle_usecase_unknown_member() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut mocked_circle_repository = mock_circle_repository(&circle);
+        mocked_circle_repository.expect_add_member().times(0);
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        member_repository
+            .expect_find_by_id()
+            .returning(|_| Err(DomainError::NotFound("Member").into()));
+
+        let mut usecase = JoinCircleUsecase::new(mocked_circle_repository, member_repository);
+        let input = JoinCircleInput {
+            circle_id: circle.id.to_string(),
+            member_id: MemberId::gen().to_string(),
+            acting_member_id: circle.owner.id.to_string(),
+        };
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::NotFound("Member"))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_join_circle_usecase_checks_role() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let mut mocked_circle_repository = mock_circle_repository(&circle);
+        mocked_circle_repository.expect_add_member().times(0);
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        member_repository.expect_find_by_id().times(0);
+
+        let mut usecase = JoinCircleUsecase::new(mocked_circle_repository, member_repository);
+        let member_id = MemberId::gen().to_string();
+        let input = JoinCircleInput {
+            circle_id: circle.id.to_string(),
+            member_id: member_id.clone(),
+            acting_member_id: member_id,
+        };
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+        ));
+        Ok(())
+    }
+}
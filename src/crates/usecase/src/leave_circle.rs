@@ -0,0 +1,238 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    error::DomainError,
+    interface::circle_repository_interface::CircleRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct LeaveCircleInput {
+    pub circle_id: String,
+    pub member_id: String,
+    /// Anyone may leave on their own; taking someone else out needs `Permission::RemoveMember`.
+    pub acting_member_id: String,
+}
+
+impl LeaveCircleInput {
+    pub fn new(circle_id: String, member_id: String, acting_member_id: String) -> Self {
+        LeaveCircleInput {
+            circle_id,
+            member_id,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct LeaveCircleOutput {
+    pub circle_id: String,
+}
+
+pub struct LeaveCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    circle_repository: T,
+}
+
+impl<T> LeaveCircleUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    pub fn new(circle_repository: T) -> Self {
+        LeaveCircleUsecase { circle_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        leave_circle_input: LeaveCircleInput,
+    ) -> Result<LeaveCircleOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(leave_circle_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(leave_circle_input.member_id.as_str())?;
+        let acting_member_id = MemberId::from_str(leave_circle_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        if acting_member_id != member_id {
+            circle.authorize(&acting_member_id, Permission::RemoveMember)?;
+        }
+
+        // someone still waiting for a seat leaves the waitlist the same way
+        let member = circle
+            .member(&member_id)
+            .or_else(|| circle.waitlist.iter().find(|m| m.id == member_id))
+            .cloned()
+            .ok_or(DomainError::NotMember)?;
+        let circle = circle.remove_member(&member)?;
+        self.circle_repository.update(&circle).await?;
+        Ok(LeaveCircleOutput {
+            circle_id: String::from(circle.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+            },
+        },
+        interface::circle_repository_interface::MockCircleRepositoryInterface,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_leave_circle_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .withf(|circle| circle.members.is_empty())
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+
+        let mut usecase = LeaveCircleUsecase::new(mocked_circle_repository);
+        let input = LeaveCircleInput::new(
+            circle.id.to_string(),
+            member.id.to_string(),
+            member.id.to_string(),
+        );
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(output.circle_id, circle.id.to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_leave_circle_usecase_rejects_owner() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository.expect_update().times(0);
+
+        let mut usecase = LeaveCircleUsecase::new(mocked_circle_repository);
+        let input = LeaveCircleInput::new(
+            circle.id.to_string(),
+            owner.id.to_string(),
+            owner.id.to_string(),
+        );
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::OwnerCannotBeRemoved)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_leave_circle_usecase_removing_others_needs_permission() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let member1 = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let member2 = Member::new(
+            MemberId::gen(),
+            "bob".to_string(),
+            Age::try_from(20)?,
+            Grade::Second,
+            Major::Law,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member1.clone())?
+        .add_member(member2.clone())?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .withf(|circle| circle.members.len() == 1)
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+        let mut usecase = LeaveCircleUsecase::new(mocked_circle_repository);
+
+        let input = LeaveCircleInput::new(
+            circle.id.to_string(),
+            member2.id.to_string(),
+            member1.id.to_string(),
+        );
+        assert!(matches!(
+            usecase.execute(input).await.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::RemoveMember))
+        ));
+
+        let input = LeaveCircleInput::new(
+            circle.id.to_string(),
+            member2.id.to_string(),
+            owner.id.to_string(),
+        );
+        usecase.execute(input).await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,24 @@
+pub mod accept_invitation;
+pub mod assign_role;
 pub mod create_circle;
+pub mod create_invitation;
+pub mod create_member;
+pub mod decide_join_request;
+pub mod delete_circle;
+pub mod disband_circle;
+pub mod error;
 pub mod fetch_all_circle;
 pub mod fetch_circle;
+pub mod fetch_invitations;
+pub mod fetch_join_requests;
+pub mod fetch_member;
+pub mod fetch_member_circles;
+pub mod join_circle;
+pub mod leave_circle;
+pub mod reactivate_circle;
+pub mod revoke_invitation;
+pub mod rollover_academic_year;
+pub mod submit_join_request;
+pub mod transfer_ownership;
 pub mod update_circle;
+pub mod update_member;
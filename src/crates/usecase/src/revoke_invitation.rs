@@ -0,0 +1,250 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{
+        circle_id::CircleId, invitation_id::InvitationId, member_id::MemberId,
+        permission::Permission,
+    },
+    error::DomainError,
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        invitation_repository_interface::InvitationRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeInvitationInput {
+    pub circle_id: String,
+    pub invitation_id: String,
+    /// Revoking needs `Permission::ApproveJoin`, like inviting.
+    pub acting_member_id: String,
+}
+
+impl RevokeInvitationInput {
+    pub fn new(circle_id: String, invitation_id: String, acting_member_id: String) -> Self {
+        RevokeInvitationInput {
+            circle_id,
+            invitation_id,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct RevokeInvitationOutput {
+    pub invitation_id: String,
+    pub status: String,
+}
+
+pub struct RevokeInvitationUsecase<T, I>
+where
+    T: CircleRepositoryInterface,
+    I: InvitationRepositoryInterface,
+{
+    circle_repository: T,
+    invitation_repository: I,
+}
+
+impl<T, I> RevokeInvitationUsecase<T, I>
+where
+    T: CircleRepositoryInterface,
+    I: InvitationRepositoryInterface,
+{
+    pub fn new(circle_repository: T, invitation_repository: I) -> Self {
+        RevokeInvitationUsecase {
+            circle_repository,
+            invitation_repository,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        revoke_invitation_input: RevokeInvitationInput,
+    ) -> Result<RevokeInvitationOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(revoke_invitation_input.circle_id.as_str())?;
+        let invitation_id = InvitationId::from_str(revoke_invitation_input.invitation_id.as_str())?;
+        let acting_member_id =
+            MemberId::from_str(revoke_invitation_input.acting_member_id.as_str())?;
+        let invitation = self
+            .invitation_repository
+            .find_by_id(&invitation_id)
+            .await?;
+        if invitation.circle_id != circle_id {
+            return Err(DomainError::NotFound("Invitation").into());
+        }
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ApproveJoin)?;
+
+        let invitation = invitation.revoke()?;
+        self.invitation_repository.update(&invitation).await?;
+        Ok(RevokeInvitationOutput {
+            invitation_id: String::from(invitation.id),
+            status: String::from(invitation.status),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            invitation::Invitation,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                invitation_status::InvitationStatus, major::Major,
+            },
+        },
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            invitation_repository_interface::MockInvitationRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn build_invitation(circle: &Circle, status: InvitationStatus) -> Invitation {
+        Invitation::reconstruct(
+            InvitationId::gen(),
+            circle.id.clone(),
+            MemberId::gen(),
+            SystemTime::now() + Duration::from_secs(60),
+            status,
+        )
+    }
+
+    fn mock_circle_repository(circle: &Circle) -> MockCircleRepositoryInterface {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        circle_repository
+    }
+
+    fn mock_invitation_repository(
+        invitation: &Invitation,
+        updates: usize,
+    ) -> MockInvitationRepositoryInterface {
+        let mut invitation_repository = MockInvitationRepositoryInterface::new();
+        let invitation_clone = invitation.clone();
+        invitation_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(invitation_clone.clone()));
+        invitation_repository
+            .expect_update()
+            .withf(|invitation| invitation.status == InvitationStatus::Revoked)
+            .times(updates)
+            .returning(|_| Ok(()));
+        invitation_repository
+    }
+
+    #[tokio::test]
+    async fn test_revoke_invitation_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let invitation = build_invitation(&circle, InvitationStatus::Pending);
+        let mut usecase = RevokeInvitationUsecase::new(
+            mock_circle_repository(&circle),
+            mock_invitation_repository(&invitation, 1),
+        );
+        let output = usecase
+            .execute(RevokeInvitationInput::new(
+                invitation.circle_id.to_string(),
+                invitation.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await?;
+
+        assert_eq!(
+            output,
+            RevokeInvitationOutput {
+                invitation_id: invitation.id.to_string(),
+                status: "Revoked".to_string(),
+            }
+        );
+
+        // an invitation is only found through its own circle
+        let mut usecase = RevokeInvitationUsecase::new(
+            MockCircleRepositoryInterface::new(),
+            mock_invitation_repository(&invitation, 0),
+        );
+        let result = usecase
+            .execute(RevokeInvitationInput::new(
+                CircleId::gen().to_string(),
+                invitation.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::NotFound("Invitation"))
+        ));
+
+        // and only revoked by someone who may invite
+        let mut usecase = RevokeInvitationUsecase::new(
+            mock_circle_repository(&circle),
+            mock_invitation_repository(&invitation, 0),
+        );
+        let result = usecase
+            .execute(RevokeInvitationInput::new(
+                invitation.circle_id.to_string(),
+                invitation.id.to_string(),
+                invitation.member_id.to_string(),
+            ))
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::Forbidden(Permission::ApproveJoin))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_revoke_invitation_usecase_accepted() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let invitation = build_invitation(&circle, InvitationStatus::Accepted);
+        let mut usecase = RevokeInvitationUsecase::new(
+            mock_circle_repository(&circle),
+            mock_invitation_repository(&invitation, 0),
+        );
+        let result = usecase
+            .execute(RevokeInvitationInput::new(
+                invitation.circle_id.to_string(),
+                invitation.id.to_string(),
+                circle.owner.id.to_string(),
+            ))
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvitationNotPending(
+                InvitationStatus::Accepted
+            ))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+use serde::{Deserialize, Serialize};
+
+use domain::{
+    aggregate::{circle::Circle, value_object::academic_year::AcademicYear},
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct RolloverAcademicYearInput {
+    /// The academic year that is ending; each year is rolled over once.
+    pub academic_year: i16,
+}
+
+impl RolloverAcademicYearInput {
+    pub fn new(academic_year: i16) -> Self {
+        RolloverAcademicYearInput { academic_year }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct RolloverAcademicYearOutput {
+    /// Every member who moved up a grade, whether or not they belong to a circle.
+    pub promoted: Vec<PromotedMemberOutput>,
+    pub circles: Vec<CircleRolloverOutput>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CircleRolloverOutput {
+    pub circle_id: String,
+    pub circle_name: String,
+    pub status: String,
+    pub graduated: Vec<GraduatedMemberOutput>,
+    /// Set when a graduating owner handed the circle over to their nominated successor.
+    pub new_owner_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PromotedMemberOutput {
+    pub member_id: String,
+    pub name: String,
+    pub from_grade: i16,
+    pub to_grade: i16,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GraduatedMemberOutput {
+    pub member_id: String,
+    pub name: String,
+}
+
+impl CircleRolloverOutput {
+    fn new(before: &Circle, after: &Circle) -> Self {
+        let graduated = std::iter::once(&before.owner)
+            .chain(&before.members)
+            .filter(|member| after.member(&member.id).is_none())
+            .map(|member| GraduatedMemberOutput {
+                member_id: member.id.to_string(),
+                name: member.name.clone(),
+            })
+            .collect();
+        CircleRolloverOutput {
+            circle_id: before.id.to_string(),
+            circle_name: before.name.to_string(),
+            status: String::from(after.status),
+            graduated,
+            new_owner_id: (after.owner.id != before.owner.id).then(|| after.owner.id.to_string()),
+        }
+    }
+}
+
+pub struct RolloverAcademicYearUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+}
+
+impl<T, M> RolloverAcademicYearUsecase<T, M>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+{
+    pub fn new(circle_repository: T, member_repository: M) -> Self {
+        RolloverAcademicYearUsecase {
+            circle_repository,
+            member_repository,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        rollover_academic_year_input: RolloverAcademicYearInput,
+    ) -> Result<RolloverAcademicYearOutput, UsecaseError> {
+        let academic_year = AcademicYear::try_from(rollover_academic_year_input.academic_year)?;
+        // circles go first: who graduates is decided by the grades of the year that is ending
+        let circles = self.circle_repository.find_all().await?;
+        let mut reports = Vec::new();
+        let mut rolled_over = Vec::new();
+        for circle in circles {
+            let next = circle.clone().rollover();
+            if !next.events.is_empty() {
+                reports.push(CircleRolloverOutput::new(&circle, &next));
+                rolled_over.push(next);
+            }
+        }
+
+        let mut promoted = Vec::new();
+        let mut promoted_members = Vec::new();
+        for member in self.member_repository.find_all().await? {
+            let next = member.clone().promote();
+            if next.grade != member.grade {
+                promoted.push(PromotedMemberOutput {
+                    member_id: member.id.to_string(),
+                    name: member.name,
+                    from_grade: i16::from(member.grade),
+                    to_grade: i16::from(next.grade),
+                });
+                promoted_members.push(next);
+            }
+        }
+        // one transaction, so a failure can't leave circles rolled over with nobody promoted;
+        // it runs even when nothing changed, to record the year as done
+        self.circle_repository
+            .rollover(academic_year, &rolled_over, &promoted_members)
+            .await?;
+
+        Ok(RolloverAcademicYearOutput {
+            promoted,
+            circles: reports,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_id::CircleId, circle_name::CircleName,
+                grade::Grade, major::Major, member_id::MemberId,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rollover_academic_year_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::Music,
+        );
+        let freshman = Member::new(
+            MemberId::gen(),
+            "paul".to_string(),
+            Age::try_from(18)?,
+            Grade::First,
+            Major::Music,
+        );
+        let senior = Member::new(
+            MemberId::gen(),
+            "george".to_string(),
+            Age::try_from(22)?,
+            Grade::Fourth,
+            Major::Music,
+        );
+        // not in any circle, but moves up a grade all the same
+        let loner = Member::new(
+            MemberId::gen(),
+            "ringo".to_string(),
+            Age::try_from(19)?,
+            Grade::Second,
+            Major::Art,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?
+        .add_member(freshman.clone())?;
+        // 4th graders can't join, so put the senior in directly
+        let circle = Circle {
+            members: vec![freshman.clone(), senior.clone()],
+            events: vec![],
+            ..circle
+        };
+        let found = circle.clone();
+        let members = vec![
+            owner.clone(),
+            freshman.clone(),
+            senior.clone(),
+            loner.clone(),
+        ];
+
+        mocked_circle_repository
+            .expect_find_all()
+            .times(1)
+            .returning(move || Ok(vec![found.clone()]));
+        let expected = vec![
+            owner.clone().promote(),
+            freshman.clone().promote(),
+            loner.clone().promote(),
+        ];
+        mocked_circle_repository
+            .expect_rollover()
+            .withf(move |academic_year, circles, members| {
+                i16::from(*academic_year) == 2025
+                    && circles.len() == 1
+                    && circles[0].owner.grade == Grade::Third
+                    && circles[0].members.len() == 1
+                    && circles[0].members[0].grade == Grade::First
+                    && members == expected.as_slice()
+            })
+            .times(1)
+            .returning(|_, circles, _| Ok(circles.to_vec()));
+        mocked_member_repository
+            .expect_find_all()
+            .times(1)
+            .returning(move || Ok(members.clone()));
+
+        let mut usecase =
+            RolloverAcademicYearUsecase::new(mocked_circle_repository, mocked_member_repository);
+        let output = usecase
+            .execute(RolloverAcademicYearInput::new(2025))
+            .await?;
+
+        assert_eq!(
+            output,
+            RolloverAcademicYearOutput {
+                promoted: vec![
+                    PromotedMemberOutput {
+                        member_id: owner.id.to_string(),
+                        name: "john".to_string(),
+                        from_grade: 3,
+                        to_grade: 4,
+                    },
+                    PromotedMemberOutput {
+                        member_id: freshman.id.to_string(),
+                        name: "paul".to_string(),
+                        from_grade: 1,
+                        to_grade: 2,
+                    },
+                    PromotedMemberOutput {
+                        member_id: loner.id.to_string(),
+                        name: "ringo".to_string(),
+                        from_grade: 2,
+                        to_grade: 3,
+                    },
+                ],
+                circles: vec![CircleRolloverOutput {
+                    circle_id: circle.id.to_string(),
+                    circle_name: "music".to_string(),
+                    status: "Recruiting".to_string(),
+                    graduated: vec![GraduatedMemberOutput {
+                        member_id: senior.id.to_string(),
+                        name: "george".to_string(),
+                    }],
+                    new_owner_id: None,
+                }],
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover_academic_year_usecase_nothing_to_do() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        mocked_circle_repository
+            .expect_find_all()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        mocked_circle_repository
+            .expect_rollover()
+            .withf(|_, circles, members| circles.is_empty() && members.is_empty())
+            .times(1)
+            .returning(|_, circles, _| Ok(circles.to_vec()));
+        let senior = Member::new(
+            MemberId::gen(),
+            "george".to_string(),
+            Age::try_from(22)?,
+            Grade::Fourth,
+            Major::Music,
+        );
+        mocked_member_repository
+            .expect_find_all()
+            .times(1)
+            .returning(move || Ok(vec![senior.clone()]));
+
+        let mut usecase =
+            RolloverAcademicYearUsecase::new(mocked_circle_repository, mocked_member_repository);
+        let output = usecase
+            .execute(RolloverAcademicYearInput::new(2025))
+            .await?;
+        assert!(output.promoted.is_empty());
+        assert!(output.circles.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover_academic_year_usecase_already_rolled_over() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        mocked_circle_repository
+            .expect_find_all()
+            .times(1)
+            .returning(|| Ok(vec![]));
+        mocked_circle_repository
+            .expect_rollover()
+            .times(1)
+            .returning(|academic_year, _, _| {
+                Err(DomainError::AlreadyRolledOver(academic_year).into())
+            });
+        mocked_member_repository
+            .expect_find_all()
+            .times(1)
+            .returning(|| Ok(vec![]));
+
+        let mut usecase =
+            RolloverAcademicYearUsecase::new(mocked_circle_repository, mocked_member_repository);
+        let result = usecase.execute(RolloverAcademicYearInput::new(2025)).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::AlreadyRolledOver(_))
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rollover_academic_year_usecase_invalid_year() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        mocked_circle_repository.expect_find_all().times(0);
+        mocked_circle_repository.expect_rollover().times(0);
+        mocked_member_repository.expect_find_all().times(0);
+
+        let mut usecase =
+            RolloverAcademicYearUsecase::new(mocked_circle_repository, mocked_member_repository);
+        let result = usecase.execute(RolloverAcademicYearInput::new(1999)).await;
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvalidAcademicYear(1999))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::{
+        join_request::JoinRequest,
+        value_object::{circle_id::CircleId, member_id::MemberId},
+    },
+    interface::{
+        circle_repository_interface::CircleRepositoryInterface,
+        id_generator_interface::IdGeneratorInterface,
+        join_request_repository_interface::JoinRequestRepositoryInterface,
+        member_repository_interface::MemberRepositoryInterface,
+    },
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitJoinRequestInput {
+    pub circle_id: String,
+    pub member_id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct SubmitJoinRequestOutput {
+    pub join_request_id: String,
+    pub status: String,
+}
+
+pub struct SubmitJoinRequestUsecase<T, M, J, G>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+    G: IdGeneratorInterface,
+{
+    circle_repository: T,
+    member_repository: M,
+    join_request_repository: J,
+    id_generator: G,
+}
+
+impl<T, M, J, G> SubmitJoinRequestUsecase<T, M, J, G>
+where
+    T: CircleRepositoryInterface,
+    M: MemberRepositoryInterface,
+    J: JoinRequestRepositoryInterface,
+    G: IdGeneratorInterface,
+{
+    pub fn new(
+        circle_repository: T,
+        member_repository: M,
+        join_request_repository: J,
+        id_generator: G,
+    ) -> Self {
+        SubmitJoinRequestUsecase {
+            circle_repository,
+            member_repository,
+            join_request_repository,
+            id_generator,
+        }
+    }
+
+    pub async fn execute(
+        &mut self,
+        submit_join_request_input: SubmitJoinRequestInput,
+    ) -> Result<SubmitJoinRequestOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(submit_join_request_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(submit_join_request_input.member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        let member = self.member_repository.find_by_id(&member_id).await?;
+
+        let join_request =
+            JoinRequest::submit(self.id_generator.join_request_id(), &circle, member.id)?;
+        self.join_request_repository.create(&join_request).await?;
+        Ok(SubmitJoinRequestOutput {
+            join_request_id: String::from(join_request.id),
+            status: String::from(join_request.status),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade,
+                join_request_id::JoinRequestId, major::Major,
+            },
+        },
+        error::DomainError,
+        interface::{
+            circle_repository_interface::MockCircleRepositoryInterface,
+            id_generator_interface::MockIdGeneratorInterface,
+            join_request_repository_interface::MockJoinRequestRepositoryInterface,
+            member_repository_interface::MockMemberRepositoryInterface,
+        },
+    };
+
+    use super::*;
+
+    fn build_circle() -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?)
+    }
+
+    fn mock_repositories(
+        circle: &Circle,
+        member: &Member,
+    ) -> (MockCircleRepositoryInterface, MockMemberRepositoryInterface) {
+        let mut circle_repository = MockCircleRepositoryInterface::new();
+        let circle_clone = circle.clone();
+        circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        let mut member_repository = MockMemberRepositoryInterface::new();
+        let member_clone = member.clone();
+        member_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(member_clone.clone()));
+        (circle_repository, member_repository)
+    }
+
+    #[tokio::test]
+    async fn test_submit_join_request_usecase() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let (circle_repository, member_repository) = mock_repositories(&circle, &member);
+        let join_request_id = JoinRequestId::gen();
+        let mut id_generator = MockIdGeneratorInterface::new();
+        let join_request_id_clone = join_request_id.clone();
+        id_generator
+            .expect_join_request_id()
+            .times(1)
+            .returning(move || join_request_id_clone.clone());
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        let (circle_id, member_id) = (circle.id.clone(), member.id.clone());
+        join_request_repository
+            .expect_create()
+            .withf(move |join_request| {
+                join_request.circle_id == circle_id && join_request.member_id == member_id
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut usecase = SubmitJoinRequestUsecase::new(
+            circle_repository,
+            member_repository,
+            join_request_repository,
+            id_generator,
+        );
+        let output = usecase
+            .execute(SubmitJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                member_id: member.id.to_string(),
+            })
+            .await?;
+
+        assert_eq!(
+            output,
+            SubmitJoinRequestOutput {
+                join_request_id: join_request_id.to_string(),
+                status: "Pending".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_join_request_usecase_already_member() -> anyhow::Result<()> {
+        let circle = build_circle()?;
+        let (circle_repository, member_repository) = mock_repositories(&circle, &circle.owner);
+        let mut id_generator = MockIdGeneratorInterface::new();
+        id_generator
+            .expect_join_request_id()
+            .returning(JoinRequestId::gen);
+        let mut join_request_repository = MockJoinRequestRepositoryInterface::new();
+        join_request_repository.expect_create().times(0);
+
+        let mut usecase = SubmitJoinRequestUsecase::new(
+            circle_repository,
+            member_repository,
+            join_request_repository,
+            id_generator,
+        );
+        let result = usecase
+            .execute(SubmitJoinRequestInput {
+                circle_id: circle.id.to_string(),
+                member_id: circle.owner.id.to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::AlreadyMember)
+        ));
+        Ok(())
+    }
+}
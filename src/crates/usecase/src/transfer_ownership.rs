@@ -0,0 +1,273 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use domain::{
+    aggregate::value_object::{circle_id::CircleId, member_id::MemberId, permission::Permission},
+    interface::circle_repository_interface::CircleRepositoryInterface,
+};
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct TransferOwnershipInput {
+    pub circle_id: String,
+    pub member_id: String,
+    /// Only nominate the member as successor, to take over when the owner graduates.
+    pub at_rollover: bool,
+    pub acting_member_id: String,
+}
+
+impl TransferOwnershipInput {
+    pub fn new(
+        circle_id: String,
+        member_id: String,
+        at_rollover: bool,
+        acting_member_id: String,
+    ) -> Self {
+        TransferOwnershipInput {
+            circle_id,
+            member_id,
+            at_rollover,
+            acting_member_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct TransferOwnershipOutput {
+    pub circle_id: String,
+    pub owner_id: String,
+    pub successor_id: Option<String>,
+}
+
+pub struct TransferOwnershipUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    circle_repository: T,
+}
+
+impl<T> TransferOwnershipUsecase<T>
+where
+    T: CircleRepositoryInterface,
+{
+    pub fn new(circle_repository: T) -> Self {
+        TransferOwnershipUsecase { circle_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        transfer_ownership_input: TransferOwnershipInput,
+    ) -> Result<TransferOwnershipOutput, UsecaseError> {
+        let circle_id = CircleId::from_str(transfer_ownership_input.circle_id.as_str())?;
+        let member_id = MemberId::from_str(transfer_ownership_input.member_id.as_str())?;
+        let acting_member_id =
+            MemberId::from_str(transfer_ownership_input.acting_member_id.as_str())?;
+        let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        circle.authorize(&acting_member_id, Permission::ManageCircle)?;
+
+        let circle = if transfer_ownership_input.at_rollover {
+            circle.nominate_successor(&member_id)?
+        } else {
+            circle.transfer_ownership(&member_id)?
+        };
+        self.circle_repository.update(&circle).await?;
+        Ok(TransferOwnershipOutput {
+            circle_id: String::from(circle.id),
+            owner_id: String::from(circle.owner.id),
+            successor_id: circle.successor_id.map(String::from),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{
+            circle::Circle,
+            member::Member,
+            value_object::{
+                age::Age, capacity::Capacity, circle_name::CircleName, grade::Grade, major::Major,
+                role::Role,
+            },
+        },
+        error::DomainError,
+        interface::circle_repository_interface::MockCircleRepositoryInterface,
+    };
+
+    use super::*;
+
+    fn build_circle(member: &Member) -> anyhow::Result<Circle> {
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        Ok(Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member.clone())?)
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_usecase() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(20)?,
+            Grade::Third,
+            Major::Economics,
+        );
+        let circle = build_circle(&member)?;
+        let circle_clone = circle.clone();
+        let member_id = member.id.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .withf(move |circle| circle.owner.id == member_id)
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+
+        let mut usecase = TransferOwnershipUsecase::new(mocked_circle_repository);
+        let input = TransferOwnershipInput::new(
+            circle.id.to_string(),
+            member.id.to_string(),
+            false,
+            circle.owner.id.to_string(),
+        );
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(
+            output,
+            TransferOwnershipOutput {
+                circle_id: circle.id.to_string(),
+                owner_id: member.id.to_string(),
+                successor_id: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_usecase_at_rollover() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(20)?,
+            Grade::Second,
+            Major::Economics,
+        );
+        let circle = build_circle(&member)?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+
+        let mut usecase = TransferOwnershipUsecase::new(mocked_circle_repository);
+        let input = TransferOwnershipInput::new(
+            circle.id.to_string(),
+            member.id.to_string(),
+            true,
+            circle.owner.id.to_string(),
+        );
+        let output = usecase.execute(input).await?;
+
+        assert_eq!(output.owner_id, circle.owner.id.to_string());
+        assert_eq!(output.successor_id, Some(member.id.to_string()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_usecase_invalid_grade() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(18)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = build_circle(&member)?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository.expect_update().times(0);
+
+        let mut usecase = TransferOwnershipUsecase::new(mocked_circle_repository);
+        let input = TransferOwnershipInput::new(
+            circle.id.to_string(),
+            member.id.to_string(),
+            false,
+            circle.owner.id.to_string(),
+        );
+        let result = usecase.execute(input).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            UsecaseError::Domain(DomainError::InvalidOwnerGrade)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfer_ownership_usecase_checks_role() -> anyhow::Result<()> {
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(20)?,
+            Grade::Third,
+            Major::Economics,
+        );
+        let circle = build_circle(&member)?;
+
+        // a member can't take the circle over, nor name themselves successor
+        for (circle, at_rollover) in [
+            (circle.clone(), false),
+            (
+                circle.clone().assign_role(&member.id, Role::Treasurer)?,
+                false,
+            ),
+            (circle.assign_role(&member.id, Role::Treasurer)?, true),
+        ] {
+            let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+            let circle_clone = circle.clone();
+            mocked_circle_repository
+                .expect_find_by_id()
+                .returning(move |_| Ok(circle_clone.clone()));
+            mocked_circle_repository.expect_update().times(0);
+
+            let mut usecase = TransferOwnershipUsecase::new(mocked_circle_repository);
+            let input = TransferOwnershipInput::new(
+                circle.id.to_string(),
+                member.id.to_string(),
+                at_rollover,
+                member.id.to_string(),
+            );
+            let result = usecase.execute(input).await;
+
+            assert!(matches!(
+                result.unwrap_err(),
+                UsecaseError::Domain(DomainError::Forbidden(Permission::ManageCircle))
+            ));
+        }
+        Ok(())
+    }
+}
@@ -1,36 +1,53 @@
 use std::str::FromStr;
 
-use anyhow::Error;
 use domain::{
-    aggregate::value_object::circle_id::CircleId,
+    aggregate::value_object::{
+        capacity::Capacity, circle_id::CircleId, circle_name::CircleName, member_id::MemberId,
+        permission::Permission,
+    },
     interface::circle_repository_interface::CircleRepositoryInterface,
 };
 use serde::Deserialize;
 
+use crate::error::UsecaseError;
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateCircleInput {
     pub id: String,
+    /// The member making the change; their role decides what they may change.
+    pub acting_member_id: String,
     pub circle_name: Option<String>,
     pub capacity: Option<i16>,
+    /// The version the client last saw; the update is refused if the circle has moved on since.
+    pub expected_version: Option<u32>,
 }
 
 impl UpdateCircleInput {
-    pub fn new(id: String, circle_name: Option<String>, capacity: Option<i16>) -> Self {
+    pub fn new(
+        id: String,
+        acting_member_id: String,
+        circle_name: Option<String>,
+        capacity: Option<i16>,
+        expected_version: Option<u32>,
+    ) -> Self {
         UpdateCircleInput {
             id,
+            acting_member_id,
             circle_name,
             capacity,
+            expected_version,
         }
     }
 }
 
 pub struct UpdateCircleOutPut {
     pub circle_id: String,
+    pub version: u32,
 }
 
 impl UpdateCircleOutPut {
-    pub fn new(circle_id: String) -> Self {
-        UpdateCircleOutPut { circle_id }
+    pub fn new(circle_id: String, version: u32) -> Self {
+        UpdateCircleOutPut { circle_id, version }
     }
 }
 
@@ -52,20 +69,51 @@ where
     pub async fn execute(
         &mut self,
         update_circle_input: UpdateCircleInput,
-    ) -> Result<UpdateCircleOutPut, Error> {
+    ) -> Result<UpdateCircleOutPut, UsecaseError> {
         let circle_id = CircleId::from_str(update_circle_input.id.as_str())?;
+        let acting_member_id = MemberId::from_str(update_circle_input.acting_member_id.as_str())?;
         let circle = self.circle_repository.find_by_id(&circle_id).await?;
+        if let Some(expected_version) = update_circle_input.expected_version {
+            if circle.version != expected_version {
+                return Err(UsecaseError::PreconditionFailed(format!(
+                    "Circle version is {}, not {}",
+                    circle.version, expected_version
+                )));
+            }
+        }
 
-        let circle = circle.update(
-            update_circle_input.circle_name,
-            update_circle_input.capacity,
-        );
-        self.circle_repository
-            .update(&circle)
-            .await
-            .map(|_cirlce| UpdateCircleOutPut {
+        let circle_name = update_circle_input
+            .circle_name
+            .map(CircleName::try_from)
+            .transpose()?;
+        let capacity = update_circle_input
+            .capacity
+            .map(Capacity::try_from)
+            .transpose()?;
+        // only what actually changes needs the permission, so a form can send every field back
+        let renamed = circle_name
+            .as_ref()
+            .is_some_and(|name| name != &circle.name);
+        let resized = capacity.is_some_and(|capacity| capacity != circle.capacity);
+        if renamed {
+            circle.authorize(&acting_member_id, Permission::Rename)?;
+        }
+        if resized {
+            circle.authorize(&acting_member_id, Permission::ChangeCapacity)?;
+        }
+        let circle = circle.update(circle_name, capacity)?;
+        // and nothing is written when nothing changes, so such a form doesn't bump the version
+        if !renamed && !resized {
+            return Ok(UpdateCircleOutPut {
                 circle_id: String::from(circle.id),
-            })
+                version: circle.version,
+            });
+        }
+        let updated = self.circle_repository.update(&circle).await?;
+        Ok(UpdateCircleOutPut {
+            circle_id: String::from(circle.id),
+            version: updated.version,
+        })
     }
 }
 
@@ -75,8 +123,9 @@ mod tests {
         aggregate::{
             circle::Circle,
             member::Member,
-            value_object::{grade::Grade, major::Major},
+            value_object::{age::Age, grade::Grade, major::Major, role::Role},
         },
+        error::DomainError,
         interface::circle_repository_interface::MockCircleRepositoryInterface,
     };
 
@@ -85,8 +134,19 @@ mod tests {
     #[tokio::test]
     async fn test_update_circle_usecase() -> anyhow::Result<()> {
         let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
-        let owner = Member::new("john".to_string(), 21, Grade::Third, Major::ComputerScience);
-        let circle = Circle::create("music".to_string(), owner.clone(), 10)?;
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner.clone(),
+            Capacity::try_from(10)?,
+        )?;
         let circle_clone = circle.clone();
         mocked_circle_repository
             .expect_find_by_id()
@@ -95,15 +155,189 @@ mod tests {
         mocked_circle_repository
             .expect_update()
             .times(1)
-            .returning(move |_| Ok(Circle::create("footBall".to_string(), owner.clone(), 20)?));
+            .returning(move |_| {
+                Ok(Circle::create(
+                    CircleId::gen(),
+                    CircleName::try_from("footBall")?,
+                    owner.clone(),
+                    Capacity::try_from(20)?,
+                )?)
+            });
         let mut usecase = UpdateCircleUsecase::new(mocked_circle_repository);
         let input = UpdateCircleInput::new(
             circle.id.to_string(),
+            circle.owner.id.to_string(),
             Some("footBall".to_string()),
             Some(20),
+            None,
         );
         let output = usecase.execute(input).await?;
         assert_eq!(output.circle_id, circle.id.to_string());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_update_circle_usecase_stale_version() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository.expect_update().times(0);
+        let mut usecase = UpdateCircleUsecase::new(mocked_circle_repository);
+        let input = UpdateCircleInput::new(
+            circle.id.to_string(),
+            circle.owner.id.to_string(),
+            Some("footBall".to_string()),
+            None,
+            Some(circle.version + 1),
+        );
+        let result = usecase.execute(input).await;
+        assert!(matches!(result, Err(UsecaseError::PreconditionFailed(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_circle_usecase_invalid_input() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let member = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(member)?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository.expect_update().times(0);
+        let mut usecase = UpdateCircleUsecase::new(mocked_circle_repository);
+
+        for (circle_name, capacity, error) in [
+            (Some("   "), None, DomainError::EmptyCircleName),
+            (None, Some(0), DomainError::InvalidCapacity(0)),
+            (None, Some(1), DomainError::CapacityBelowMemberCount(2)),
+        ] {
+            let input = UpdateCircleInput::new(
+                circle.id.to_string(),
+                circle.owner.id.to_string(),
+                circle_name.map(str::to_string),
+                capacity,
+                None,
+            );
+            let result = usecase.execute(input).await;
+            assert!(matches!(result, Err(UsecaseError::Domain(e)) if e == error));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_circle_usecase_checks_role() -> anyhow::Result<()> {
+        let mut mocked_circle_repository = MockCircleRepositoryInterface::new();
+        let owner = Member::new(
+            MemberId::gen(),
+            "john".to_string(),
+            Age::try_from(21)?,
+            Grade::Third,
+            Major::ComputerScience,
+        );
+        let treasurer = Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        );
+        let circle = Circle::create(
+            CircleId::gen(),
+            CircleName::try_from("music")?,
+            owner,
+            Capacity::try_from(10)?,
+        )?
+        .add_member(treasurer.clone())?
+        .assign_role(&treasurer.id, Role::Treasurer)?;
+        let circle_clone = circle.clone();
+        mocked_circle_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(circle_clone.clone()));
+        mocked_circle_repository
+            .expect_update()
+            .times(1)
+            .returning(|circle| Ok(circle.clone()));
+        let mut usecase = UpdateCircleUsecase::new(mocked_circle_repository);
+
+        for (acting_member_id, circle_name, capacity, error) in [
+            (
+                treasurer.id.clone(),
+                Some("football"),
+                None,
+                Some(Permission::Rename),
+            ),
+            (
+                MemberId::gen(),
+                None,
+                Some(20),
+                Some(Permission::ChangeCapacity),
+            ),
+            // the unchanged name is not a rename
+            (treasurer.id.clone(), Some("music"), Some(20), None),
+        ] {
+            let input = UpdateCircleInput::new(
+                circle.id.to_string(),
+                acting_member_id.to_string(),
+                circle_name.map(str::to_string),
+                capacity,
+                None,
+            );
+            let result = usecase.execute(input).await;
+            match error {
+                Some(permission) => assert!(matches!(
+                    result,
+                    Err(UsecaseError::Domain(DomainError::Forbidden(p))) if p == permission
+                )),
+                None => assert!(result.is_ok()),
+            }
+        }
+
+        // resending the current values writes nothing, so it needs no role either
+        let input = UpdateCircleInput::new(
+            circle.id.to_string(),
+            MemberId::gen().to_string(),
+            Some("music".to_string()),
+            Some(10),
+            None,
+        );
+        let output = usecase.execute(input).await?;
+        assert_eq!(output.version, circle.version);
+        Ok(())
+    }
 }
@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use domain::{
+    aggregate::value_object::{age::Age, major::Major, member_id::MemberId},
+    interface::member_repository_interface::MemberRepositoryInterface,
+};
+use serde::Deserialize;
+
+use crate::error::UsecaseError;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMemberInput {
+    pub id: String,
+    pub name: Option<String>,
+    pub age: Option<i16>,
+    pub major: Option<String>,
+}
+
+impl UpdateMemberInput {
+    pub fn new(id: String, name: Option<String>, age: Option<i16>, major: Option<String>) -> Self {
+        UpdateMemberInput {
+            id,
+            name,
+            age,
+            major,
+        }
+    }
+}
+
+pub struct UpdateMemberOutput {
+    pub member_id: String,
+}
+
+pub struct UpdateMemberUsecase<T>
+where
+    T: MemberRepositoryInterface,
+{
+    member_repository: T,
+}
+
+impl<T> UpdateMemberUsecase<T>
+where
+    T: MemberRepositoryInterface,
+{
+    pub fn new(member_repository: T) -> Self {
+        UpdateMemberUsecase { member_repository }
+    }
+
+    pub async fn execute(
+        &mut self,
+        update_member_input: UpdateMemberInput,
+    ) -> Result<UpdateMemberOutput, UsecaseError> {
+        let member_id = MemberId::from_str(update_member_input.id.as_str())?;
+        let member = self.member_repository.find_by_id(&member_id).await?;
+
+        let age = update_member_input.age.map(Age::try_from).transpose()?;
+        let major = update_member_input
+            .major
+            .map(|major| Major::from(major.as_str()));
+        let member = member.update(update_member_input.name, age, major);
+        self.member_repository.update(&member).await?;
+        Ok(UpdateMemberOutput {
+            member_id: String::from(member.id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        aggregate::{member::Member, value_object::grade::Grade},
+        error::DomainError,
+        interface::member_repository_interface::MockMemberRepositoryInterface,
+    };
+
+    use super::*;
+
+    fn build_member() -> anyhow::Result<Member> {
+        Ok(Member::new(
+            MemberId::gen(),
+            "mike".to_string(),
+            Age::try_from(19)?,
+            Grade::First,
+            Major::Economics,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_update_member_usecase() -> anyhow::Result<()> {
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        let member = build_member()?;
+        let member_clone = member.clone();
+        mocked_member_repository
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_| Ok(member_clone.clone()));
+        mocked_member_repository
+            .expect_update()
+            .times(1)
+            .withf(|member| {
+                member.name == "michael"
+                    && i16::from(member.age) == 20
+                    && member.major == Major::Economics
+            })
+            .returning(|_| Ok(()));
+
+        let mut usecase = UpdateMemberUsecase::new(mocked_member_repository);
+        let input = UpdateMemberInput::new(
+            member.id.to_string(),
+            Some("michael".to_string()),
+            Some(20),
+            None,
+        );
+        let output = usecase.execute(input).await?;
+        assert_eq!(output.member_id, member.id.to_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_member_usecase_invalid_input() -> anyhow::Result<()> {
+        let mut mocked_member_repository = MockMemberRepositoryInterface::new();
+        let member = build_member()?;
+        let member_clone = member.clone();
+        mocked_member_repository
+            .expect_find_by_id()
+            .returning(move |_| Ok(member_clone.clone()));
+        mocked_member_repository.expect_update().times(0);
+
+        let mut usecase = UpdateMemberUsecase::new(mocked_member_repository);
+        let input = UpdateMemberInput::new(member.id.to_string(), None, Some(200), None);
+        let result = usecase.execute(input).await;
+        assert!(matches!(
+            result,
+            Err(UsecaseError::Domain(DomainError::InvalidAge(200)))
+        ));
+        Ok(())
+    }
+}